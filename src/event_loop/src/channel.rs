@@ -0,0 +1,76 @@
+//! Update channels with independent fixed update rates.
+
+use std::time::{Duration, Instant};
+
+use input::ChannelUpdateArgs;
+
+use {duration_to_secs, ns_to_duration, BILLION};
+
+#[derive(Copy, Clone, Debug)]
+struct Channel {
+    id: u64,
+    dt_update_in_ns: u64,
+    last_update: Instant,
+}
+
+/// Stores the update channels of an event loop.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Channels {
+    channels: Vec<Channel>,
+}
+
+impl Channels {
+    /// Adds an update channel, replacing any existing channel with the same id.
+    pub fn add(&mut self, id: u64, now: Instant, ups: u64) {
+        assert!(ups > 0, "Update channel requires updates per second larger than 0");
+        self.remove(id);
+        self.channels.push(Channel {
+            id: id,
+            dt_update_in_ns: BILLION / ups,
+            last_update: now,
+        });
+    }
+
+    /// Removes an update channel, returning `true` if it existed.
+    pub fn remove(&mut self, id: u64) -> bool {
+        let len = self.channels.len();
+        self.channels.retain(|channel| channel.id != id);
+        self.channels.len() != len
+    }
+
+    /// Returns the time of the next channel update.
+    pub fn next_update(&self) -> Option<Instant> {
+        self.channels.iter()
+            .map(|channel| channel.last_update + ns_to_duration(channel.dt_update_in_ns))
+            .min()
+    }
+
+    /// Moves the update time forward, e.g. after being paused.
+    pub fn delay(&mut self, dt: Duration) {
+        for channel in &mut self.channels {
+            channel.last_update += dt;
+        }
+    }
+
+    /// Returns the earliest channel update that is due.
+    ///
+    /// Delayed updates are skipped after lagging more than `ups_reset` updates.
+    /// When `ups_reset` is `0`, it will always try to catch up.
+    pub fn pop_due(&mut self, now: Instant, ups_reset: u64) -> Option<ChannelUpdateArgs> {
+        let channel = self.channels.iter_mut()
+            .filter(|channel| channel.last_update + ns_to_duration(channel.dt_update_in_ns) <= now)
+            .min_by_key(|channel| channel.last_update + ns_to_duration(channel.dt_update_in_ns))?;
+        let dt_update = ns_to_duration(channel.dt_update_in_ns);
+        if ups_reset > 0 &&
+           now - channel.last_update > ns_to_duration(ups_reset * channel.dt_update_in_ns) {
+            // Skip updates because CPU is too busy.
+            channel.last_update = now;
+        } else {
+            channel.last_update += dt_update;
+        }
+        Some(ChannelUpdateArgs {
+            id: channel.id,
+            dt: duration_to_secs(dt_update),
+        })
+    }
+}
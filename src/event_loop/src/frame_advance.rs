@@ -0,0 +1,114 @@
+//! Stepping the event loop one frame at a time.
+
+use input::{AfterUpdateArgs, RenderArgs};
+use window::Window;
+
+use {Events, Idle, State, Step};
+
+impl Events {
+    /// Enables or disables frame advance mode.
+    ///
+    /// In frame advance mode, the event loop waits for input and advances
+    /// exactly one update and one render per call to
+    /// [`advance_frame()`](#method.advance_frame), e.g. when pressing a key.
+    /// The update time is fixed, so scripted input with a
+    /// [`HeadlessWindow`](../window/struct.HeadlessWindow.html) reproduces the same frames,
+    /// for tool-assisted testing and frame-precise debugging.
+    /// Idle events, timers and update channels are not emitted in this mode.
+    ///
+    /// When disabled, the event loop continues from the current time without catching up.
+    pub fn set_frame_advance(&mut self, enable: bool) {
+        if self.frame_advance && !enable {
+            let now = self.clock.now();
+            self.last_update = now;
+            self.last_frame = now;
+        }
+        self.frame_advance = enable;
+        self.frame_steps = 0;
+    }
+
+    /// Returns `true` if frame advance mode is enabled.
+    pub fn is_frame_advance(&self) -> bool {
+        self.frame_advance
+    }
+
+    /// Advances one update and render in frame advance mode.
+    ///
+    /// Multiple calls advance multiple frames.
+    pub fn advance_frame(&mut self) {
+        self.frame_steps += 1;
+    }
+
+    pub(crate) fn step_frame_advance<W>(&mut self, window: &mut W, block: bool) -> Step
+        where W: Window
+    {
+        loop {
+            if window.should_close() {
+                return Step::Done;
+            }
+            match self.state {
+                State::AfterUpdate => {
+                    self.state = State::Render;
+                    return Step::Event(AfterUpdateArgs.into());
+                }
+                State::Render => {
+                    self.state = State::UpdateLoop(Idle::No);
+                    let size = window.size();
+                    let draw_size = window.draw_size();
+                    if size.width != 0.0 && size.height != 0.0 {
+                        // Swap buffers next time.
+                        self.state = State::SwapBuffers;
+                        let frame = self.frames;
+                        self.frames += 1;
+                        return Step::Event(RenderArgs {
+                            ext_dt: 0.0,
+                            ext_alpha: 0.0,
+                            frame: frame,
+                            // The simulation time, to reproduce the same frames.
+                            time: self.update_time(),
+                            window_size: size.into(),
+                            draw_size: draw_size.into(),
+                        }.into());
+                    }
+                }
+                State::SwapBuffers => {
+                    let render_end = self.clock.now();
+                    if self.settings.swap_buffers {
+                        if let Some(e) = self.swap_window_buffers(window) {
+                            return Step::Event(e);
+                        }
+                    }
+                    self.state = State::UpdateLoop(Idle::No);
+                    return Step::Event(self.after_render_args(window, render_end).into());
+                }
+                State::AfterRender => {
+                    self.state = State::UpdateLoop(Idle::No);
+                    let render_end = self.clock.now();
+                    return Step::Event(self.after_render_args(window, render_end).into());
+                }
+                State::UpdateLoop(_) | State::HandleEvents | State::Update => {
+                    // Handle all input events before advancing.
+                    if let Some(e) = self.poll_event(window) {
+                        return Step::Event(e);
+                    }
+                    if self.frame_steps > 0 {
+                        self.frame_steps -= 1;
+                        if self.settings.ups == 0 {
+                            self.state = State::Render;
+                            continue;
+                        }
+                        self.updates += 1;
+                        self.state = State::AfterUpdate;
+                        return Step::Event(self.next_update_args().into());
+                    }
+                    if !block {
+                        return Step::Wait(None);
+                    }
+                    // Wait for input to advance the next frame.
+                    self.state = State::HandleEvents;
+                    return Step::Event(self.wait_event(window));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Detecting periods without user input.
+
+use std::time::{Duration, Instant};
+
+use input::{Event, InactivityArgs, Input, Motion};
+
+use {duration_to_secs, Events, Step};
+
+/// Tracks the time of the last user input.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Inactivity {
+    last_input: Instant,
+    inactive: bool,
+}
+
+impl Inactivity {
+    /// Starts counting from now.
+    pub fn new(now: Instant) -> Inactivity {
+        Inactivity {
+            last_input: now,
+            inactive: false,
+        }
+    }
+
+    /// Returns the time when the user becomes inactive, or `None` if already inactive.
+    pub fn deadline(&self, timeout: Duration) -> Option<Instant> {
+        if self.inactive {None} else {Some(self.last_input + timeout)}
+    }
+
+    /// Returns inactivity arguments when the user became inactive.
+    pub fn pop_due(&mut self, now: Instant, timeout: Duration) -> Option<InactivityArgs> {
+        if self.deadline(timeout)? > now {
+            return None;
+        }
+        self.inactive = true;
+        Some(InactivityArgs {
+            inactive: true,
+            idle_time: duration_to_secs(now - self.last_input),
+        })
+    }
+
+    /// Records user input, returning inactivity arguments if the user was inactive.
+    pub fn input(&mut self, now: Instant) -> Option<InactivityArgs> {
+        let idle_time = if now > self.last_input {
+            duration_to_secs(now - self.last_input)
+        } else {
+            0.0
+        };
+        self.last_input = now;
+        if !self.inactive {
+            return None;
+        }
+        self.inactive = false;
+        Some(InactivityArgs {
+            inactive: false,
+            idle_time: idle_time,
+        })
+    }
+}
+
+/// Returns `true` if the input comes from the user, not from the window or the system.
+fn is_user_input(input: &Input) -> bool {
+    match *input {
+        // Sensors report values while the device is lying still.
+        Input::Move(Motion::Accelerometer(_)) |
+        Input::Move(Motion::Gyroscope(_)) |
+        Input::Move(Motion::Magnetometer(_)) => false,
+        Input::Button(_) | Input::Move(_) | Input::Text(_) | Input::FileDrag(_) |
+        Input::DropData(_) | Input::Tablet(_) | Input::Back(_) => true,
+        _ => false,
+    }
+}
+
+impl Events {
+    /// Returns `true` if the user is inactive.
+    ///
+    /// See `EventSettings::inactivity_timeout`.
+    pub fn is_inactive(&self) -> bool {
+        self.inactivity.inactive
+    }
+
+    /// Records user input, emitting an inactivity event before the input
+    /// when the user was inactive.
+    pub(crate) fn check_inactivity(&mut self, step: Step) -> Step {
+        if self.timing_replay.is_some() {
+            // The inactivity events are replayed.
+            return step;
+        }
+        let resumed = match step {
+            Step::Event(Event::Input(ref input, _)) if is_user_input(input) => {
+                let now = self.clock.now();
+                self.inactivity.input(now)
+            }
+            _ => None,
+        };
+        match (resumed, step) {
+            (Some(args), Step::Event(e)) => {
+                self.resumed_input = Some(e);
+                Step::Event(args.into())
+            }
+            (_, step) => step,
+        }
+    }
+}
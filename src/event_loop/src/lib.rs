@@ -5,12 +5,53 @@
 
 extern crate window;
 extern crate input;
+#[cfg(feature = "futures")]
+extern crate futures_core;
+#[cfg(feature = "remote")]
+extern crate serde_json;
 
-use std::thread::sleep;
+use std::error::Error;
+use std::fmt;
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 use std::cmp;
-use window::Window;
-use input::{Event, AfterRenderArgs, IdleArgs, RenderArgs, UpdateArgs};
+use window::{SwapBuffersError, Window};
+use input::{Event, AfterRenderArgs, AfterUpdateArgs, ContextState, HandlerPanickedArgs, IdleArgs,
+            Input, LagKind, LagWarningArgs, Lifecycle, Loop, RenderArgs, StallArgs, StallPhase,
+            SwapFailedArgs, UpdateArgs};
+
+pub use profile::{Phase, PhaseProfiler};
+pub use proxy::EventLoopProxy;
+pub use replay::TimingEntry;
+pub use run::PumpStatus;
+pub use smoothing::{DtFilter, DtSmoothing};
+pub use stats::FrameStats;
+pub use threaded::ThreadedUpdate;
+pub use window::{MockClock, SleepStrategy, SystemClock, TimeSource};
+#[cfg(feature = "futures")]
+pub use stream::EventStream;
+#[cfg(feature = "remote")]
+pub use remote::RemoteInput;
+
+mod channel;
+mod frame_advance;
+mod inactivity;
+mod open_file;
+mod open_url;
+mod profile;
+mod proxy;
+mod replay;
+mod run;
+mod smoothing;
+mod stats;
+mod threaded;
+mod timer;
+#[cfg(feature = "futures")]
+mod stream;
+#[cfg(feature = "remote")]
+mod remote;
 
 /// Tells whether last emitted event was idle or not.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -19,13 +60,25 @@ enum Idle {
     Yes,
 }
 
+/// The result of advancing the event loop.
+enum Step {
+    /// An event was emitted.
+    Event(Event),
+    /// The event loop must wait until the time, or for input when `None`.
+    Wait(Option<Instant>),
+    /// The event loop ended.
+    Done,
+}
+
 #[derive(Copy, Clone, Debug)]
 enum State {
     Render,
     SwapBuffers,
+    AfterRender,
     UpdateLoop(Idle),
     HandleEvents,
     Update,
+    AfterUpdate,
 }
 
 /// Stores event loop settings.
@@ -45,7 +98,40 @@ pub struct EventSettings {
     pub ups: u64,
     /// The number of delayed updates before skipping them to catch up.
     /// When set to `0`, it will always try to catch up.
+    ///
+    /// Used by [`CatchUp::SlowDownClock`](./enum.CatchUp.html#variant.SlowDownClock).
     pub ups_reset: u64,
+    /// How to catch up with delayed updates after the event loop lags.
+    pub catch_up: CatchUp,
+    /// The maximum time between two frames before emitting a lag warning event.
+    ///
+    /// The lag warning is emitted before rendering the late frame.
+    /// Not used in lazy mode, benchmark mode or when `ups` is `0`.
+    /// When set to `None`, frame times are not checked.
+    pub frame_budget: Option<Duration>,
+    /// Enable or disable rendering only when a redraw is requested.
+    ///
+    /// See [`Events::request_redraw`](./struct.Events.html#method.request_redraw).
+    /// Unlike `lazy`, update events are still emitted.
+    /// The first frame and frames after resizing, scale factor, window state
+    /// or graphics context changes are always rendered.
+    /// Not used in benchmark mode.
+    pub render_on_demand: bool,
+    /// The maximum time for handling a single update or render event
+    /// before emitting a stall event.
+    ///
+    /// The stall event is emitted after handling the event, with the phase and duration.
+    /// When set to `None`, the time is not checked.
+    pub stall_threshold: Option<Duration>,
+    /// Enable or disable catching panics of event handlers.
+    ///
+    /// See [`Events::handle`](./struct.Events.html#method.handle).
+    pub catch_panics: bool,
+    /// How to wait until the next frame or update.
+    ///
+    /// The default sleeps until 1 millisecond before the deadline
+    /// and spins for the rest of the time, for precise frame pacing.
+    pub sleep_strategy: SleepStrategy,
     /// Enable or disable automatic swapping of buffers.
     pub swap_buffers: bool,
     /// Enable or disable benchmark mode.
@@ -53,9 +139,43 @@ pub struct EventSettings {
     /// Used to test performance by playing through as fast as possible.
     /// Requires `lazy` to be set to `false`.
     pub bench_mode: bool,
+    /// The number of frames to render in benchmark mode.
+    ///
+    /// The event loop ends after the last frame, see `Events::bench_report`.
+    /// When set to `0`, the event loop runs until the window closes.
+    pub bench_frames: u64,
     /// Enable or disable rendering only when receiving input.
     /// When enabled, update and idle events are disabled.
+    ///
+    /// The event loop sleeps until input arrives, a timer fires
+    /// or it is woken by an [`EventLoopProxy`](./struct.EventLoopProxy.html),
+    /// and renders a frame afterwards.
     pub lazy: bool,
+    /// The maximum number of frames per second while paused.
+    ///
+    /// See [`Events::set_paused`](./struct.Events.html#method.set_paused).
+    /// When set to `0`, uses `max_fps`.
+    pub paused_max_fps: u64,
+    /// The maximum number of frames per second while the window is not focused.
+    ///
+    /// Reduces the frame rate to save battery when the application is in the background.
+    /// When set to `0`, uses `max_fps`.
+    pub unfocused_max_fps: u64,
+    /// The time without user input before emitting an inactivity event.
+    ///
+    /// The next user input emits another inactivity event before the input,
+    /// to resume from attract modes or automatic pausing.
+    /// Not used in frame advance mode.
+    /// When set to `None`, inactivity is not detected.
+    pub inactivity_timeout: Option<Duration>,
+    /// Enable or disable rendering only when the platform calls back for a frame.
+    ///
+    /// Frames are driven by [`Events::pump_frame`](./struct.Events.html#method.pump_frame),
+    /// e.g. from `requestAnimationFrame` on the web or `CADisplayLink` on iOS,
+    /// instead of sleeping until the next frame at `max_fps`.
+    /// Update events keep their fixed rate and catch up before rendering.
+    /// Not used in lazy mode or benchmark mode.
+    pub external_frames: bool,
 }
 
 impl EventSettings {
@@ -66,9 +186,70 @@ impl EventSettings {
             ups: DEFAULT_UPS,
             swap_buffers: true,
             bench_mode: false,
+            bench_frames: 0,
             lazy: false,
             ups_reset: DEFAULT_UPS_RESET,
+            catch_up: CatchUp::default(),
+            frame_budget: None,
+            render_on_demand: false,
+            stall_threshold: None,
+            catch_panics: false,
+            sleep_strategy: SleepStrategy::default(),
+            paused_max_fps: 0,
+            unfocused_max_fps: 0,
+            inactivity_timeout: None,
+            external_frames: false,
+        }
+    }
+
+    /// Creates settings for games.
+    ///
+    /// Uses the default settings, but drops lag after 10 updates
+    /// between two frames, to recover quickly after a long stall.
+    pub fn game() -> EventSettings {
+        EventSettings {
+            catch_up: CatchUp::ClampTo(10),
+            ..EventSettings::new()
+        }
+    }
+
+    /// Creates settings for GUI applications.
+    ///
+    /// Renders only when receiving input, without update and idle events.
+    pub fn gui() -> EventSettings {
+        EventSettings {
+            ups: 0,
+            lazy: true,
+            ..EventSettings::new()
+        }
+    }
+
+    /// Checks whether the combination of settings is valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `max_fps` is `0`, if `catch_up` clamps to `0` updates,
+    /// or if `bench_mode` is enabled together with `lazy`.
+    pub fn validate(&self) -> Result<(), InvalidEventSettingsError> {
+        if self.max_fps == 0 {
+            return Err(InvalidEventSettingsError {
+                setting: "max_fps",
+                message: "Expected maximum frames per second larger than 0".into(),
+            });
+        }
+        if self.catch_up == CatchUp::ClampTo(0) {
+            return Err(InvalidEventSettingsError {
+                setting: "catch_up",
+                message: "Expected clamping to at least 1 update per frame".into(),
+            });
+        }
+        if self.bench_mode && self.lazy {
+            return Err(InvalidEventSettingsError {
+                setting: "bench_mode",
+                message: "Benchmark mode requires `lazy` to be disabled".into(),
+            });
         }
+        Ok(())
     }
 }
 
@@ -78,12 +259,55 @@ impl Default for EventSettings {
     }
 }
 
+/// How to catch up with delayed updates after the event loop lags.
+///
+/// Simulations that must be correct can run all updates,
+/// while other applications can stay responsive after a hitch.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CatchUp {
+    /// Runs all delayed updates, even if this takes multiple frames.
+    RunAll,
+    /// Runs at most the number of updates between two frames.
+    ///
+    /// When exceeded, the remaining lag is dropped and a lag warning event is emitted,
+    /// instead of freezing while trying to catch up after a long stall.
+    ClampTo(u64),
+    /// Skips delayed updates when lagging more than `ups_reset` updates,
+    /// so the simulation runs slower than real time while the CPU is too busy.
+    SlowDownClock,
+}
+
+impl Default for CatchUp {
+    fn default() -> CatchUp {
+        CatchUp::SlowDownClock
+    }
+}
+
+/// An error for when event loop settings are invalid.
+///
+/// See [`EventSettings::validate`](./struct.EventSettings.html#method.validate).
+#[derive(Debug)]
+pub struct InvalidEventSettingsError {
+    /// The name of the setting, e.g. `"max_fps"`.
+    pub setting: &'static str,
+    /// Describes what is wrong with the setting.
+    pub message: String,
+}
+
+impl fmt::Display for InvalidEventSettingsError {
+    fn fmt(&self, w: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(w, "Invalid event setting `{}`: {}", self.setting, self.message)
+    }
+}
+
+impl Error for InvalidEventSettingsError {}
+
 /// An event loop iterator
 ///
 /// *Warning: Because the iterator polls events from the window back-end,
 /// it must be used on the same thread as the window back-end (usually main thread),
 /// unless the window back-end supports multi-thread event polling.*
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct Events {
     state: State,
     last_update: Instant,
@@ -93,6 +317,90 @@ pub struct Events {
     dt: f64,
     settings: EventSettings,
     first_frame: bool,
+    updates_since_frame: u64,
+    start: Instant,
+    frames: u64,
+    updates: u64,
+    frame_stats: Option<FrameStats>,
+    /// The time when the event loop was paused.
+    paused: Option<Instant>,
+    proxy_queue: proxy::ProxyQueue,
+    /// Whether the window is focused, from the last focus event.
+    focused: bool,
+    /// Whether the application is suspended, from the last lifecycle event.
+    suspended: bool,
+    /// Whether the platform called back for a frame that is not rendered yet.
+    frame_requested: bool,
+    timers: timer::Timers,
+    channels: channel::Channels,
+    clock: Arc<TimeSource>,
+    /// Whether to emit an update after skipping delayed updates.
+    skipped_updates: bool,
+    /// Whether a lag warning was emitted for exceeding the frame budget.
+    frame_budget_warned: bool,
+    redraw_requested: bool,
+    frame_advance: bool,
+    /// The number of frames to advance in frame advance mode.
+    frame_steps: u64,
+    timing_record: Option<Vec<TimingEntry>>,
+    timing_replay: Option<replay::TimingReplay>,
+    /// The phase and time of the last emitted update or render event.
+    watchdog: Option<(StallPhase, Instant)>,
+    /// A caught panic of an event handler to emit next time.
+    panicked: Option<HandlerPanickedArgs>,
+    /// The number of caught panics and the message of the last one.
+    panics: (u64, Option<String>),
+    /// The number of failed swaps and the error message of the last one.
+    swap_failures: (u64, Option<String>),
+    /// The number of update events, kept when changing settings.
+    update_tick: u64,
+    /// The tick and simulation time when the update delta time last changed.
+    update_time_base: (u64, f64),
+    /// Receives the phases of the event loop.
+    profiler: Option<Arc<PhaseProfiler>>,
+    /// The update or render phase while the event is handled.
+    phase: Option<Phase>,
+    inactivity: inactivity::Inactivity,
+    /// A user input event to emit after the inactivity event for resuming.
+    resumed_input: Option<Event>,
+    /// Whether the next input event was polled from the window or the proxy queue.
+    polled: bool,
+}
+
+/// Stores the result of running the event loop in benchmark mode.
+///
+/// See [`Events::bench_report`](./struct.Events.html#method.bench_report).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BenchReport {
+    /// The number of render events.
+    pub frames: u64,
+    /// The number of update events.
+    pub updates: u64,
+    /// The time since the event loop started.
+    pub elapsed: Duration,
+}
+
+impl BenchReport {
+    /// Returns the average frames per second.
+    pub fn fps(&self) -> f64 {
+        self.frames as f64 / duration_to_secs(self.elapsed)
+    }
+
+    /// Returns the average updates per second.
+    pub fn ups(&self) -> f64 {
+        self.updates as f64 / duration_to_secs(self.elapsed)
+    }
+}
+
+/// Returns the message of a panic payload.
+fn panic_message(payload: &(Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<Any>".into()
+    }
 }
 
 static BILLION: u64 = 1_000_000_000;
@@ -115,8 +423,25 @@ pub const DEFAULT_UPS_RESET: u64 = 2;
 pub const DEFAULT_MAX_FPS: u64 = 60;
 
 impl Events {
+    /// Creates a new event iterator after checking the settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the settings are invalid,
+    /// see [`EventSettings::validate`](./struct.EventSettings.html#method.validate).
+    pub fn try_new(settings: EventSettings) -> Result<Events, InvalidEventSettingsError> {
+        settings.validate()?;
+        Ok(Events::new(settings))
+    }
+
     /// Creates a new event iterator with default UPS and FPS settings.
+    ///
+    /// Panics if the settings are invalid, for example if `max_fps` is `0`.
+    /// Use [`try_new()`](#method.try_new) to check the settings without panicking.
     pub fn new(settings: EventSettings) -> Events {
+        if let Err(err) = settings.validate() {
+            panic!("{}", err);
+        }
         let start = Instant::now();
         Events {
             state: State::Render,
@@ -135,33 +460,573 @@ impl Events {
             },
             settings: settings,
             first_frame: true,
+            updates_since_frame: 0,
+            start: start,
+            frames: 0,
+            updates: 0,
+            frame_stats: None,
+            paused: None,
+            proxy_queue: Default::default(),
+            focused: true,
+            suspended: false,
+            frame_requested: false,
+            timers: Default::default(),
+            channels: Default::default(),
+            clock: Arc::new(SystemClock),
+            skipped_updates: false,
+            frame_budget_warned: false,
+            redraw_requested: true,
+            frame_advance: false,
+            frame_steps: 0,
+            timing_record: None,
+            timing_replay: None,
+            watchdog: None,
+            panicked: None,
+            panics: (0, None),
+            swap_failures: (0, None),
+            update_tick: 0,
+            update_time_base: (0, 0.0),
+            profiler: None,
+            phase: None,
+            inactivity: inactivity::Inactivity::new(start),
+            resumed_input: None,
+            polled: false,
+        }
+    }
+
+    /// Sets the source of time, e.g. a [`MockClock`](./struct.MockClock.html) for testing.
+    ///
+    /// Resets the event loop to start at the current time of the source.
+    pub fn set_time_source(&mut self, source: Arc<TimeSource>) {
+        let start = source.now();
+        self.clock = source;
+        self.start = start;
+        self.last_update = start;
+        self.last_frame = start;
+        self.first_frame = true;
+        self.state = State::Render;
+        self.inactivity = inactivity::Inactivity::new(start);
+        if self.paused.is_some() {
+            self.paused = Some(start);
+        }
+    }
+
+    /// Sets event loop settings after checking them.
+    ///
+    /// The event loop is reset like with
+    /// [`EventLoop::set_event_settings`](./trait.EventLoop.html#tymethod.set_event_settings),
+    /// which panics on invalid settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and keeps the current settings if the settings are invalid,
+    /// see [`EventSettings::validate`](./struct.EventSettings.html#method.validate).
+    pub fn try_set_event_settings(&mut self, settings: EventSettings)
+        -> Result<(), InvalidEventSettingsError>
+    {
+        settings.validate()?;
+        // Reset event loop to initial state.
+        let mut frame_stats = self.frame_stats.take();
+        if let Some(ref mut stats) = frame_stats {
+            stats.clear();
+        }
+        let paused = self.paused.is_some();
+        let proxy_queue = self.proxy_queue.clone();
+        let focused = self.focused;
+        let suspended = self.suspended;
+        let frame_advance = self.frame_advance;
+        let frames = self.frames;
+        // Before the first frame, the time starts again with the new settings.
+        let elapsed = if frames == 0 {Duration::from_secs(0)} else {self.now() - self.start};
+        let timing_record = self.timing_record.take();
+        let timing_replay = self.timing_replay.take();
+        let update_tick = self.update_tick;
+        let update_time_base = (update_tick, self.update_time());
+        let profiler = self.profiler.take();
+        let phase = self.phase.take();
+        let timers = self.timers.clone();
+        let channels = self.channels.clone();
+        let clock = self.clock.clone();
+        let inactivity = self.inactivity;
+        let panics = self.panics.clone();
+        let swap_failures = self.swap_failures.clone();
+        *self = Events::new(settings);
+        self.set_time_source(clock);
+        self.inactivity = inactivity;
+        self.panics = panics;
+        self.swap_failures = swap_failures;
+        self.timers = timers;
+        self.channels = channels;
+        self.proxy_queue = proxy_queue;
+        self.focused = focused;
+        self.suspended = suspended;
+        self.frame_advance = frame_advance;
+        // The frame number and time continue with the new settings.
+        self.frames = frames;
+        self.start = self.now() - elapsed;
+        self.timing_record = timing_record;
+        self.timing_replay = timing_replay;
+        self.update_tick = update_tick;
+        self.update_time_base = update_time_base;
+        self.profiler = profiler;
+        self.phase = phase;
+        self.frame_stats = frame_stats;
+        self.set_paused(paused);
+        Ok(())
+    }
+
+    /// Returns the time between frames.
+    ///
+    /// Uses the lowest frame rate that applies when paused or not focused.
+    fn frame_dt(&self) -> Duration {
+        let mut dt_frame_in_ns = self.dt_frame_in_ns;
+        if self.paused.is_some() && self.settings.paused_max_fps > 0 {
+            dt_frame_in_ns = cmp::max(dt_frame_in_ns, BILLION / self.settings.paused_max_fps);
+        }
+        if !self.focused && self.settings.unfocused_max_fps > 0 {
+            dt_frame_in_ns = cmp::max(dt_frame_in_ns, BILLION / self.settings.unfocused_max_fps);
+        }
+        ns_to_duration(dt_frame_in_ns)
+    }
+
+    /// Returns the current time, which is fixed per frame in benchmark mode.
+    fn now(&self) -> Instant {
+        if self.settings.bench_mode {self.last_frame} else {self.clock.now()}
+    }
+
+    /// Pauses or resumes the event loop.
+    ///
+    /// While paused, update events are suppressed but input and render events are still emitted,
+    /// at `paused_max_fps` if set, e.g. for pause menus or when the application is suspended.
+    /// The time spent paused is not counted as update time,
+    /// so the simulation continues where it stopped when resuming.
+    pub fn set_paused(&mut self, paused: bool) {
+        match (self.paused, paused) {
+            (None, true) => self.paused = Some(self.now()),
+            (Some(start), false) => {
+                let now = self.now();
+                self.last_update += now - start;
+                self.channels.delay(now - start);
+                self.paused = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns `true` if the event loop is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused.is_some()
+    }
+
+    /// Returns `true` if the application is suspended,
+    /// from the last `Input::Lifecycle` event.
+    ///
+    /// The event loop does not pause by itself while suspended,
+    /// see [`set_paused()`](#method.set_paused).
+    pub fn is_suspended(&self) -> bool {
+        self.suspended
+    }
+
+    /// Polls the next event from the proxy queue or the window.
+    fn poll_event<W>(&mut self, window: &mut W) -> Option<Event>
+        where W: Window
+    {
+        self.phase_start(Phase::Poll);
+        let e = proxy::poll_event(&self.proxy_queue, window);
+        self.phase_end(Phase::Poll);
+        self.polled = e.is_some();
+        e
+    }
+
+    /// Waits for the next event from the window.
+    fn wait_event<W>(&mut self, window: &mut W) -> Event
+        where W: Window
+    {
+        self.phase_start(Phase::Idle);
+        let e = window.wait_event();
+        self.phase_end(Phase::Idle);
+        self.polled = true;
+        e
+    }
+
+    /// Waits for the next event from the window, until the timeout is reached.
+    fn wait_event_timeout<W>(&mut self, window: &mut W, timeout: Duration) -> Option<Event>
+        where W: Window
+    {
+        self.phase_start(Phase::Idle);
+        let e = window.wait_event_timeout(timeout);
+        self.phase_end(Phase::Idle);
+        self.polled = e.is_some();
+        e
+    }
+
+    /// Sleeps until the deadline with the sleep strategy of the settings.
+    fn sleep_until(&self, deadline: Instant) {
+        self.phase_start(Phase::Idle);
+        self.clock.sleep_until(deadline, self.settings.sleep_strategy);
+        self.phase_end(Phase::Idle);
+    }
+
+    /// Swaps buffers and returns an event if swapping failed.
+    ///
+    /// The after render event is emitted next time.
+    fn swap_window_buffers<W>(&mut self, window: &mut W) -> Option<Event>
+        where W: Window
+    {
+        self.phase_start(Phase::Swap);
+        let res = window.swap_buffers();
+        self.phase_end(Phase::Swap);
+        let e = match res {
+            Ok(()) => return None,
+            Err(SwapBuffersError::ContextLost) => Input::Context(ContextState::Lost).into(),
+            Err(SwapBuffersError::SurfaceLost) => Input::Context(ContextState::SurfaceLost).into(),
+            Err(SwapBuffersError::DeviceRemoved) => {
+                Input::Context(ContextState::DeviceRemoved).into()
+            }
+            // Other errors do not prevent rendering the next frame.
+            Err(SwapBuffersError::Other(message)) => {
+                let id = self.swap_failures.0;
+                self.swap_failures = (id + 1, Some(message));
+                SwapFailedArgs { id: id }.into()
+            }
+        };
+        self.state = State::AfterRender;
+        Some(e)
+    }
+
+    /// Returns the after render arguments with the GPU time reported by the window,
+    /// recording the CPU and GPU time of the frame.
+    fn after_render_args<W>(&mut self, window: &mut W, render_end: Instant) -> AfterRenderArgs
+        where W: Window
+    {
+        let gpu_time = window.gpu_frame_time();
+        if let (Some(stats), Some(gpu_time)) = (self.frame_stats.as_mut(), gpu_time) {
+            if let Some(frame_start) = stats.last_frame() {
+                stats.record_gpu_time(render_end, render_end - frame_start, gpu_time);
+            }
+        }
+        AfterRenderArgs { gpu_dt: gpu_time.map(duration_to_secs) }
+    }
+
+    /// Returns the number of frames and updates since the event loop started.
+    ///
+    /// In benchmark mode, the number of frames and updates are deterministic,
+    /// because the time between events is fixed.
+    /// Set `bench_frames` to run a fixed number of frames,
+    /// so the elapsed time can be compared across machines.
+    pub fn bench_report(&self) -> BenchReport {
+        BenchReport {
+            frames: self.frames,
+            updates: self.updates,
+            elapsed: self.clock.now() - self.start,
+        }
+    }
+
+    /// Enables collecting frame timing statistics over a sliding window of time.
+    pub fn enable_frame_stats(&mut self, window: Duration) {
+        self.frame_stats = Some(FrameStats::new(window));
+    }
+
+    /// Disables collecting frame timing statistics.
+    pub fn disable_frame_stats(&mut self) {
+        self.frame_stats = None;
+    }
+
+    /// Returns frame timing statistics, if enabled.
+    pub fn frame_stats(&self) -> Option<&FrameStats> {
+        self.frame_stats.as_ref()
+    }
+
+    /// Creates a proxy for sending events to the event loop from other threads.
+    ///
+    /// The proxy wakes the event loop with a waker from the window,
+    /// see [`Window::create_waker`](../window/trait.Window.html#method.create_waker).
+    pub fn create_proxy<W>(&self, window: &W) -> EventLoopProxy
+        where W: Window
+    {
+        EventLoopProxy::new(self.proxy_queue.clone(), window)
+    }
+
+    /// Sets a timer that emits a timer event with the id after a duration.
+    ///
+    /// A repeating timer fires every duration until cancelled,
+    /// skipping missed intervals after a stall.
+    /// Setting a timer with the same id as an existing timer replaces it.
+    /// Timers keep running while paused.
+    /// In lazy mode, the event loop wakes up for timers and renders a frame after them,
+    /// e.g. to drive an animation in a GUI application.
+    ///
+    /// Panics if `repeating` is `true` and the duration is zero.
+    pub fn set_timer(&mut self, id: u64, duration: Duration, repeating: bool) {
+        let now = self.now();
+        self.timers.set(id, now, duration, repeating);
+    }
+
+    /// Cancels a timer, returning `true` if it existed.
+    pub fn cancel_timer(&mut self, id: u64) -> bool {
+        self.timers.cancel(id)
+    }
+
+    /// Adds an update channel that emits channel update events with the id
+    /// at its own fixed rate, e.g. physics at 120 updates per second and AI at 10.
+    ///
+    /// Adding a channel with the same id as an existing channel replaces it.
+    /// Channel updates are suppressed while paused and are not emitted in lazy mode.
+    /// Delayed channel updates are skipped after lagging more than `ups_reset` updates,
+    /// unless `catch_up` is set to `CatchUp::RunAll`.
+    ///
+    /// Panics if `ups` is `0`.
+    pub fn add_update_channel(&mut self, id: u64, ups: u64) {
+        let now = self.now();
+        self.channels.add(id, now, ups);
+    }
+
+    /// Removes an update channel, returning `true` if it existed.
+    pub fn remove_update_channel(&mut self, id: u64) -> bool {
+        self.channels.remove(id)
+    }
+
+    /// Requests rendering the next frame when `render_on_demand` is enabled.
+    ///
+    /// Call this from any event handler after changing what is shown.
+    pub fn request_redraw(&mut self) {
+        self.redraw_requested = true;
+    }
+
+    /// Returns `true` if the next frame is not rendered, because no redraw was requested.
+    fn skip_render(&self) -> bool {
+        self.settings.render_on_demand && !self.settings.bench_mode && !self.redraw_requested
+    }
+
+    /// Calls an event handler, catching panics when `catch_panics` is enabled.
+    ///
+    /// When the handler panics, a handler panicked event is emitted next time,
+    /// so the application can save its state before closing:
+    ///
+    /// ```ignore
+    /// while let Some(e) = events.next(&mut window) {
+    ///     if e.handler_panicked_args().is_some() {
+    ///         println!("{}", events.last_panic_message().unwrap());
+    ///         app.save();
+    ///         break;
+    ///     }
+    ///     events.handle(|| app.event(&e));
+    /// }
+    /// ```
+    ///
+    /// The state of the application might be inconsistent after a panic.
+    pub fn handle<F>(&mut self, f: F)
+        where F: FnOnce()
+    {
+        if !self.settings.catch_panics {
+            return f();
+        }
+        if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(f)) {
+            let id = self.panics.0;
+            self.panics = (id + 1, Some(panic_message(&*payload)));
+            self.panicked = Some(HandlerPanickedArgs { id: id });
+        }
+    }
+
+    /// Returns the message of the last panic caught by `handle`.
+    pub fn last_panic_message(&self) -> Option<&str> {
+        self.panics.1.as_ref().map(|s| &**s)
+    }
+
+    /// Returns the error message of the last swap failed event.
+    pub fn last_swap_failed_message(&self) -> Option<&str> {
+        self.swap_failures.1.as_ref().map(|s| &**s)
+    }
+
+    /// Returns the simulation time before the next update.
+    fn update_time(&self) -> f64 {
+        let (base_tick, base_time) = self.update_time_base;
+        base_time + (self.update_tick - base_tick) as f64 * self.dt
+    }
+
+    /// Returns the arguments of the next update event.
+    fn next_update_args(&mut self) -> UpdateArgs {
+        let args = UpdateArgs {
+            dt: self.dt,
+            tick: self.update_tick,
+            time: self.update_time(),
+        };
+        self.update_tick += 1;
+        args
+    }
+
+    /// Returns stall arguments if handling the last update or render event
+    /// took longer than the stall threshold.
+    fn check_watchdog(&mut self) -> Option<StallArgs> {
+        let (phase, start) = self.watchdog.take()?;
+        let threshold = self.settings.stall_threshold?;
+        let dt = self.clock.now() - start;
+        if dt > threshold {
+            Some(StallArgs { phase: phase, dt: duration_to_secs(dt) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the next timer or channel update event that is due.
+    fn pop_due(&mut self, now: Instant) -> Option<Event> {
+        if let Some(args) = self.timers.pop_due(now) {
+            return Some(args.into());
+        }
+        if let Some(timeout) = self.settings.inactivity_timeout {
+            if let Some(args) = self.inactivity.pop_due(now, timeout) {
+                return Some(args.into());
+            }
         }
+        if self.paused.is_none() && !self.settings.lazy {
+            let ups_reset = if self.settings.bench_mode ||
+                               self.settings.catch_up == CatchUp::RunAll {
+                0
+            } else {
+                self.settings.ups_reset
+            };
+            if let Some(args) = self.channels.pop_due(now, ups_reset) {
+                return Some(args.into());
+            }
+        }
+        None
+    }
+
+    /// Returns the time of the next timer, inactivity event or channel update.
+    fn next_deadline(&self) -> Option<Instant> {
+        let next_timer = match (self.timers.next_deadline(), self.inactivity_deadline()) {
+            (Some(a), Some(b)) => Some(cmp::min(a, b)),
+            (a, b) => a.or(b),
+        };
+        if self.paused.is_some() || self.settings.lazy {
+            return next_timer;
+        }
+        match (next_timer, self.channels.next_update()) {
+            (Some(a), Some(b)) => Some(cmp::min(a, b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    /// Returns the time of the next frame.
+    ///
+    /// With external frames, the next frame is due when the platform called back for a frame.
+    fn next_frame(&self, frame_dt: Duration, now: Instant) -> Instant {
+        match (self.settings.external_frames, self.frame_requested) {
+            (false, _) => self.last_frame + frame_dt,
+            (true, true) => now,
+            (true, false) => now + frame_dt,
+        }
+    }
+
+    fn inactivity_deadline(&self) -> Option<Instant> {
+        self.inactivity.deadline(self.settings.inactivity_timeout?)
     }
 
     /// Returns the next event.
     pub fn next<W>(&mut self, window: &mut W) -> Option<Event>
         where W: Window
     {
+        match self.step(window, true) {
+            Step::Event(e) => Some(e),
+            Step::Done => None,
+            Step::Wait(_) => unreachable!("Blocking event loop never waits"),
+        }
+    }
+
+    /// Advances the event loop to the next event.
+    ///
+    /// When not blocking, returns the time to wait for instead of waiting.
+    fn step<W>(&mut self, window: &mut W, block: bool) -> Step
+        where W: Window
+    {
+        self.end_handler_phase();
+        let step = if let Some(args) = self.panicked.take() {
+            Step::Event(args.into())
+        } else if let Some(e) = self.resumed_input.take() {
+            Step::Event(e)
+        } else if let Some(args) = self.check_watchdog() {
+            Step::Event(args.into())
+        } else {
+            let step = match self.step_replay(window, block) {
+                Some(step) => step,
+                None => self.advance(window, block),
+            };
+            self.check_inactivity(step)
+        };
+        self.record_timing(&step);
+        if let Step::Event(ref e) = step {
+            self.start_handler_phase(e);
+        }
+        if self.settings.stall_threshold.is_some() {
+            let now = self.clock.now();
+            self.watchdog = match step {
+                Step::Event(Event::Loop(Loop::Update(_))) => Some((StallPhase::Update, now)),
+                Step::Event(Event::Loop(Loop::Render(_))) => Some((StallPhase::Render, now)),
+                _ => None,
+            };
+        }
+        match step {
+            Step::Event(Event::Input(Input::Focus(focused), _)) => self.focused = focused,
+            Step::Event(Event::Input(Input::Lifecycle(Lifecycle::Suspend), _)) => {
+                self.suspended = true;
+            }
+            Step::Event(Event::Input(Input::Lifecycle(Lifecycle::Resume), _)) => {
+                self.suspended = false;
+                // The window content must be drawn again.
+                self.redraw_requested = true;
+            }
+            // The window content must be drawn again.
+            Step::Event(Event::Input(Input::Resize(_), _)) |
+            Step::Event(Event::Input(Input::ScaleFactorChanged(_), _)) |
+            Step::Event(Event::Input(Input::WindowState(_), _)) |
+            Step::Event(Event::Input(Input::Context(_), _)) => self.redraw_requested = true,
+            _ => {}
+        }
+        step
+    }
+
+    fn advance<W>(&mut self, window: &mut W, block: bool) -> Step
+        where W: Window
+    {
+        if self.frame_advance {
+            return self.step_frame_advance(window, block);
+        }
+        if self.settings.bench_mode && self.settings.bench_frames > 0 &&
+           self.frames >= self.settings.bench_frames {
+            if let State::UpdateLoop(_) = self.state {
+                // The benchmark ends after the last frame.
+                return Step::Done;
+            }
+        }
+        let frame_dt = self.frame_dt();
         if self.settings.lazy || self.settings.ups == 0 {
             // This mode does not emit update events.
             // More commonly used in UI applications.
             if window.should_close() {
-                return None;
+                return Step::Done;
             }
             match self.state {
                 State::SwapBuffers => {
+                    let render_end = self.clock.now();
                     if self.settings.swap_buffers {
-                        window.swap_buffers();
+                        if let Some(e) = self.swap_window_buffers(window) {
+                            return Step::Event(e);
+                        }
                     }
                     // This mode needs no `Render` state.
                     self.state = State::UpdateLoop(Idle::No);
-                    return Some(AfterRenderArgs.into());
+                    return Step::Event(self.after_render_args(window, render_end).into());
+                }
+                State::AfterRender => {
+                    self.state = State::UpdateLoop(Idle::No);
+                    let render_end = self.clock.now();
+                    return Step::Event(self.after_render_args(window, render_end).into());
                 }
                 State::HandleEvents => {
                     if !self.settings.bench_mode {
                         // Poll input events until event queue is empty.
-                        if let Some(ev) = window.poll_event() {
-                            return Some(ev);
+                        if let Some(ev) = self.poll_event(window) {
+                            return Step::Event(ev);
                         }
                     }
                     self.state = State::Render;
@@ -172,47 +1037,87 @@ impl Events {
                 // Handle input events before rendering,
                 // because window might be closed and destroy
                 // the graphics context.
-                if let Some(e) = window.poll_event() {
+                if let Some(e) = self.poll_event(window) {
                     if self.settings.bench_mode {
                         // Ignore input events in benchmark mode.
                         // This is to avoid the input events affecting
                         // the application state when benchmarking.
                         continue;
                     } else {
-                        return Some(e);
+                        return Step::Event(e);
                     }
                 }
                 if window.should_close() {
-                    return None;
+                    return Step::Done;
+                }
+                if let State::UpdateLoop(_) = self.state {
+                    if let Some(e) = self.pop_due(self.now()) {
+                        if self.settings.lazy {
+                            // Render after the timer.
+                            self.state = State::HandleEvents;
+                        }
+                        return Step::Event(e);
+                    }
                 }
 
                 if !self.settings.bench_mode {
                     if self.settings.lazy {
                         // A lazy event loop always waits until next event, ignoring time to render.
                         if let State::UpdateLoop(_) = self.state {
-                            // Wait for next input event.
-                            let ev = window.wait_event();
+                            let deadline = self.next_deadline();
+                            if !block {
+                                return Step::Wait(deadline);
+                            }
+                            // Wait for next input event, or until the next timer.
+                            let ev = match deadline {
+                                None => self.wait_event(window),
+                                Some(deadline) => {
+                                    let current_time = self.clock.now();
+                                    let timeout = if deadline > current_time {
+                                        deadline - current_time
+                                    } else {
+                                        Duration::from_secs(0)
+                                    };
+                                    match self.wait_event_timeout(window, timeout) {
+                                        Some(ev) => ev,
+                                        // Emit the timer event.
+                                        None => continue,
+                                    }
+                                }
+                            };
                             // Handle rest of events before rendering.
                             self.state = State::HandleEvents;
-                            return Some(ev);
+                            return Step::Event(ev);
                         }
                     } else {
-                        let current_time = Instant::now();
-                        let next_frame = self.last_frame + ns_to_duration(self.dt_frame_in_ns);
+                        let current_time = self.clock.now();
+                        let next_frame = self.last_frame + frame_dt;
+                        let wait_until = match self.next_deadline() {
+                            Some(deadline) => cmp::min(next_frame, deadline),
+                            None => next_frame,
+                        };
                         if !self.first_frame && next_frame > current_time {
                             if let State::UpdateLoop(Idle::No) = self.state {
                                 // Emit idle event with time until next frame,
                                 // in case the application wants to do some background work.
                                 self.state = State::UpdateLoop(Idle::Yes);
-                                let seconds = duration_to_secs(next_frame - current_time);
-                                return Some(IdleArgs { dt: seconds }.into());
+                                return Step::Event(IdleArgs {
+                                    dt: duration_to_secs(next_frame - current_time),
+                                    sleep_dt: duration_to_secs(wait_until - current_time),
+                                }.into());
                             }
-                            match window.wait_event_timeout(next_frame - current_time) {
-                                None => {}
+                            if !block {
+                                return Step::Wait(Some(wait_until));
+                            }
+                            match self.wait_event_timeout(window, wait_until - current_time) {
+                                None => if wait_until < next_frame {
+                                    // Emit the timer event before rendering.
+                                    continue;
+                                },
                                 Some(x) => {
                                     // Handle rest of events before rendering.
                                     self.state = State::HandleEvents;
-                                    return Some(x)
+                                    return Step::Event(x);
                                 }
                             }
                         }
@@ -222,15 +1127,30 @@ impl Events {
                 self.first_frame = false;
 
                 // In normal mode, let the FPS slip if late.
-                self.last_frame = Instant::now();
+                self.last_frame = self.clock.now();
+
+                if self.skip_render() {
+                    // Wait until a redraw is requested.
+                    self.state = State::UpdateLoop(Idle::No);
+                    continue;
+                }
 
                 let size = window.size();
                 let draw_size = window.draw_size();
                 if size.width != 0.0 && size.height != 0.0 {
                     // Swap buffers next time.
                     self.state = State::SwapBuffers;
-                    return Some(RenderArgs {
+                    self.redraw_requested = false;
+                    let frame = self.frames;
+                    self.frames += 1;
+                    if let Some(ref mut stats) = self.frame_stats {
+                        stats.record_frame(self.clock.now());
+                    }
+                    return Step::Event(RenderArgs {
                         ext_dt: 0.0,
+                        ext_alpha: 0.0,
+                        frame: frame,
+                        time: duration_to_secs(self.last_frame - self.start),
                         window_size: size.into(),
                         draw_size: draw_size.into(),
                     }.into());
@@ -243,44 +1163,91 @@ impl Events {
 
         loop {
             if window.should_close() {
-                return None;
+                return Step::Done;
             }
             self.state = match self.state {
                 State::Render => {
                     // Handle input events before rendering,
                     // because window might be closed and destroy
                     // the graphics context.
-                    if let Some(e) = window.poll_event() {
+                    if let Some(e) = self.poll_event(window) {
                         if self.settings.bench_mode {
                             // Ignore input events in benchmark mode.
                             // This is to avoid the input events affecting
                             // the application state when benchmarking.
                             continue;
                         } else {
-                            return Some(e);
+                            return Step::Event(e);
                         }
                     }
                     if window.should_close() {
-                        return None;
+                        return Step::Done;
+                    }
+
+                    if self.settings.external_frames && !self.frame_requested &&
+                       !self.settings.bench_mode {
+                        // Wait until the platform calls back for a frame.
+                        self.state = State::UpdateLoop(Idle::No);
+                        continue;
+                    }
+                    self.frame_requested = false;
+                    if self.skip_render() {
+                        // Wait until a redraw is requested.
+                        self.last_frame = self.clock.now();
+                        self.updates_since_frame = 0;
+                        self.state = State::UpdateLoop(Idle::No);
+                        continue;
+                    }
+
+                    if let (Some(budget), false) = (self.settings.frame_budget,
+                                                    self.settings.bench_mode) {
+                        let frame_time = self.clock.now() - self.last_frame;
+                        if self.frame_budget_warned {
+                            self.frame_budget_warned = false;
+                        } else if frame_time > budget {
+                            // Emit the lag warning before rendering.
+                            self.frame_budget_warned = true;
+                            return Step::Event(LagWarningArgs {
+                                kind: LagKind::FrameBudget,
+                                lag_dt: duration_to_secs(frame_time),
+                                dropped_dt: 0.0,
+                            }.into());
+                        }
                     }
 
                     if self.settings.bench_mode {
                         // In benchmark mode, pretend FPS is perfect.
-                        self.last_frame += ns_to_duration(self.dt_frame_in_ns);
+                        self.last_frame += frame_dt;
                     } else {
                         // In normal mode, let the FPS slip if late.
-                        self.last_frame = Instant::now();
+                        self.last_frame = self.clock.now();
                     }
+                    self.updates_since_frame = 0;
 
                     let size = window.size();
                     let draw_size = window.draw_size();
                     if size.width != 0.0 && size.height != 0.0 {
                         // Swap buffers next time.
                         self.state = State::SwapBuffers;
-                        return Some(RenderArgs {
-                            // Extrapolate time forward to allow smooth motion.
-                            ext_dt: duration_to_secs(self.last_frame
-                                .duration_since(self.last_update)),
+                        self.redraw_requested = false;
+                        let frame = self.frames;
+                        self.frames += 1;
+                        if let Some(ref mut stats) = self.frame_stats {
+                            stats.record_frame(self.clock.now());
+                        }
+                        // Extrapolate time forward to allow smooth motion.
+                        // While paused, the time is frozen.
+                        let ext_time = match self.paused {
+                            Some(start) => cmp::min(start, self.last_frame),
+                            None => self.last_frame,
+                        };
+                        let ext_dt = duration_to_secs(ext_time.duration_since(self.last_update));
+                        return Step::Event(RenderArgs {
+                            ext_dt: ext_dt,
+                            // The remainder of the update accumulator.
+                            ext_alpha: (ext_dt / self.dt).min(1.0),
+                            frame: frame,
+                            time: duration_to_secs(self.last_frame - self.start),
                             window_size: size.into(),
                             draw_size: draw_size.into(),
                         }.into());
@@ -289,41 +1256,75 @@ impl Events {
                     State::UpdateLoop(Idle::No)
                 }
                 State::SwapBuffers => {
+                    let render_end = self.clock.now();
                     if self.settings.swap_buffers {
-                        window.swap_buffers();
+                        if let Some(e) = self.swap_window_buffers(window) {
+                            return Step::Event(e);
+                        }
                     }
                     self.state = State::UpdateLoop(Idle::No);
-                    return Some(AfterRenderArgs.into());
+                    return Step::Event(self.after_render_args(window, render_end).into());
+                }
+                State::AfterRender => {
+                    self.state = State::UpdateLoop(Idle::No);
+                    let render_end = self.clock.now();
+                    return Step::Event(self.after_render_args(window, render_end).into());
                 }
-                State::UpdateLoop(ref mut idle) => {
+                State::UpdateLoop(idle) => {
                     if self.settings.bench_mode {
                         // In benchmark mode, pick the next event without sleep.
                         // Idle and input events are ignored.
                         // This is to avoid the input events affecting
                         // the application state when benchmarking.
-                        let next_frame = self.last_frame + ns_to_duration(self.dt_frame_in_ns);
+                        let next_frame = self.last_frame + frame_dt;
                         let next_update = self.last_update + ns_to_duration(self.dt_update_in_ns);
-                        let next_event = cmp::min(next_frame, next_update);
+                        // Updates are suppressed while paused.
+                        let next_event = if self.paused.is_some() {
+                            next_frame
+                        } else {
+                            cmp::min(next_frame, next_update)
+                        };
+                        if let Some(e) = self.pop_due(next_event) {
+                            return Step::Event(e);
+                        }
                         if next_event == next_frame {
                             State::Render
                         } else {
                             State::HandleEvents
                         }
                     } else {
-                        let current_time = Instant::now();
-                        let next_frame = self.last_frame + ns_to_duration(self.dt_frame_in_ns);
+                        let current_time = self.clock.now();
+                        if let Some(e) = self.pop_due(current_time) {
+                            self.state = State::UpdateLoop(Idle::No);
+                            return Step::Event(e);
+                        }
+                        let next_frame = self.next_frame(frame_dt, current_time);
                         let next_update = self.last_update + ns_to_duration(self.dt_update_in_ns);
-                        let next_event = cmp::min(next_frame, next_update);
+                        // Updates are suppressed while paused.
+                        let next_event = if self.paused.is_some() {
+                            next_frame
+                        } else {
+                            cmp::min(next_frame, next_update)
+                        };
+                        let wait_until = match self.next_deadline() {
+                            Some(deadline) => cmp::min(next_event, deadline),
+                            None => next_event,
+                        };
                         if next_event > current_time {
-                            if let Some(x) = window.poll_event() {
-                                *idle = Idle::No;
-                                return Some(x);
-                            } else if *idle == Idle::No {
-                                *idle = Idle::Yes;
-                                let seconds = duration_to_secs(next_event - current_time);
-                                return Some(IdleArgs { dt: seconds }.into());
+                            if let Some(x) = self.poll_event(window) {
+                                self.state = State::UpdateLoop(Idle::No);
+                                return Step::Event(x);
+                            } else if idle == Idle::No {
+                                self.state = State::UpdateLoop(Idle::Yes);
+                                return Step::Event(IdleArgs {
+                                    dt: duration_to_secs(next_event - current_time),
+                                    sleep_dt: duration_to_secs(wait_until - current_time),
+                                }.into());
                             }
-                            sleep(next_event - current_time);
+                            if !block {
+                                return Step::Wait(Some(wait_until));
+                            }
+                            self.sleep_until(wait_until);
                             State::UpdateLoop(Idle::No)
                         } else if next_event == next_frame {
                             State::Render
@@ -337,30 +1338,73 @@ impl Events {
                         // Ignore input events.
                         // This is to avoid the input events affecting
                         // the application state when benchmarking.
-                        match window.poll_event() {
+                        match self.poll_event(window) {
                             None => State::Update,
                             Some(_) => State::HandleEvents,
                         }
                     } else {
                         // Handle all events before updating.
-                        match window.poll_event() {
+                        match self.poll_event(window) {
                             None => State::Update,
-                            x => return x,
+                            Some(x) => return Step::Event(x),
                         }
                     }
                 }
                 State::Update => {
                     self.state = State::UpdateLoop(Idle::No);
-                    if !self.settings.bench_mode && self.settings.ups_reset > 0 &&
-                       Instant::now() - self.last_update >
+                    let max_updates = match self.settings.catch_up {
+                        CatchUp::ClampTo(n) => n,
+                        CatchUp::RunAll | CatchUp::SlowDownClock => 0,
+                    };
+                    if !self.settings.bench_mode && max_updates > 0 &&
+                       self.updates_since_frame >= max_updates {
+                        // Drop the remaining lag to render the next frame.
+                        let now = self.clock.now();
+                        let dropped_dt = duration_to_secs(now - self.last_update);
+                        self.last_update = now;
+                        self.updates_since_frame = 0;
+                        return Step::Event(LagWarningArgs {
+                            kind: LagKind::UpdateDebt,
+                            lag_dt: dropped_dt,
+                            dropped_dt: dropped_dt,
+                        }.into());
+                    }
+                    let now = self.clock.now();
+                    if self.skipped_updates {
+                        // The update after the lag warning.
+                        self.skipped_updates = false;
+                    } else if !self.settings.bench_mode && self.settings.ups_reset > 0 &&
+                       self.settings.catch_up == CatchUp::SlowDownClock &&
+                       now - self.last_update >
                        ns_to_duration(self.settings.ups_reset * self.dt_update_in_ns) {
                         // Skip updates because CPU is too busy.
-                        self.last_update = Instant::now();
+                        let lag = now - self.last_update;
+                        self.last_update = now;
+                        self.skipped_updates = true;
+                        // Emit one update after the lag warning.
+                        self.state = State::Update;
+                        let dropped = lag - ns_to_duration(self.dt_update_in_ns);
+                        return Step::Event(LagWarningArgs {
+                            kind: LagKind::UpdateDebt,
+                            lag_dt: duration_to_secs(lag),
+                            dropped_dt: duration_to_secs(dropped),
+                        }.into());
                     } else {
                         // Use the update state stored right after sleep.
                         self.last_update += ns_to_duration(self.dt_update_in_ns);
                     }
-                    return Some(UpdateArgs { dt: self.dt }.into());
+                    self.updates_since_frame += 1;
+                    self.updates += 1;
+                    if let Some(ref mut stats) = self.frame_stats {
+                        stats.record_update(self.clock.now());
+                    }
+                    // Emit the after update event next time.
+                    self.state = State::AfterUpdate;
+                    return Step::Event(self.next_update_args().into());
+                }
+                State::AfterUpdate => {
+                    self.state = State::UpdateLoop(Idle::No);
+                    return Step::Event(AfterUpdateArgs.into());
                 }
             };
         }
@@ -428,6 +1472,86 @@ pub trait EventLoop: Sized {
         self
     }
 
+    /// How to catch up with delayed updates after the event loop lags.
+    fn set_catch_up(&mut self, catch_up: CatchUp) {
+        let old_settings = self.get_event_settings();
+        self.set_event_settings(EventSettings { catch_up: catch_up, ..old_settings })
+    }
+
+    /// How to catch up with delayed updates after the event loop lags.
+    fn catch_up(mut self, catch_up: CatchUp) -> Self {
+        self.set_catch_up(catch_up);
+        self
+    }
+
+    /// The maximum time between two frames before emitting a lag warning event.
+    /// When set to `None`, frame times are not checked.
+    fn set_frame_budget(&mut self, budget: Option<Duration>) {
+        let old_settings = self.get_event_settings();
+        self.set_event_settings(EventSettings { frame_budget: budget, ..old_settings })
+    }
+
+    /// The maximum time between two frames before emitting a lag warning event.
+    /// When set to `None`, frame times are not checked.
+    fn frame_budget(mut self, budget: Option<Duration>) -> Self {
+        self.set_frame_budget(budget);
+        self
+    }
+
+    /// Enable or disable rendering only when a redraw is requested.
+    /// Unlike `lazy`, update events are still emitted.
+    fn set_render_on_demand(&mut self, enable: bool) {
+        let old_settings = self.get_event_settings();
+        self.set_event_settings(EventSettings { render_on_demand: enable, ..old_settings })
+    }
+
+    /// Enable or disable rendering only when a redraw is requested.
+    /// Unlike `lazy`, update events are still emitted.
+    fn render_on_demand(mut self, enable: bool) -> Self {
+        self.set_render_on_demand(enable);
+        self
+    }
+
+    /// The maximum time for handling a single update or render event
+    /// before emitting a stall event.
+    /// When set to `None`, the time is not checked.
+    fn set_stall_threshold(&mut self, threshold: Option<Duration>) {
+        let old_settings = self.get_event_settings();
+        self.set_event_settings(EventSettings { stall_threshold: threshold, ..old_settings })
+    }
+
+    /// The maximum time for handling a single update or render event
+    /// before emitting a stall event.
+    /// When set to `None`, the time is not checked.
+    fn stall_threshold(mut self, threshold: Option<Duration>) -> Self {
+        self.set_stall_threshold(threshold);
+        self
+    }
+
+    /// Enable or disable catching panics of event handlers.
+    fn set_catch_panics(&mut self, enable: bool) {
+        let old_settings = self.get_event_settings();
+        self.set_event_settings(EventSettings { catch_panics: enable, ..old_settings })
+    }
+
+    /// Enable or disable catching panics of event handlers.
+    fn catch_panics(mut self, enable: bool) -> Self {
+        self.set_catch_panics(enable);
+        self
+    }
+
+    /// How to wait until the next frame or update.
+    fn set_sleep_strategy(&mut self, strategy: SleepStrategy) {
+        let old_settings = self.get_event_settings();
+        self.set_event_settings(EventSettings { sleep_strategy: strategy, ..old_settings })
+    }
+
+    /// How to wait until the next frame or update.
+    fn sleep_strategy(mut self, strategy: SleepStrategy) -> Self {
+        self.set_sleep_strategy(strategy);
+        self
+    }
+
     /// Enable or disable automatic swapping of buffers.
     fn set_swap_buffers(&mut self, enable: bool) {
         let old_settings = self.get_event_settings();
@@ -458,6 +1582,20 @@ pub trait EventLoop: Sized {
         self
     }
 
+    /// The number of frames to render in benchmark mode.
+    /// When set to `0`, the event loop runs until the window closes.
+    fn set_bench_frames(&mut self, frames: u64) {
+        let old_settings = self.get_event_settings();
+        self.set_event_settings(EventSettings { bench_frames: frames, ..old_settings })
+    }
+
+    /// The number of frames to render in benchmark mode.
+    /// When set to `0`, the event loop runs until the window closes.
+    fn bench_frames(mut self, frames: u64) -> Self {
+        self.set_bench_frames(frames);
+        self
+    }
+
     /// Enable or disable rendering only when receiving input.
     /// When enabled, update events are disabled.
     /// Idle events are emitted while receiving input.
@@ -473,6 +1611,60 @@ pub trait EventLoop: Sized {
         self.set_lazy(enable);
         self
     }
+
+    /// The maximum number of frames per second while paused.
+    /// When set to `0`, uses `max_fps`.
+    fn set_paused_max_fps(&mut self, frames: u64) {
+        let old_settings = self.get_event_settings();
+        self.set_event_settings(EventSettings { paused_max_fps: frames, ..old_settings })
+    }
+
+    /// The maximum number of frames per second while paused.
+    /// When set to `0`, uses `max_fps`.
+    fn paused_max_fps(mut self, frames: u64) -> Self {
+        self.set_paused_max_fps(frames);
+        self
+    }
+
+    /// The maximum number of frames per second while the window is not focused.
+    /// When set to `0`, uses `max_fps`.
+    fn set_unfocused_max_fps(&mut self, frames: u64) {
+        let old_settings = self.get_event_settings();
+        self.set_event_settings(EventSettings { unfocused_max_fps: frames, ..old_settings })
+    }
+
+    /// The maximum number of frames per second while the window is not focused.
+    /// When set to `0`, uses `max_fps`.
+    fn unfocused_max_fps(mut self, frames: u64) -> Self {
+        self.set_unfocused_max_fps(frames);
+        self
+    }
+
+    /// The time without user input before emitting an inactivity event.
+    /// When set to `None`, inactivity is not detected.
+    fn set_inactivity_timeout(&mut self, timeout: Option<Duration>) {
+        let old_settings = self.get_event_settings();
+        self.set_event_settings(EventSettings { inactivity_timeout: timeout, ..old_settings })
+    }
+
+    /// The time without user input before emitting an inactivity event.
+    /// When set to `None`, inactivity is not detected.
+    fn inactivity_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.set_inactivity_timeout(timeout);
+        self
+    }
+
+    /// Enable or disable rendering only when the platform calls back for a frame.
+    fn set_external_frames(&mut self, enable: bool) {
+        let old_settings = self.get_event_settings();
+        self.set_event_settings(EventSettings { external_frames: enable, ..old_settings })
+    }
+
+    /// Enable or disable rendering only when the platform calls back for a frame.
+    fn external_frames(mut self, enable: bool) -> Self {
+        self.set_external_frames(enable);
+        self
+    }
 }
 
 impl EventLoop for EventSettings {
@@ -489,7 +1681,737 @@ impl EventLoop for Events {
         self.settings
     }
     fn set_event_settings(&mut self, settings: EventSettings) {
-        // Reset event loop to initial state.
-        *self = Events::new(settings);
+        if let Err(err) = self.try_set_event_settings(settings) {
+            panic!("{}", err);
+        }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use input::{AfterRenderEvent, AfterUpdateEvent, ChannelUpdateEvent, ContextEvent, FocusEvent,
+                HandlerPanickedEvent, IdleEvent, InactivityEvent, LagWarningEvent, LifecycleEvent,
+                MidiEvent, MidiMessage, RenderEvent, StallEvent, SwapFailedEvent, TextEvent,
+                TimerEvent, UpdateEvent, WindowState};
+    use std::thread;
+    use window::{HeadlessWindow, NoWindow, WindowSettings};
+
+    fn test_window() -> HeadlessWindow {
+        HeadlessWindow::new(&WindowSettings::new("test", [100, 100]))
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(EventSettings::game().validate().is_ok());
+        assert!(EventSettings::gui().validate().is_ok());
+        let err = Events::try_new(EventSettings::new().max_fps(0)).err().unwrap();
+        assert_eq!(err.setting, "max_fps");
+        let err = EventSettings::new().catch_up(CatchUp::ClampTo(0)).validate().unwrap_err();
+        assert_eq!(err.setting, "catch_up");
+        let err = EventSettings::gui().bench_mode(true).validate().unwrap_err();
+        assert_eq!(err.setting, "bench_mode");
+    }
+
+    #[test]
+    fn test_try_set_event_settings() {
+        let mut events = Events::new(EventSettings::gui());
+        let err = events.try_set_event_settings(EventSettings::gui().bench_mode(true)).unwrap_err();
+        assert_eq!(err.setting, "bench_mode");
+        assert!(!events.get_event_settings().bench_mode);
+        assert!(events.try_set_event_settings(EventSettings::gui().max_fps(30)).is_ok());
+        assert_eq!(events.get_event_settings().max_fps, 30);
+    }
+
+    #[test]
+    #[should_panic(expected = "max_fps")]
+    fn test_set_max_fps_zero() {
+        let mut events = Events::new(EventSettings::new());
+        events.set_max_fps(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "bench_mode")]
+    fn test_set_bench_mode_lazy() {
+        let mut events = Events::new(EventSettings::gui());
+        events.set_bench_mode(true);
+    }
+
+    #[test]
+    fn test_bench_frames() {
+        let run = || {
+            let mut window = test_window();
+            let mut events = Events::new(EventSettings::new()).bench_mode(true).bench_frames(10);
+            while events.next(&mut window).is_some() {}
+            events.bench_report()
+        };
+        let a = run();
+        let b = run();
+        assert_eq!(a.frames, 10);
+        assert_eq!(a.updates, 19);
+        assert_eq!((a.frames, a.updates), (b.frames, b.updates));
+    }
+
+    #[test]
+    fn test_ext_alpha() {
+        let mut window = test_window();
+        let mut events = Events::new(EventSettings::new()).ups(100).max_fps(40).bench_mode(true);
+        let mut alphas = vec![];
+        while alphas.len() < 3 {
+            let e = events.next(&mut window).unwrap();
+            if let Some(args) = e.render_args() {
+                alphas.push(args.ext_alpha);
+            }
+        }
+        assert_eq!(alphas[0], 1.0);
+        assert_eq!(alphas[1], 1.0);
+        assert!((alphas[2] - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_catch_up_clamp() {
+        let mut window = test_window();
+        let mut events = Events::new(EventSettings::new())
+            .ups(1000).max_fps(1).catch_up(CatchUp::ClampTo(3));
+        assert!(events.next(&mut window).unwrap().render_args().is_some());
+        thread::sleep(Duration::from_millis(20));
+        let mut updates = 0;
+        let lag = loop {
+            let e = events.next(&mut window).unwrap();
+            if e.update_args().is_some() {
+                updates += 1;
+            }
+            if let Some(args) = e.lag_warning_args() {
+                break args;
+            }
+        };
+        assert_eq!(updates, 3);
+        assert_eq!(lag.kind, LagKind::UpdateDebt);
+        assert!(lag.dropped_dt > 0.01);
+    }
+
+    #[test]
+    fn test_paused() {
+        let mut window = test_window();
+        let mut events = Events::new(EventSettings::new())
+            .ups(100).max_fps(50).paused_max_fps(10).bench_mode(true);
+        events.set_paused(true);
+        let mut frames = 0;
+        while frames < 5 {
+            let e = events.next(&mut window).unwrap();
+            assert!(e.update_args().is_none());
+            if let Some(args) = e.render_args() {
+                assert_eq!(args.ext_dt, 0.0);
+                frames += 1;
+            }
+        }
+        assert_eq!(events.bench_report().updates, 0);
+        assert!(events.is_paused());
+        events.set_paused(false);
+        while events.next(&mut window).unwrap().update_args().is_none() {}
+        assert_eq!(events.bench_report().frames, 5);
+    }
+
+    #[test]
+    fn test_proxy() {
+        let mut window = NoWindow::new(&WindowSettings::new("test", [100, 100]));
+        let mut events = Events::new(EventSettings::gui());
+        let proxy = events.create_proxy(&window);
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            proxy.send(Input::Text("hello".into()));
+        });
+        let text = loop {
+            if let Some(text) = events.next(&mut window).unwrap().text_args() {
+                break text;
+            }
+        };
+        assert_eq!(text, "hello");
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_send_midi() {
+        let mut window = NoWindow::new(&WindowSettings::new("test", [100, 100]));
+        let mut events = Events::new(EventSettings::gui());
+        let proxy = events.create_proxy(&window);
+        assert!(!proxy.send_midi(0, None, &[0xf8]));
+        assert!(proxy.send_midi(0, Some(0.25), &[0x90, 60, 100]));
+        let args = events.next(&mut window).unwrap().midi_args().unwrap();
+        assert_eq!(args.message, MidiMessage::NoteOn {channel: 0, note: 60, velocity: 100});
+        assert_eq!(args.time, Some(0.25));
+    }
+
+    #[test]
+    fn test_suspend() {
+        let mut window = test_window();
+        window.push_event(Input::Lifecycle(Lifecycle::Suspend));
+        let mut events = Events::new(EventSettings::new());
+        while events.next(&mut window).unwrap().lifecycle_args().is_none() {}
+        assert!(events.is_suspended());
+        events.set_event_settings(EventSettings::gui());
+        assert!(events.is_suspended());
+        window.push_event(Input::Lifecycle(Lifecycle::Resume));
+        while events.next(&mut window).unwrap().lifecycle_args().is_none() {}
+        assert!(!events.is_suspended());
+    }
+
+    #[test]
+    fn test_unfocused_max_fps() {
+        let mut window = test_window();
+        window.push_event(Input::Focus(false));
+        let mut events = Events::new(EventSettings::new()).unfocused_max_fps(10);
+        assert_eq!(events.frame_dt(), ns_to_duration(BILLION / 60));
+        while events.next(&mut window).unwrap().focus_args().is_none() {}
+        assert_eq!(events.frame_dt(), Duration::from_millis(100));
+        events.set_paused(true);
+        assert_eq!(events.frame_dt(), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_timer() {
+        let mut window = test_window();
+        let mut events = Events::new(EventSettings::new())
+            .ups(100).max_fps(10).bench_mode(true).bench_frames(10);
+        events.set_timer(1, Duration::from_millis(250), true);
+        events.set_timer(2, Duration::from_millis(50), false);
+        let mut timers = vec![];
+        while let Some(e) = events.next(&mut window) {
+            if let Some(args) = e.timer_args() {
+                timers.push(args.id);
+            }
+        }
+        // The last frame is rendered after 1 second.
+        assert_eq!(timers, vec![2, 1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn test_after_update() {
+        let mut window = test_window();
+        let mut events = Events::new(EventSettings::new()).bench_mode(true).bench_frames(3);
+        let mut last_update = false;
+        let mut after_updates = 0;
+        while let Some(e) = events.next(&mut window) {
+            if e.after_update_args().is_some() {
+                assert!(last_update);
+                after_updates += 1;
+            }
+            last_update = e.update_args().is_some();
+        }
+        assert_eq!(after_updates, events.bench_report().updates);
+    }
+
+    #[test]
+    fn test_catch_up_run_all() {
+        let mut window = test_window();
+        let mut events = Events::new(EventSettings::new())
+            .ups(1000).max_fps(1).catch_up(CatchUp::RunAll);
+        assert!(events.next(&mut window).unwrap().render_args().is_some());
+        thread::sleep(Duration::from_millis(20));
+        let mut updates = 0;
+        while updates < 15 {
+            let e = events.next(&mut window).unwrap();
+            assert!(e.render_args().is_none() && e.lag_warning_args().is_none());
+            if e.update_args().is_some() {
+                updates += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn test_update_channels() {
+        let mut window = test_window();
+        let mut events = Events::new(EventSettings::new())
+            .ups(100).max_fps(10).bench_mode(true).bench_frames(10);
+        events.add_update_channel(1, 20);
+        events.add_update_channel(2, 5);
+        let mut counts = [0, 0];
+        while let Some(e) = events.next(&mut window) {
+            if let Some(args) = e.channel_update_args() {
+                let dt = if args.id == 1 {0.05} else {0.2};
+                assert!((args.dt - dt).abs() < 1e-9);
+                counts[args.id as usize - 1] += 1;
+            }
+        }
+        assert_eq!(counts, [20, 5]);
+    }
+
+    #[test]
+    fn test_mock_clock() {
+        let mut window = test_window();
+        let clock = MockClock::new();
+        let mut events = Events::new(EventSettings::new()).ups(10).max_fps(5);
+        events.set_time_source(Arc::new(clock.clone()));
+        let mut frames = 0;
+        let mut updates = 0;
+        while frames < 11 {
+            let e = events.next(&mut window).unwrap();
+            if e.render_args().is_some() {
+                frames += 1;
+            }
+            if e.update_args().is_some() {
+                updates += 1;
+            }
+        }
+        assert_eq!(clock.elapsed(), Duration::from_secs(2));
+        assert_eq!(updates, 19);
+    }
+
+    #[test]
+    fn test_headless_shared_clock() {
+        let mut window = test_window();
+        let clock = MockClock::new();
+        window.set_time_source(Arc::new(clock.clone()));
+        window.push_event_at(Duration::from_millis(250), Input::Text("hello".into()));
+        let mut events = Events::new(EventSettings::new()).ups(10).max_fps(5);
+        events.set_time_source(Arc::new(clock.clone()));
+        while events.next(&mut window).unwrap().text_args().is_none() {}
+        assert!(clock.elapsed() >= Duration::from_millis(250));
+        assert!(clock.elapsed() < Duration::from_millis(350));
+    }
+
+    #[test]
+    fn test_lag_warning() {
+        let mut window = test_window();
+        let clock = MockClock::new();
+        let mut events = Events::new(EventSettings::new())
+            .ups(10).max_fps(5).frame_budget(Some(Duration::from_millis(150)));
+        events.set_time_source(Arc::new(clock.clone()));
+        assert!(events.next(&mut window).unwrap().render_args().is_some());
+
+        // Skipping delayed updates emits a lag warning before the update.
+        clock.advance(Duration::from_millis(350));
+        let lag = loop {
+            if let Some(args) = events.next(&mut window).unwrap().lag_warning_args() {
+                break args;
+            }
+        };
+        assert_eq!(lag.kind, LagKind::UpdateDebt);
+        assert!((lag.lag_dt - 0.35).abs() < 1e-9);
+        assert!((lag.dropped_dt - 0.25).abs() < 1e-9);
+        assert!(events.next(&mut window).unwrap().update_args().is_some());
+
+        // The late frame is rendered after the lag warning.
+        let lag = loop {
+            if let Some(args) = events.next(&mut window).unwrap().lag_warning_args() {
+                break args;
+            }
+        };
+        assert_eq!(lag.kind, LagKind::FrameBudget);
+        assert!(lag.lag_dt > 0.15);
+        assert!(events.next(&mut window).unwrap().render_args().is_some());
+    }
+
+    #[test]
+    fn test_render_on_demand() {
+        let mut window = test_window();
+        let clock = MockClock::new();
+        let mut events = Events::new(EventSettings::new())
+            .ups(10).max_fps(5).render_on_demand(true);
+        events.set_time_source(Arc::new(clock.clone()));
+        let mut run = |events: &mut Events, updates: u32| {
+            let mut frames = 0;
+            let mut n = 0;
+            while n < updates {
+                let e = events.next(&mut window).unwrap();
+                if e.render_args().is_some() {
+                    frames += 1;
+                }
+                if e.update_args().is_some() {
+                    n += 1;
+                }
+            }
+            frames
+        };
+        // The first frame is always rendered.
+        assert_eq!(run(&mut events, 10), 1);
+        events.request_redraw();
+        assert_eq!(run(&mut events, 10), 1);
+        assert_eq!(run(&mut events, 10), 0);
+    }
+
+    #[test]
+    fn test_frame_advance() {
+        let mut window = test_window();
+        let mut events = Events::new(EventSettings::new()).ups(100);
+        events.set_frame_advance(true);
+        events.advance_frame();
+        events.advance_frame();
+        let mut frames = vec![];
+        while let Some(e) = events.next(&mut window) {
+            if let Some(args) = e.update_args() {
+                assert_eq!(args.dt, 0.01);
+                frames.push("update");
+            }
+            if e.render_args().is_some() {
+                frames.push("render");
+            }
+        }
+        // The initial frame is rendered before advancing.
+        assert_eq!(frames, vec!["render", "update", "render", "update", "render"]);
+    }
+
+    #[test]
+    fn test_replay_timing() {
+        let window = || {
+            let mut window = test_window();
+            window.push_event(Input::Text("a".into()));
+            window.push_event(Input::Text("b".into()));
+            window
+        };
+        let mut live_window = window();
+        let mut events = Events::new(EventSettings::new()).ups(100).max_fps(30);
+        events.set_time_source(Arc::new(MockClock::new()));
+        events.start_timing_record();
+        let live: Vec<Event> = (0..40).map(|_| events.next(&mut live_window).unwrap()).collect();
+        let record = events.stop_timing_record().unwrap();
+        assert_eq!(record.len(), 40);
+
+        // The replay ignores the time source.
+        let mut replay_window = window();
+        let mut events = Events::new(EventSettings::new());
+        events.replay_timing(record);
+        let replay: Vec<Event> = (0..40)
+            .map(|_| events.next(&mut replay_window).unwrap())
+            .collect();
+        assert_eq!(live, replay);
+        assert!(events.is_replaying_timing());
+        events.next(&mut replay_window);
+        assert!(!events.is_replaying_timing());
+    }
+
+    #[test]
+    fn test_replay_timing_loop_input() {
+        let window = || {
+            let mut window = test_window();
+            window.push_event(Input::Text("a".into()));
+            window.push_swap_error(SwapBuffersError::ContextLost);
+            window
+        };
+        let mut live_window = window();
+        let mut events = Events::new(EventSettings::new()).ups(100).max_fps(30);
+        events.set_time_source(Arc::new(MockClock::new()));
+        events.start_timing_record();
+        let live: Vec<Event> = (0..20).map(|_| events.next(&mut live_window).unwrap()).collect();
+        let record = events.stop_timing_record().unwrap();
+        assert_eq!(live.iter().filter(|e| e.context_args().is_some()).count(), 1);
+        // The lost context is emitted by the event loop and not recorded.
+        assert_eq!(record.len(), 19);
+        assert_eq!(record.iter().filter(|&e| *e == TimingEntry::Input).count(), 1);
+
+        let mut replay_window = window();
+        let mut events = Events::new(EventSettings::new());
+        events.replay_timing(record);
+        let replay: Vec<Event> = (0..20)
+            .map(|_| events.next(&mut replay_window).unwrap())
+            .collect();
+        assert_eq!(live, replay);
+    }
+
+    #[test]
+    fn test_stall_threshold() {
+        let mut window = test_window();
+        let clock = MockClock::new();
+        let mut events = Events::new(EventSettings::new())
+            .stall_threshold(Some(Duration::from_millis(50)));
+        events.set_time_source(Arc::new(clock.clone()));
+        assert!(events.next(&mut window).unwrap().render_args().is_some());
+        clock.advance(Duration::from_millis(80));
+        let args = events.next(&mut window).unwrap().stall_args().unwrap();
+        assert_eq!(args.phase, StallPhase::Render);
+        assert!((args.dt - 0.08).abs() < 1e-9);
+        assert!(events.next(&mut window).unwrap().after_render_args().is_some());
+    }
+
+    #[test]
+    fn test_catch_panics() {
+        let mut window = test_window();
+        let mut events = Events::new(EventSettings::new()).catch_panics(true);
+        events.handle(|| panic!("oops"));
+        let args = events.next(&mut window).unwrap().handler_panicked_args().unwrap();
+        assert_eq!(args.id, 0);
+        assert_eq!(events.last_panic_message(), Some("oops"));
+        let mut called = false;
+        events.handle(|| called = true);
+        assert!(called);
+    }
+
+    #[test]
+    fn test_render_frame_time() {
+        let mut window = test_window();
+        let mut events = Events::new(EventSettings::new()).max_fps(50).bench_mode(true);
+        let mut frames = vec![];
+        while frames.len() < 3 {
+            if let Some(args) = events.next(&mut window).unwrap().render_args() {
+                frames.push((args.frame, args.time));
+            }
+        }
+        assert_eq!(frames[0].0, 0);
+        assert_eq!(frames[2].0, 2);
+        assert!((frames[0].1 - 0.02).abs() < 1e-9);
+        assert!((frames[2].1 - 0.06).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_render_frame_time_max_fps() {
+        let mut window = test_window();
+        let mut events = Events::new(EventSettings::new()).max_fps(50);
+        events.set_time_source(Arc::new(MockClock::new()));
+        let mut frames = vec![];
+        let mut changed = false;
+        while frames.len() < 6 {
+            let e = events.next(&mut window).unwrap();
+            if let Some(args) = e.render_args() {
+                frames.push((args.frame, args.time));
+            }
+            if frames.len() == 3 && !changed && e.update_args().is_some() {
+                events.set_max_fps(20);
+                changed = true;
+            }
+        }
+        // The frame number and time continue after changing the frame rate.
+        for (i, w) in frames.windows(2).enumerate() {
+            assert_eq!(w[1].0, i as u64 + 1);
+            assert!(w[1].1 > w[0].1);
+        }
+        assert!((frames[5].1 - frames[4].1 - 0.05).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_update_tick_time() {
+        let mut window = test_window();
+        let mut events = Events::new(EventSettings::new()).ups(100).bench_mode(true);
+        let mut updates = vec![];
+        while updates.len() < 3 {
+            if let Some(args) = events.next(&mut window).unwrap().update_args() {
+                updates.push(args);
+            }
+        }
+        assert_eq!(updates[2].tick, 2);
+        assert!((updates[2].time - 0.02).abs() < 1e-9);
+
+        // The tick and time continue after changing the update rate.
+        events.set_ups(10);
+        let args = loop {
+            if let Some(args) = events.next(&mut window).unwrap().update_args() {
+                break args;
+            }
+        };
+        assert_eq!(args.tick, 3);
+        assert!((args.time - 0.03).abs() < 1e-9);
+        assert_eq!(args.dt, 0.1);
+    }
+
+    #[test]
+    fn test_gpu_frame_time() {
+        let mut window = test_window();
+        window.set_gpu_frame_time(Some(Duration::from_secs(1)));
+        let mut events = Events::new(EventSettings::new());
+        events.enable_frame_stats(Duration::from_secs(10));
+        let args = loop {
+            if let Some(args) = events.next(&mut window).unwrap().after_render_args() {
+                break args;
+            }
+        };
+        assert_eq!(args.gpu_dt, Some(1.0));
+        let stats = events.frame_stats().unwrap();
+        assert_eq!(stats.mean_gpu_time(), Some(Duration::from_secs(1)));
+        assert_eq!(stats.gpu_bound_frames(), 1);
+        assert_eq!(stats.cpu_bound_frames(), 0);
+    }
+
+    #[test]
+    fn test_swap_errors() {
+        let mut window = test_window();
+        window.push_swap_error(SwapBuffersError::ContextLost);
+        window.push_swap_error(SwapBuffersError::SurfaceLost);
+        window.push_swap_error(SwapBuffersError::DeviceRemoved);
+        window.push_swap_error(SwapBuffersError::Other("timeout".into()));
+        let mut events = Events::new(EventSettings::new());
+        events.set_time_source(Arc::new(MockClock::new()));
+        let mut errors = vec![];
+        let mut after_render = 0;
+        while after_render < 5 {
+            let e = events.next(&mut window).unwrap();
+            if let Some(state) = e.context_args() {
+                errors.push(format!("{:?}", state));
+            }
+            if let Some(args) = e.swap_failed_args() {
+                assert_eq!(args.id, 0);
+                errors.push(events.last_swap_failed_message().unwrap().to_string());
+            }
+            if e.after_render_args().is_some() {
+                after_render += 1;
+                // Every error is followed by the after render event of the frame.
+                assert_eq!(errors.len(), cmp::min(after_render, 4));
+            }
+        }
+        assert_eq!(errors, vec!["Lost", "SurfaceLost", "DeviceRemoved", "timeout"]);
+        assert_eq!(window.swap_count(), 1);
+    }
+
+    #[test]
+    fn test_idle_sleep_dt() {
+        let mut window = test_window();
+        let mut events = Events::new(EventSettings::new()).ups(1).max_fps(1);
+        events.set_time_source(Arc::new(MockClock::new()));
+        events.set_timer(0, Duration::from_millis(100), false);
+        let args = loop {
+            if let Some(args) = events.next(&mut window).unwrap().idle_args() {
+                break args;
+            }
+        };
+        assert_eq!(args.dt, 1.0);
+        assert_eq!(args.sleep_dt, 0.1);
+    }
+
+    #[test]
+    fn test_run() {
+        let mut window = test_window();
+        window.push_event(Input::Text("hello".into()));
+        let settings = EventSettings::new().catch_panics(true);
+        let mut texts = 0;
+        let mut panics = 0;
+        Events::run(&mut window, settings, |e, window| {
+            if e.text_args().is_some() {
+                texts += 1;
+                panic!("oops");
+            }
+            if e.handler_panicked_args().is_some() {
+                panics += 1;
+                window.set_should_close(true);
+            }
+        });
+        assert_eq!(texts, 1);
+        assert_eq!(panics, 1);
+    }
+
+    #[test]
+    fn test_pump_events() {
+        let mut window = test_window();
+        let mut events = Events::new(EventSettings::new().max_fps(1).ups(1));
+        let mut renders = 0;
+        // The first pump renders the first frame and waits for the next frame.
+        let status = events.pump_events(&mut window, |e, _| {
+            if e.render_args().is_some() {
+                renders += 1;
+            }
+        });
+        assert_eq!(renders, 1);
+        match status {
+            PumpStatus::Wait(Some(_)) => {}
+            _ => panic!("Expected waiting for the next frame"),
+        }
+
+        // The event loop pauses while the window is minimized.
+        window.push_event(Input::WindowState(WindowState::Minimized));
+        events.pump_events(&mut window, |_, _| {});
+        assert!(events.is_paused());
+        window.push_event(Input::WindowState(WindowState::Normal));
+        events.pump_events(&mut window, |_, _| {});
+        assert!(!events.is_paused());
+    }
+
+    #[test]
+    fn test_external_frames() {
+        let mut window = test_window();
+        let clock = MockClock::new();
+        let mut events = Events::new(EventSettings::new().ups(10).external_frames(true));
+        events.set_time_source(Arc::new(clock.clone()));
+        let mut renders = 0;
+        let mut updates = 0;
+        let mut count = |e: Event, _: &mut HeadlessWindow| {
+            if e.render_args().is_some() {
+                renders += 1;
+            }
+            if e.update_args().is_some() {
+                updates += 1;
+            }
+        };
+        // Pumping events between frames does not render.
+        events.pump_events(&mut window, &mut count);
+        clock.advance(Duration::from_millis(150));
+        events.pump_frame(&mut window, &mut count);
+        clock.advance(Duration::from_millis(100));
+        events.pump_events(&mut window, &mut count);
+        events.pump_frame(&mut window, &mut count);
+        assert_eq!(renders, 2);
+        assert_eq!(updates, 2);
+    }
+
+    #[test]
+    fn test_phase_profiler() {
+        use std::sync::Mutex;
+
+        struct Recorder(Mutex<Vec<(bool, Phase)>>);
+
+        impl PhaseProfiler for Recorder {
+            fn on_phase_start(&self, phase: Phase) {
+                self.0.lock().unwrap().push((true, phase));
+            }
+            fn on_phase_end(&self, phase: Phase) {
+                self.0.lock().unwrap().push((false, phase));
+            }
+        }
+
+        let mut window = test_window();
+        let recorder = Arc::new(Recorder(Mutex::new(vec![])));
+        let mut events = Events::new(EventSettings::new()).bench_mode(true);
+        events.set_phase_profiler(Some(recorder.clone()));
+        for _ in 0..20 {
+            events.next(&mut window);
+        }
+        events.set_phase_profiler(None);
+        let phases = recorder.0.lock().unwrap();
+        for &phase in &[Phase::Poll, Phase::Update, Phase::Render, Phase::Swap] {
+            assert!(phases.contains(&(true, phase)));
+        }
+        // Every phase ends before the next starts.
+        for pair in phases.chunks(2) {
+            assert_eq!(pair.len(), 2);
+            assert!(pair[0].0 && !pair[1].0);
+            assert_eq!(pair[0].1, pair[1].1);
+        }
+    }
+
+    #[test]
+    fn test_lazy_timer() {
+        let mut window = NoWindow::new(&WindowSettings::new("test", [100, 100]));
+        let mut events = Events::new(EventSettings::gui());
+        // Renders the first frame and sleeps until the timer.
+        assert!(events.next(&mut window).unwrap().render_args().is_some());
+        assert!(events.next(&mut window).unwrap().after_render_args().is_some());
+        events.set_timer(1, Duration::from_millis(20), false);
+        assert_eq!(events.next(&mut window).unwrap().timer_args().map(|args| args.id), Some(1));
+        assert!(events.next(&mut window).unwrap().render_args().is_some());
+    }
+
+    #[test]
+    fn test_inactivity() {
+        let mut window = test_window();
+        let mut events = Events::new(EventSettings::new().max_fps(10).ups(10))
+            .inactivity_timeout(Some(Duration::from_secs(1)));
+        events.set_time_source(Arc::new(MockClock::new()));
+        let args = loop {
+            if let Some(args) = events.next(&mut window).unwrap().inactivity_args() {
+                break args;
+            }
+        };
+        assert!(args.inactive);
+        assert_eq!(args.idle_time, 1.0);
+        assert!(events.is_inactive());
+
+        // The user input follows the inactivity event.
+        window.push_event(Input::Text("hello".into()));
+        let args = loop {
+            if let Some(args) = events.next(&mut window).unwrap().inactivity_args() {
+                break args;
+            }
+        };
+        assert!(!args.inactive);
+        assert!(args.idle_time >= 1.0);
+        assert!(!events.is_inactive());
+        assert!(events.next(&mut window).unwrap().text_args().is_some());
     }
 }
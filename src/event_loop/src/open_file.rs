@@ -0,0 +1,53 @@
+//! Delivering files that launched the application.
+
+use std::env;
+use std::path::PathBuf;
+
+use input::Input;
+
+use Events;
+
+/// Returns the arguments that are paths with one of the extensions, ignoring case.
+fn files_in_args<I>(args: I, extensions: &[&str]) -> Vec<PathBuf>
+    where I: IntoIterator<Item = PathBuf>
+{
+    args.into_iter()
+        .filter(|path| match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) => extensions.iter().any(|e| e.eq_ignore_ascii_case(ext)),
+            None => false,
+        })
+        .collect()
+}
+
+impl Events {
+    /// Emits open file events for command line arguments that are existing files
+    /// with one of the extensions, such as `"map"`, returning the number of files.
+    ///
+    /// Platforms that launch the application with an associated file as command line
+    /// argument deliver it this way, so it is emitted before events from the window.
+    /// Files opened while running are emitted by the window backend,
+    /// or can be sent with an [`EventLoopProxy`](./struct.EventLoopProxy.html),
+    /// e.g. when a second instance forwards its arguments.
+    pub fn open_startup_files(&mut self, extensions: &[&str]) -> usize {
+        let files = files_in_args(env::args_os().skip(1).map(PathBuf::from), extensions);
+        let mut queue = self.proxy_queue.lock().unwrap();
+        let mut n = 0;
+        for file in files.into_iter().filter(|file| file.is_file()) {
+            queue.push_back(Input::OpenFile(file).into());
+            n += 1;
+        }
+        n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_files_in_args() {
+        let args = vec!["--verbose".into(), "levels/one.MAP".into(), "map".into(),
+                        "notes.txt".into()];
+        assert_eq!(files_in_args(args, &["map", "tiles"]), vec![PathBuf::from("levels/one.MAP")]);
+    }
+}
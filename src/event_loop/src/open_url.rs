@@ -0,0 +1,52 @@
+//! Delivering URLs that launched the application.
+
+use std::env;
+
+use input::Input;
+
+use Events;
+
+/// Returns the arguments that are URLs with the scheme, ignoring case of the scheme.
+fn urls_in_args<I>(args: I, scheme: &str) -> Vec<String>
+    where I: IntoIterator<Item = String>
+{
+    args.into_iter()
+        .filter(|arg| {
+            arg.len() > scheme.len() && arg.is_char_boundary(scheme.len()) &&
+            arg[..scheme.len()].eq_ignore_ascii_case(scheme) &&
+            arg[scheme.len()..].starts_with(':')
+        })
+        .collect()
+}
+
+impl Events {
+    /// Emits open URL events for command line arguments that are URLs with the scheme,
+    /// such as `mygame` for `mygame://join/1234`, returning the number of URLs.
+    ///
+    /// Platforms that launch the application with a URL as command line argument
+    /// deliver it this way, so it is emitted before events from the window.
+    /// URLs opened while running are emitted by the window backend,
+    /// or can be sent with an [`EventLoopProxy`](./struct.EventLoopProxy.html),
+    /// e.g. when a second instance forwards its arguments.
+    pub fn open_startup_urls(&mut self, scheme: &str) -> usize {
+        let urls = urls_in_args(env::args().skip(1), scheme);
+        let mut queue = self.proxy_queue.lock().unwrap();
+        for url in &urls {
+            queue.push_back(Input::OpenUrl(url.clone()).into());
+        }
+        urls.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_urls_in_args() {
+        let args = vec!["--verbose".into(), "MyGame://join/1234".into(), "mygame".into(),
+                        "mygamer://x".into(), "mygame:settings".into()];
+        assert_eq!(urls_in_args(args, "mygame"),
+                   vec!["MyGame://join/1234".to_string(), "mygame:settings".into()]);
+    }
+}
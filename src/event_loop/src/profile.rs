@@ -0,0 +1,83 @@
+//! Profiling hooks for phases of the event loop.
+
+use std::sync::Arc;
+
+use input::{Event, Loop};
+
+use Events;
+
+/// A phase of the event loop.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Phase {
+    /// Polling input events from the window.
+    Poll,
+    /// Handling an update event.
+    Update,
+    /// Handling a render event.
+    Render,
+    /// Swapping buffers.
+    Swap,
+    /// Sleeping or waiting for input.
+    Idle,
+}
+
+/// Receives the start and end of every phase of the event loop.
+///
+/// Use this to annotate flamegraphs or to show an in-game profiler,
+/// see [`Events::set_phase_profiler`](./struct.Events.html#method.set_phase_profiler).
+/// The update and render phases last until the next event is requested,
+/// which includes the time spent handling the event.
+/// The hooks are called on the thread running the event loop and should return quickly.
+pub trait PhaseProfiler: Send + Sync {
+    /// Called when a phase starts.
+    fn on_phase_start(&self, phase: Phase);
+
+    /// Called when a phase ends.
+    fn on_phase_end(&self, phase: Phase);
+}
+
+impl Events {
+    /// Sets the profiler that receives the phases of the event loop.
+    ///
+    /// Set to `None` to disable profiling.
+    pub fn set_phase_profiler(&mut self, profiler: Option<Arc<PhaseProfiler>>) {
+        if let Some(phase) = self.phase.take() {
+            self.phase_end(phase);
+        }
+        self.profiler = profiler;
+    }
+
+    pub(crate) fn phase_start(&self, phase: Phase) {
+        if let Some(ref profiler) = self.profiler {
+            profiler.on_phase_start(phase);
+        }
+    }
+
+    pub(crate) fn phase_end(&self, phase: Phase) {
+        if let Some(ref profiler) = self.profiler {
+            profiler.on_phase_end(phase);
+        }
+    }
+
+    /// Ends the update or render phase when the next event is requested.
+    pub(crate) fn end_handler_phase(&mut self) {
+        if let Some(phase) = self.phase.take() {
+            self.phase_end(phase);
+        }
+    }
+
+    /// Starts the update or render phase while the event is handled.
+    pub(crate) fn start_handler_phase(&mut self, e: &Event) {
+        if self.profiler.is_none() {
+            return;
+        }
+        self.phase = match *e {
+            Event::Loop(Loop::Update(_)) => Some(Phase::Update),
+            Event::Loop(Loop::Render(_)) => Some(Phase::Render),
+            _ => None,
+        };
+        if let Some(phase) = self.phase {
+            self.phase_start(phase);
+        }
+    }
+}
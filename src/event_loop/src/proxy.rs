@@ -0,0 +1,78 @@
+//! Injecting events into the event loop from other threads.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use input::{Event, MidiArgs, MidiMessage, Motion};
+use window::{Window, WindowWaker};
+
+/// The queue of events sent by proxies.
+pub(crate) type ProxyQueue = Arc<Mutex<VecDeque<Event>>>;
+
+/// A cloneable handle for sending events to the event loop from any thread.
+///
+/// Events are emitted in the order they are sent, before polling the window,
+/// e.g. for integrating networking, file watchers or audio callbacks.
+/// Sending an event wakes the event loop if it is blocked waiting for input.
+/// When the window backend does not support waking,
+/// the event is emitted the next time the event loop polls events.
+///
+/// See [`Events::create_proxy`](./struct.Events.html#method.create_proxy).
+#[derive(Clone, Debug)]
+pub struct EventLoopProxy {
+    queue: ProxyQueue,
+    waker: Option<WindowWaker>,
+}
+
+impl EventLoopProxy {
+    pub(crate) fn new<W>(queue: ProxyQueue, window: &W) -> EventLoopProxy
+        where W: Window
+    {
+        EventLoopProxy {
+            queue: queue,
+            waker: window.create_waker(),
+        }
+    }
+
+    /// Sends an input or custom event to the event loop.
+    pub fn send<E>(&self, event: E)
+        where E: Into<Event>
+    {
+        self.queue.lock().unwrap().push_back(event.into());
+        self.wake();
+    }
+
+    /// Sends a MIDI message from raw bytes to the event loop,
+    /// e.g. from the callback of a MIDI library.
+    ///
+    /// The time is in seconds, as reported by the MIDI source.
+    /// Returns `false` if the message is not supported, see `MidiMessage::from_bytes`.
+    pub fn send_midi(&self, device: i64, time: Option<f64>, bytes: &[u8]) -> bool {
+        match MidiMessage::from_bytes(bytes) {
+            Some(message) => {
+                self.send(Motion::Midi(MidiArgs {
+                    device: device,
+                    message: message,
+                    time: time,
+                }));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Wakes the event loop without sending an event.
+    pub fn wake(&self) {
+        if let Some(ref waker) = self.waker {
+            waker.wake();
+        }
+    }
+}
+
+/// Polls events sent by proxies before events from the window.
+pub(crate) fn poll_event<W>(queue: &ProxyQueue, window: &mut W) -> Option<Event>
+    where W: Window
+{
+    let event = queue.lock().unwrap().pop_front();
+    event.or_else(|| window.poll_event())
+}
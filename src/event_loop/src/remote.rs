@@ -0,0 +1,131 @@
+//! Injecting input events remotely over TCP.
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use input::Input;
+use serde_json;
+
+use EventLoopProxy;
+
+/// A server that accepts serialized input events and sends them to the event loop,
+/// so a running application can be driven remotely, e.g. by QA tools or automated tests.
+///
+/// Clients connect over TCP and send one JSON serialized `Input` per line.
+/// Lines that can not be parsed are answered with a line starting with `error: `.
+/// There is no authentication, so bind to a local address such as `127.0.0.1:0`,
+/// unless the network is trusted.
+/// Requires the `remote` feature.
+///
+/// The server stops accepting connections when dropped.
+///
+/// ```ignore
+/// let proxy = events.create_proxy(&window);
+/// let remote = RemoteInput::bind("127.0.0.1:7878", proxy)?;
+/// ```
+#[derive(Debug)]
+pub struct RemoteInput {
+    addr: SocketAddr,
+    stopped: Arc<AtomicBool>,
+}
+
+impl RemoteInput {
+    /// Starts a server on the address, sending received events with the proxy.
+    pub fn bind<A>(addr: A, proxy: EventLoopProxy) -> io::Result<RemoteInput>
+        where A: ToSocketAddrs
+    {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let stopped = Arc::new(AtomicBool::new(false));
+        let stopped_server = stopped.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stopped_server.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    let proxy = proxy.clone();
+                    let stopped = stopped_server.clone();
+                    thread::spawn(move || serve(stream, &proxy, &stopped));
+                }
+            }
+        });
+        Ok(RemoteInput {
+            addr: addr,
+            stopped: stopped,
+        })
+    }
+
+    /// Returns the address of the server, e.g. to find the port when binding to port `0`.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+}
+
+impl Drop for RemoteInput {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // Wakes the server thread waiting for a connection.
+        let _ = TcpStream::connect(self.addr);
+    }
+}
+
+/// Sends the events received from a client until it disconnects.
+fn serve(stream: TcpStream, proxy: &EventLoopProxy, stopped: &AtomicBool) {
+    let mut writer = match stream.try_clone() {
+        Ok(writer) => writer,
+        Err(_) => return,
+    };
+    for line in BufReader::new(stream).lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(_) => break,
+        };
+        if stopped.load(Ordering::SeqCst) {
+            break;
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<Input>(&line) {
+            Ok(input) => proxy.send(input),
+            Err(err) => {
+                if writeln!(writer, "error: {}", err).is_err() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use input::TextEvent;
+    use window::{NoWindow, WindowSettings};
+    use {EventSettings, Events};
+
+    #[test]
+    fn test_remote_input() {
+        let mut window = NoWindow::new(&WindowSettings::new("test", [100, 100]));
+        let mut events = Events::new(EventSettings::gui());
+        let remote = RemoteInput::bind("127.0.0.1:0", events.create_proxy(&window)).unwrap();
+        let mut client = TcpStream::connect(remote.local_addr()).unwrap();
+        writeln!(client, "not an event").unwrap();
+        let input = serde_json::to_string(&Input::Text("hello".into())).unwrap();
+        writeln!(client, "{}", input).unwrap();
+
+        let mut reply = String::new();
+        BufReader::new(client.try_clone().unwrap()).read_line(&mut reply).unwrap();
+        assert!(reply.starts_with("error: "));
+        let text = loop {
+            if let Some(text) = events.next(&mut window).unwrap().text_args() {
+                break text;
+            }
+        };
+        assert_eq!(text, "hello");
+    }
+}
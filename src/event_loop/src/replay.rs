@@ -0,0 +1,141 @@
+//! Recording and replaying the timing of the event loop.
+
+use std::collections::VecDeque;
+use std::mem;
+
+use input::{Event, Loop};
+use window::Window;
+
+use {Events, Idle, State, Step};
+
+/// An entry in the timing record of the event loop.
+///
+/// See [`Events::start_timing_record`](./struct.Events.html#method.start_timing_record).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TimingEntry {
+    /// An input or custom event polled from the window or the proxy queue,
+    /// replayed by polling again.
+    Input,
+    /// A loop event with the recorded arguments.
+    Loop(Loop),
+}
+
+/// The timing entries left to replay.
+pub(crate) type TimingReplay = VecDeque<TimingEntry>;
+
+impl Events {
+    /// Starts recording the timing of the event loop.
+    ///
+    /// Records the order of input events and the arguments of loop events,
+    /// such as the delta time of updates, to replay them with
+    /// [`replay_timing()`](#method.replay_timing).
+    pub fn start_timing_record(&mut self) {
+        self.timing_record = Some(vec![]);
+    }
+
+    /// Stops recording and returns the timing record.
+    pub fn stop_timing_record(&mut self) -> Option<Vec<TimingEntry>> {
+        self.timing_record.take()
+    }
+
+    /// Replays a timing record, ignoring the time source.
+    ///
+    /// Loop events are emitted with the recorded arguments and in the recorded order
+    /// relative to input events, so replaying the recorded input with
+    /// a [`HeadlessWindow`](../window/struct.HeadlessWindow.html)
+    /// reproduces the same simulation.
+    /// Input events are polled from the window, waiting for input if necessary.
+    /// After the last entry, the event loop continues from the current time.
+    pub fn replay_timing(&mut self, record: Vec<TimingEntry>) {
+        self.timing_replay = Some(record.into_iter().collect());
+    }
+
+    /// Returns `true` if a timing record is being replayed.
+    pub fn is_replaying_timing(&self) -> bool {
+        self.timing_replay.is_some()
+    }
+
+    /// Records an emitted event.
+    ///
+    /// Events of the event loop that are not loop events, such as lost contexts,
+    /// are not recorded, since replaying emits them again.
+    pub(crate) fn record_timing(&mut self, step: &Step) {
+        let polled = match *step {
+            Step::Event(Event::Input(..)) | Step::Event(Event::Custom(..)) => {
+                mem::replace(&mut self.polled, false)
+            }
+            _ => false,
+        };
+        if let (Some(ref mut record), &Step::Event(ref e)) = (self.timing_record.as_mut(), step) {
+            match *e {
+                // Replaying swaps the buffers of the window.
+                Event::Loop(Loop::SwapFailed(_)) => {}
+                Event::Loop(ref l) => record.push(TimingEntry::Loop(*l)),
+                Event::Input(..) | Event::Custom(..) => {
+                    if polled {
+                        record.push(TimingEntry::Input);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the next replayed event, or `None` when not replaying.
+    pub(crate) fn step_replay<W>(&mut self, window: &mut W, block: bool) -> Option<Step>
+        where W: Window
+    {
+        if self.timing_replay.is_none() {
+            return None;
+        }
+        if window.should_close() {
+            return Some(Step::Done);
+        }
+        let entry = match self.timing_replay.as_mut().and_then(|replay| replay.pop_front()) {
+            Some(entry) => entry,
+            None => {
+                // Continue from the current time without catching up.
+                self.timing_replay = None;
+                let now = self.clock.now();
+                self.last_update = now;
+                self.last_frame = now;
+                self.state = State::UpdateLoop(Idle::No);
+                return None;
+            }
+        };
+        match entry {
+            TimingEntry::Input => {
+                if let Some(e) = self.poll_event(window) {
+                    return Some(Step::Event(e));
+                }
+                if !block {
+                    if let Some(ref mut replay) = self.timing_replay {
+                        replay.push_front(entry);
+                    }
+                    return Some(Step::Wait(None));
+                }
+                Some(Step::Event(self.wait_event(window)))
+            }
+            TimingEntry::Loop(l) => {
+                match l {
+                    Loop::Render(_) => self.frames += 1,
+                    Loop::Update(_) => self.updates += 1,
+                    Loop::AfterRender(_) => {
+                        if let State::AfterRender = self.state {
+                            // Buffers were swapped before reporting the error.
+                            self.state = State::UpdateLoop(Idle::No);
+                        } else if self.settings.swap_buffers {
+                            if let Some(e) = self.swap_window_buffers(window) {
+                                if let Some(ref mut replay) = self.timing_replay {
+                                    replay.push_front(TimingEntry::Loop(l));
+                                }
+                                return Some(Step::Event(e));
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+                Some(Step::Event(Event::Loop(l)))
+            }
+        }
+    }
+}
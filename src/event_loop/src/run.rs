@@ -0,0 +1,88 @@
+//! Closure based event loop.
+
+use std::time::Instant;
+
+use input::{Event, Input, WindowState};
+use window::Window;
+
+use {EventSettings, Events, Step};
+
+/// The state of the event loop after pumping events.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PumpStatus {
+    /// Pump events again when input arrives, or at the latest at the time if any.
+    Wait(Option<Instant>),
+    /// The event loop ended.
+    Done,
+}
+
+impl Events {
+    /// Runs the event loop until the window closes, calling the handler for every event.
+    ///
+    /// This is an alternative to calling [`next()`](#method.next) in a loop:
+    ///
+    /// ```ignore
+    /// Events::run(&mut window, EventSettings::new(), |e, window| {
+    ///     if let Some(args) = e.render_args() {
+    ///         // Render.
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// The event loop is paused while the window is minimized,
+    /// and throttled with `paused_max_fps` and `unfocused_max_fps`.
+    /// When `catch_panics` is enabled, the handler is run with [`handle()`](#method.handle).
+    pub fn run<W, F>(window: &mut W, settings: EventSettings, mut f: F)
+        where W: Window, F: FnMut(Event, &mut W)
+    {
+        let mut events = Events::new(settings);
+        while let Some(e) = events.next(window) {
+            events.dispatch(e, window, &mut f);
+        }
+    }
+
+    /// Handles all events that are due without waiting, calling the handler for every event.
+    ///
+    /// This is for platforms that require a callback style main loop, such as web or iOS,
+    /// where the platform calls the application every frame or when input arrives.
+    /// Returns when to pump events again.
+    /// Events are handled like in [`run()`](#method.run).
+    pub fn pump_events<W, F>(&mut self, window: &mut W, mut f: F) -> PumpStatus
+        where W: Window, F: FnMut(Event, &mut W)
+    {
+        loop {
+            match self.step(window, false) {
+                Step::Event(e) => self.dispatch(e, window, &mut f),
+                Step::Wait(deadline) => return PumpStatus::Wait(deadline),
+                Step::Done => return PumpStatus::Done,
+            }
+        }
+    }
+
+    /// Handles a frame requested by the platform, calling the handler for every event.
+    ///
+    /// This is for platforms that call the application when the next frame should be drawn,
+    /// such as `requestAnimationFrame` on the web or `CADisplayLink` on iOS.
+    /// Requires `EventSettings::external_frames` to be enabled, so
+    /// [`pump_events()`](#method.pump_events) handles input and updates between frames
+    /// without rendering.
+    /// Handles the updates that are due and renders exactly one frame,
+    /// unless the window has zero size or does not need a redraw with render on demand.
+    /// Returns when to pump events again.
+    pub fn pump_frame<W, F>(&mut self, window: &mut W, f: F) -> PumpStatus
+        where W: Window, F: FnMut(Event, &mut W)
+    {
+        self.frame_requested = true;
+        self.pump_events(window, f)
+    }
+
+    /// Applies the pause policy and calls the handler.
+    fn dispatch<W, F>(&mut self, e: Event, window: &mut W, f: &mut F)
+        where F: FnMut(Event, &mut W)
+    {
+        if let Event::Input(Input::WindowState(state), _) = e {
+            self.set_paused(state == WindowState::Minimized);
+        }
+        self.handle(|| f(e, window));
+    }
+}
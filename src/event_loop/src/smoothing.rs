@@ -0,0 +1,109 @@
+//! Smoothing variable delta times.
+
+use std::collections::VecDeque;
+
+/// How to smooth delta times.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DtSmoothing {
+    /// The average of the last number of delta times.
+    MovingAverage(usize),
+    /// The median of the last number of delta times.
+    ///
+    /// Suppresses single frame spikes completely.
+    Median(usize),
+}
+
+/// Filters variable delta times to suppress spikes,
+/// which make camera and physics motion visibly jump.
+///
+/// The event loop emits update events with fixed delta time.
+/// When updating with variable time steps, for example the time between render events,
+/// use this filter on the measured delta time:
+///
+/// ```ignore
+/// let mut filter = DtFilter::new(DtSmoothing::Median(5));
+/// let mut last = Instant::now();
+/// while let Some(e) = events.next(&mut window) {
+///     if e.render_args().is_some() {
+///         let now = Instant::now();
+///         let dt = filter.filter((now - last).as_secs_f64());
+///         last = now;
+///         // Update with `dt`.
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DtFilter {
+    smoothing: DtSmoothing,
+    samples: VecDeque<f64>,
+}
+
+impl DtFilter {
+    /// Creates a new delta time filter.
+    pub fn new(smoothing: DtSmoothing) -> DtFilter {
+        DtFilter {
+            smoothing: smoothing,
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Returns the smoothing of the filter.
+    pub fn smoothing(&self) -> DtSmoothing {
+        self.smoothing
+    }
+
+    /// Adds a delta time in seconds and returns the smoothed delta time.
+    pub fn filter(&mut self, dt: f64) -> f64 {
+        let len = match self.smoothing {
+            DtSmoothing::MovingAverage(n) | DtSmoothing::Median(n) => n.max(1),
+        };
+        while self.samples.len() >= len {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(dt);
+        match self.smoothing {
+            DtSmoothing::MovingAverage(_) => {
+                self.samples.iter().sum::<f64>() / self.samples.len() as f64
+            }
+            DtSmoothing::Median(_) => {
+                let mut sorted: Vec<f64> = self.samples.iter().cloned().collect();
+                sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(::std::cmp::Ordering::Equal));
+                let mid = sorted.len() / 2;
+                if sorted.len() % 2 == 0 {
+                    (sorted[mid - 1] + sorted[mid]) / 2.0
+                } else {
+                    sorted[mid]
+                }
+            }
+        }
+    }
+
+    /// Removes all delta times, e.g. after loading or pausing.
+    pub fn reset(&mut self) {
+        self.samples.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dt_filter() {
+        let mut median = DtFilter::new(DtSmoothing::Median(3));
+        let mut average = DtFilter::new(DtSmoothing::MovingAverage(3));
+        let mut last = (0.0, 0.0);
+        for &dt in &[0.01, 0.01, 0.1, 0.01, 0.02] {
+            last = (median.filter(dt), average.filter(dt));
+            if dt == 0.1 {
+                // The spike is suppressed.
+                assert_eq!(last.0, 0.01);
+                assert!((last.1 - 0.04).abs() < 1e-9);
+            }
+        }
+        assert_eq!(last.0, 0.02);
+        assert!((last.1 - 0.13 / 3.0).abs() < 1e-9);
+        median.reset();
+        assert_eq!(median.filter(0.05), 0.05);
+    }
+}
@@ -0,0 +1,198 @@
+//! Frame timing statistics.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use duration_to_secs;
+
+/// Collects frame timing statistics over a sliding window of time.
+///
+/// Enable with [`Events::enable_frame_stats`](./struct.Events.html#method.enable_frame_stats),
+/// for example to show a performance overlay in a game.
+/// The frame time is the time between the start of two frames.
+///
+/// When the window backend reports GPU frame times,
+/// frames are classified as CPU-bound or GPU-bound,
+/// depending on whether the CPU or the GPU spent the most time rendering.
+#[derive(Clone, Debug)]
+pub struct FrameStats {
+    window: Duration,
+    frames: VecDeque<(Instant, Duration)>,
+    /// The CPU and GPU time of frames with GPU timing.
+    gpu_frames: VecDeque<(Instant, Duration, Duration)>,
+    updates: VecDeque<Instant>,
+    last_frame: Option<Instant>,
+}
+
+impl FrameStats {
+    /// Creates new frame statistics over a sliding window of time.
+    pub fn new(window: Duration) -> FrameStats {
+        FrameStats {
+            window: window,
+            frames: VecDeque::new(),
+            gpu_frames: VecDeque::new(),
+            updates: VecDeque::new(),
+            last_frame: None,
+        }
+    }
+
+    /// Returns the sliding window of time.
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Returns the start of the last frame.
+    pub fn last_frame(&self) -> Option<Instant> {
+        self.last_frame
+    }
+
+    /// Records the start of a frame.
+    pub fn record_frame(&mut self, now: Instant) {
+        if let Some(last_frame) = self.last_frame {
+            self.frames.push_back((now, now - last_frame));
+        }
+        self.last_frame = Some(now);
+        while self.frames.front().map(|&(t, _)| now - t > self.window).unwrap_or(false) {
+            self.frames.pop_front();
+        }
+    }
+
+    /// Records the CPU and GPU time of a frame.
+    ///
+    /// The CPU time is the time from the start of the frame until rendering finished.
+    pub fn record_gpu_time(&mut self, now: Instant, cpu_time: Duration, gpu_time: Duration) {
+        self.gpu_frames.push_back((now, cpu_time, gpu_time));
+        while self.gpu_frames.front().map(|&(t, _, _)| now - t > self.window).unwrap_or(false) {
+            self.gpu_frames.pop_front();
+        }
+    }
+
+    /// Records an update.
+    pub fn record_update(&mut self, now: Instant) {
+        self.updates.push_back(now);
+        while self.updates.front().map(|&t| now - t > self.window).unwrap_or(false) {
+            self.updates.pop_front();
+        }
+    }
+
+    /// Removes all recorded frames and updates.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+        self.gpu_frames.clear();
+        self.updates.clear();
+        self.last_frame = None;
+    }
+
+    /// Returns the average frames per second.
+    pub fn fps(&self) -> f64 {
+        let total: Duration = self.frames.iter().map(|&(_, dt)| dt).sum();
+        if total == Duration::from_secs(0) {
+            0.0
+        } else {
+            self.frames.len() as f64 / duration_to_secs(total)
+        }
+    }
+
+    /// Returns the average updates per second.
+    pub fn ups(&self) -> f64 {
+        match (self.updates.front(), self.updates.back()) {
+            (Some(&first), Some(&last)) if last > first => {
+                (self.updates.len() - 1) as f64 / duration_to_secs(last - first)
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Returns the mean frame time.
+    pub fn mean_frame_time(&self) -> Duration {
+        if self.frames.is_empty() {
+            return Duration::from_secs(0);
+        }
+        let total: Duration = self.frames.iter().map(|&(_, dt)| dt).sum();
+        total / self.frames.len() as u32
+    }
+
+    /// Returns the frame time that a fraction of frames are faster than or equal to,
+    /// e.g. `0.95` for the 95th percentile.
+    pub fn percentile_frame_time(&self, fraction: f64) -> Duration {
+        if self.frames.is_empty() {
+            return Duration::from_secs(0);
+        }
+        let mut frame_times: Vec<Duration> = self.frames.iter().map(|&(_, dt)| dt).collect();
+        frame_times.sort();
+        let n = frame_times.len();
+        let rank = (fraction.max(0.0).min(1.0) * n as f64).ceil() as usize;
+        frame_times[rank.max(1) - 1]
+    }
+
+    /// Returns the 95th percentile frame time.
+    pub fn p95_frame_time(&self) -> Duration {
+        self.percentile_frame_time(0.95)
+    }
+
+    /// Returns the 99th percentile frame time.
+    pub fn p99_frame_time(&self) -> Duration {
+        self.percentile_frame_time(0.99)
+    }
+
+    /// Returns the mean GPU frame time,
+    /// or `None` if the window backend does not report GPU frame times.
+    pub fn mean_gpu_time(&self) -> Option<Duration> {
+        if self.gpu_frames.is_empty() {
+            return None;
+        }
+        let total: Duration = self.gpu_frames.iter().map(|&(_, _, gpu)| gpu).sum();
+        Some(total / self.gpu_frames.len() as u32)
+    }
+
+    /// Returns the number of frames where the CPU spent more time than the GPU.
+    pub fn cpu_bound_frames(&self) -> usize {
+        self.gpu_frames.iter().filter(|&&(_, cpu, gpu)| cpu >= gpu).count()
+    }
+
+    /// Returns the number of frames where the GPU spent more time than the CPU.
+    pub fn gpu_bound_frames(&self) -> usize {
+        self.gpu_frames.iter().filter(|&&(_, cpu, gpu)| gpu > cpu).count()
+    }
+
+    /// Returns the longest frame time, the longest stall of the event loop.
+    pub fn longest_frame_time(&self) -> Duration {
+        self.frames.iter().map(|&(_, dt)| dt).max().unwrap_or(Duration::from_secs(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_stats() {
+        let start = Instant::now();
+        let ms = Duration::from_millis;
+        let mut stats = FrameStats::new(Duration::from_secs(1));
+        let mut t = start;
+        for i in 0..21 {
+            t += if i == 10 {ms(50)} else {ms(10)};
+            stats.record_frame(t);
+            stats.record_update(t);
+        }
+        assert_eq!(stats.longest_frame_time(), ms(50));
+        assert_eq!(stats.mean_frame_time(), ms(12));
+        assert_eq!(stats.p95_frame_time(), ms(10));
+        assert_eq!(stats.p99_frame_time(), ms(50));
+        assert!((stats.fps() - 1000.0 / 12.0).abs() < 1e-6);
+
+        // Frames outside the sliding window are removed.
+        stats.record_frame(t + Duration::from_secs(2));
+        assert_eq!(stats.longest_frame_time(), Duration::from_millis(2000));
+        assert_eq!(stats.mean_frame_time(), Duration::from_millis(2000));
+
+        assert_eq!(stats.mean_gpu_time(), None);
+        stats.record_gpu_time(t, ms(4), ms(8));
+        stats.record_gpu_time(t, ms(6), ms(2));
+        stats.record_gpu_time(t, ms(3), ms(5));
+        assert_eq!(stats.mean_gpu_time(), Some(ms(5)));
+        assert_eq!(stats.cpu_bound_frames(), 1);
+        assert_eq!(stats.gpu_bound_frames(), 2);
+    }
+}
@@ -0,0 +1,162 @@
+//! Async adapter for the event loop.
+
+use std::cmp;
+use std::pin::Pin;
+use std::sync::mpsc::{channel, RecvTimeoutError, Sender};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use futures_core::Stream;
+use input::Event;
+use window::Window;
+
+use {Events, Step};
+
+/// How often input is checked while waiting in lazy mode.
+///
+/// The window can not notify the async runtime about input,
+/// so waiting for input in lazy mode is done by checking regularly.
+const LAZY_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// A stream of events from the event loop.
+///
+/// Instead of sleeping until the next event, the stream returns `Poll::Pending`
+/// and wakes the task when the next event is due.
+/// This allows waiting for window events and other futures at the same time,
+/// e.g. with `select!` in tokio or async-std.
+///
+/// The stream must be polled on the same thread as the window back-end.
+/// Requires the `futures` feature.
+///
+/// See [`Events::stream`](./struct.Events.html#method.stream).
+pub struct EventStream<'a, W: 'a> {
+    events: &'a mut Events,
+    window: &'a mut W,
+    timer: Option<Sender<(Instant, Waker)>>,
+}
+
+impl<'a, W> EventStream<'a, W> {
+    /// Wakes the task when the time is reached.
+    fn wake_at(&mut self, deadline: Instant, waker: Waker) {
+        if let Some(ref timer) = self.timer {
+            if timer.send((deadline, waker.clone())).is_ok() {
+                return;
+            }
+        }
+        let (tx, rx) = channel::<(Instant, Waker)>();
+        thread::spawn(move || {
+            // Only the latest request is kept, because the stream
+            // registers a new waker every time it returns pending.
+            let mut pending: Option<(Instant, Waker)> = None;
+            loop {
+                let msg = match pending {
+                    None => match rx.recv() {
+                        Ok(msg) => msg,
+                        Err(_) => break,
+                    },
+                    Some((deadline, _)) => {
+                        let now = Instant::now();
+                        let res = if deadline > now {
+                            rx.recv_timeout(deadline - now)
+                        } else {
+                            Err(RecvTimeoutError::Timeout)
+                        };
+                        match res {
+                            Ok(msg) => msg,
+                            Err(RecvTimeoutError::Timeout) => {
+                                pending.take().unwrap().1.wake();
+                                continue;
+                            }
+                            Err(RecvTimeoutError::Disconnected) => break,
+                        }
+                    }
+                };
+                pending = Some(msg);
+            }
+        });
+        // The receiver lives until the sender is dropped.
+        let _ = tx.send((deadline, waker));
+        self.timer = Some(tx);
+    }
+}
+
+impl<'a, W> Stream for EventStream<'a, W>
+    where W: Window
+{
+    type Item = Event;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Event>> {
+        let this = self.get_mut();
+        match this.events.step(this.window, false) {
+            Step::Event(e) => Poll::Ready(Some(e)),
+            Step::Done => Poll::Ready(None),
+            Step::Wait(deadline) => {
+                let mut deadline = deadline.unwrap_or_else(|| Instant::now() + LAZY_POLL_INTERVAL);
+                if this.events.settings.lazy {
+                    // Check for input while waiting for a timer.
+                    deadline = cmp::min(deadline, Instant::now() + LAZY_POLL_INTERVAL);
+                }
+                this.wake_at(deadline, cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Events {
+    /// Returns a stream of events from the window.
+    ///
+    /// Requires the `futures` feature.
+    pub fn stream<'a, W>(&'a mut self, window: &'a mut W) -> EventStream<'a, W>
+        where W: Window
+    {
+        EventStream {
+            events: self,
+            window: window,
+            timer: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::task::Wake;
+    use input::{RenderEvent, UpdateEvent};
+    use window::{HeadlessWindow, WindowSettings};
+    use EventLoop;
+
+    struct ThreadWaker(thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    #[test]
+    fn test_stream() {
+        let mut window = HeadlessWindow::new(&WindowSettings::new("test", [100, 100]));
+        let mut events = Events::new(Default::default()).ups(200).max_fps(100);
+        let mut stream = events.stream(&mut window);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let (mut renders, mut updates, mut pending) = (0, 0, 0);
+        while renders < 3 || updates < 3 {
+            match Pin::new(&mut stream).poll_next(&mut cx) {
+                Poll::Ready(Some(e)) => {
+                    if e.render_args().is_some() {renders += 1}
+                    if e.update_args().is_some() {updates += 1}
+                }
+                Poll::Ready(None) => panic!("Expected stream to continue"),
+                Poll::Pending => {
+                    pending += 1;
+                    thread::park();
+                }
+            }
+        }
+        assert!(pending > 0);
+    }
+}
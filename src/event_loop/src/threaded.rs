@@ -0,0 +1,165 @@
+//! Running update logic on a worker thread.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, sleep, JoinHandle};
+use std::time::Instant;
+
+use input::{Input, UpdateArgs};
+
+use {ns_to_duration, BILLION};
+
+/// Runs update logic at a fixed rate on a worker thread,
+/// while rendering and input handling stay on the window thread.
+///
+/// The worker thread owns the application state.
+/// Input is forwarded with [`send_input()`](#method.send_input)
+/// and passed to the next update.
+/// After every update, a copy of the state is published,
+/// which the window thread reads with [`snapshot()`](#method.snapshot) when rendering.
+///
+/// Disable update events in the event loop by setting `ups` to `0`,
+/// so heavy simulations do not stall input processing and swapping of buffers:
+///
+/// ```ignore
+/// let sim = ThreadedUpdate::spawn(state, 120, |state, args, inputs| {
+///     // Update state.
+/// });
+/// let mut events = Events::new(EventSettings::new().ups(0));
+/// while let Some(e) = events.next(&mut window) {
+///     if let Event::Input(ref input, _) = e {
+///         sim.send_input(input.clone());
+///     }
+///     if let Some(args) = e.render_args() {
+///         let state = sim.snapshot();
+///         // Render state.
+///     }
+/// }
+/// let state = sim.stop().unwrap();
+/// ```
+pub struct ThreadedUpdate<S> {
+    input: Sender<Input>,
+    snapshot: Arc<Mutex<S>>,
+    running: Arc<AtomicBool>,
+    thread: Option<JoinHandle<S>>,
+}
+
+impl<S> ThreadedUpdate<S>
+    where S: 'static + Clone + Send
+{
+    /// Spawns a worker thread that updates the state `ups` times per second.
+    ///
+    /// The update function receives the state, the update arguments
+    /// and the input received since the last update.
+    /// The worker skips lagging updates like the event loop does by default.
+    pub fn spawn<F>(state: S, ups: u64, mut update: F) -> ThreadedUpdate<S>
+        where F: 'static + FnMut(&mut S, &UpdateArgs, &[Input]) + Send
+    {
+        assert!(ups > 0, "Expected updates per second larger than 0");
+        let (tx, rx) = channel();
+        let snapshot = Arc::new(Mutex::new(state.clone()));
+        let running = Arc::new(AtomicBool::new(true));
+        let thread = {
+            let snapshot = snapshot.clone();
+            let running = running.clone();
+            thread::spawn(move || {
+                let mut state = state;
+                let dt_update = ns_to_duration(BILLION / ups);
+                let dt = 1.0 / ups as f64;
+                let mut tick = 0;
+                let mut inputs = vec![];
+                let mut next_update = Instant::now();
+                while running.load(Ordering::SeqCst) {
+                    inputs.extend(rx.try_iter());
+                    let args = UpdateArgs { dt: dt, tick: tick, time: tick as f64 * dt };
+                    update(&mut state, &args, &inputs);
+                    tick += 1;
+                    inputs.clear();
+                    *snapshot.lock().unwrap() = state.clone();
+
+                    next_update += dt_update;
+                    let now = Instant::now();
+                    if next_update > now {
+                        sleep(next_update - now);
+                    } else if now - next_update > dt_update * 2 {
+                        // Skip updates because CPU is too busy.
+                        next_update = now;
+                    }
+                }
+                state
+            })
+        };
+        ThreadedUpdate {
+            input: tx,
+            snapshot: snapshot,
+            running: running,
+            thread: Some(thread),
+        }
+    }
+
+    /// Forwards input to the next update.
+    pub fn send_input(&self, input: Input) {
+        // The worker thread only stops when dropped or after a panic.
+        let _ = self.input.send(input);
+    }
+
+    /// Returns a copy of the state after the latest update.
+    pub fn snapshot(&self) -> S {
+        self.snapshot.lock().unwrap().clone()
+    }
+
+    /// Returns `true` if the worker thread is running.
+    ///
+    /// Returns `false` if the update function panicked.
+    pub fn is_running(&self) -> bool {
+        match self.thread {
+            Some(ref thread) => !thread.is_finished(),
+            None => false,
+        }
+    }
+
+    /// Stops the worker thread and returns the final state.
+    ///
+    /// Returns an error with the panic payload if the update function panicked.
+    pub fn stop(mut self) -> thread::Result<S> {
+        self.running.store(false, Ordering::SeqCst);
+        self.thread.take().unwrap().join()
+    }
+}
+
+impl<S> Drop for ThreadedUpdate<S> {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::SeqCst);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use input::{Button, ButtonArgs, ButtonState, Key};
+    use std::time::Duration;
+
+    #[test]
+    fn test_threaded_update() {
+        let sim = ThreadedUpdate::spawn((0, 0), 1000, |state, _args, inputs| {
+            state.0 += 1;
+            state.1 += inputs.len();
+        });
+        sim.send_input(Input::Button(ButtonArgs {
+            state: ButtonState::Press,
+            button: Button::Keyboard(Key::Space),
+            scancode: None,
+            device: None,
+        }));
+        while sim.snapshot().1 == 0 {
+            sleep(Duration::from_millis(1));
+        }
+        let (updates, inputs) = sim.stop().unwrap();
+        assert!(updates > 0);
+        assert_eq!(inputs, 1);
+    }
+}
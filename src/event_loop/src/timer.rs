@@ -0,0 +1,93 @@
+//! Scheduling timers on the event loop.
+
+use std::time::{Duration, Instant};
+
+use input::TimerArgs;
+
+#[derive(Copy, Clone, Debug)]
+struct Timer {
+    id: u64,
+    deadline: Instant,
+    interval: Duration,
+    repeating: bool,
+}
+
+/// Stores the timers of an event loop.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Timers {
+    timers: Vec<Timer>,
+}
+
+impl Timers {
+    /// Sets a timer, replacing any existing timer with the same id.
+    pub fn set(&mut self, id: u64, now: Instant, interval: Duration, repeating: bool) {
+        assert!(!repeating || interval > Duration::from_secs(0),
+                "Repeating timer requires a non-zero duration");
+        self.cancel(id);
+        self.timers.push(Timer {
+            id: id,
+            deadline: now + interval,
+            interval: interval,
+            repeating: repeating,
+        });
+    }
+
+    /// Removes a timer, returning `true` if it existed.
+    pub fn cancel(&mut self, id: u64) -> bool {
+        let len = self.timers.len();
+        self.timers.retain(|timer| timer.id != id);
+        self.timers.len() != len
+    }
+
+    /// Returns the deadline of the next timer.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.timers.iter().map(|timer| timer.deadline).min()
+    }
+
+    /// Removes the earliest timer that is due, rescheduling it if repeating.
+    ///
+    /// Missed intervals of repeating timers are skipped after a stall.
+    pub fn pop_due(&mut self, now: Instant) -> Option<TimerArgs> {
+        let index = self.timers.iter()
+            .enumerate()
+            .filter(|&(_, timer)| timer.deadline <= now)
+            .min_by_key(|&(_, timer)| timer.deadline)
+            .map(|(index, _)| index)?;
+        let id = self.timers[index].id;
+        if self.timers[index].repeating {
+            let timer = &mut self.timers[index];
+            timer.deadline += timer.interval;
+            if timer.deadline <= now {
+                timer.deadline = now + timer.interval;
+            }
+        } else {
+            self.timers.swap_remove(index);
+        }
+        Some(TimerArgs { id: id })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timers() {
+        let start = Instant::now();
+        let ms = Duration::from_millis;
+        let mut timers = Timers::default();
+        timers.set(1, start, ms(10), true);
+        timers.set(2, start, ms(15), false);
+        assert_eq!(timers.next_deadline(), Some(start + ms(10)));
+        assert_eq!(timers.pop_due(start + ms(5)), None);
+        assert_eq!(timers.pop_due(start + ms(15)), Some(TimerArgs { id: 1 }));
+        assert_eq!(timers.pop_due(start + ms(15)), Some(TimerArgs { id: 2 }));
+        assert_eq!(timers.pop_due(start + ms(15)), None);
+        // Missed intervals are skipped.
+        assert_eq!(timers.pop_due(start + ms(55)), Some(TimerArgs { id: 1 }));
+        assert_eq!(timers.next_deadline(), Some(start + ms(65)));
+        assert!(timers.cancel(1));
+        assert!(!timers.cancel(2));
+        assert_eq!(timers.next_deadline(), None);
+    }
+}
@@ -0,0 +1,94 @@
+use {Event, Input};
+
+/// Stores the accessibility settings of the operating system
+/// that are relevant for input handling and presentation.
+///
+/// Optional settings are `None` and flags are `false` if the platform does not report them.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Debug, Default)]
+pub struct AccessibilitySettings {
+    /// Whether sticky keys are active, so modifier keys stay pressed
+    /// until the next key is pressed.
+    pub sticky_keys: bool,
+    /// The delay before a held key starts repeating, in seconds.
+    pub key_repeat_delay: Option<f64>,
+    /// The number of key repeats per second while a key is held.
+    pub key_repeat_rate: Option<f64>,
+    /// The maximum time between two clicks of a double click, in seconds.
+    pub double_click_interval: Option<f64>,
+    /// Whether the user prefers reduced motion,
+    /// so effects such as screen shake and parallax should be disabled.
+    #[serde(default)]
+    pub reduced_motion: bool,
+    /// Whether a high contrast mode is active,
+    /// so colors should be adjusted to be easier to tell apart.
+    #[serde(default)]
+    pub high_contrast: bool,
+}
+
+impl AccessibilitySettings {
+    /// Returns the double click interval, or the default of `0.5` seconds
+    /// if the platform does not report it.
+    pub fn double_click_interval_or_default(&self) -> f64 {
+        self.double_click_interval.unwrap_or(0.5)
+    }
+}
+
+/// When the accessibility settings of the operating system change.
+pub trait AccessibilityEvent: Sized {
+    /// Creates an accessibility event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_accessibility_settings(settings: AccessibilitySettings, old_event: &Self)
+                                   -> Option<Self>;
+    /// Calls closure if this is an accessibility event.
+    fn accessibility<U, F>(&self, f: F) -> Option<U> where F: FnMut(AccessibilitySettings) -> U;
+    /// Returns accessibility arguments.
+    fn accessibility_args(&self) -> Option<AccessibilitySettings> {
+        self.accessibility(|settings| settings)
+    }
+}
+
+impl AccessibilityEvent for Event {
+    fn from_accessibility_settings(settings: AccessibilitySettings, old_event: &Self)
+                                   -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Accessibility(settings), timestamp))
+    }
+
+    fn accessibility<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(AccessibilitySettings) -> U
+    {
+        match *self {
+            Event::Input(Input::Accessibility(settings), _) => Some(f(settings)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_accessibility() {
+        use super::super::Input;
+
+        let e: Event = Input::Accessibility(AccessibilitySettings::default()).into();
+        let settings = AccessibilitySettings {
+            sticky_keys: true,
+            key_repeat_delay: Some(0.25),
+            key_repeat_rate: Some(30.0),
+            double_click_interval: Some(0.8),
+            reduced_motion: true,
+            high_contrast: false,
+        };
+        let x: Option<Event> = AccessibilityEvent::from_accessibility_settings(settings, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .accessibility(|settings| {
+                AccessibilityEvent::from_accessibility_settings(settings, x.as_ref().unwrap())
+            })
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -0,0 +1,58 @@
+use {ButtonState, Event, Input};
+
+/// Action arguments, such as the name of the action and whether it started or stopped.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize, Hash)]
+pub struct ActionArgs {
+    /// The name of the action.
+    pub action: String,
+    /// Whether the action started or stopped.
+    pub state: ButtonState,
+}
+
+/// When a named action bound to physical input started or stopped,
+/// see [`Bindings`](./bindings/struct.Bindings.html).
+pub trait ActionEvent: Sized {
+    /// Creates an action event from arguments.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_action_args(args: &ActionArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is an action event.
+    fn action<U, F>(&self, f: F) -> Option<U> where F: FnMut(&ActionArgs) -> U;
+    /// Returns action arguments.
+    fn action_args(&self) -> Option<ActionArgs> {
+        self.action(|args| args.clone())
+    }
+}
+
+impl ActionEvent for Event {
+    fn from_action_args(args: &ActionArgs, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Action(args.clone()), timestamp))
+    }
+
+    fn action<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(&ActionArgs) -> U
+    {
+        match *self {
+            Event::Input(Input::Action(ref args), _) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_action() {
+        let args = ActionArgs { action: "jump".into(), state: ButtonState::Press };
+        let e: Event = args.clone().into();
+        let x: Option<Event> = ActionEvent::from_action_args(&args, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .action(|args| ActionEvent::from_action_args(args, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
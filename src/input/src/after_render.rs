@@ -1,8 +1,14 @@
 use {Event, Loop};
 
 /// After render arguments.
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize, Hash)]
-pub struct AfterRenderArgs;
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct AfterRenderArgs {
+    /// The GPU time of the last finished frame in seconds.
+    ///
+    /// This is `None` if the window backend does not support timer queries.
+    #[serde(default)]
+    pub gpu_dt: Option<f64>,
+}
 
 /// After rendering and buffers are swapped.
 pub trait AfterRenderEvent: Sized {
@@ -39,8 +45,9 @@ mod tests {
     fn test_input_after_render() {
         use AfterRenderArgs;
 
-        let e: Event = AfterRenderArgs.into();
-        let x: Option<Event> = AfterRenderEvent::from_after_render_args(&AfterRenderArgs, &e);
+        let args = AfterRenderArgs { gpu_dt: Some(0.004) };
+        let e: Event = args.into();
+        let x: Option<Event> = AfterRenderEvent::from_after_render_args(&args, &e);
         let y: Option<Event> =
             x.clone()
                 .unwrap()
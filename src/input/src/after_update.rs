@@ -0,0 +1,53 @@
+use {Event, Loop};
+
+/// After update arguments.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize, Hash)]
+pub struct AfterUpdateArgs;
+
+/// After updating the state of the application.
+pub trait AfterUpdateEvent: Sized {
+    /// Creates an after update event.
+    fn from_after_update_args(args: &AfterUpdateArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is an after update event.
+    fn after_update<U, F>(&self, f: F) -> Option<U> where F: FnMut(&AfterUpdateArgs) -> U;
+    /// Returns after update arguments.
+    fn after_update_args(&self) -> Option<AfterUpdateArgs> {
+        self.after_update(|args| args.clone())
+    }
+}
+
+impl AfterUpdateEvent for Event {
+    fn from_after_update_args(args: &AfterUpdateArgs, _old_event: &Self) -> Option<Self> {
+        Some(Event::Loop(Loop::AfterUpdate(*args)))
+    }
+
+    fn after_update<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(&AfterUpdateArgs) -> U
+    {
+        match *self {
+            Event::Loop(Loop::AfterUpdate(ref args)) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_after_update() {
+        use AfterUpdateArgs;
+
+        let e: Event = AfterUpdateArgs.into();
+        let x: Option<Event> = AfterUpdateEvent::from_after_update_args(&AfterUpdateArgs, &e);
+        let y: Option<Event> =
+            x.clone()
+                .unwrap()
+                .after_update(|args| {
+                    AfterUpdateEvent::from_after_update_args(args, x.as_ref().unwrap())
+                })
+                .unwrap();
+        assert_eq!(x, y);
+    }
+}
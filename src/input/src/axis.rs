@@ -0,0 +1,58 @@
+use {Event, Input};
+
+/// Axis arguments, such as the name of the axis and its value.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct AxisArgs {
+    /// The name of the axis.
+    pub axis: String,
+    /// The value of the axis, usually in the range `[-1.0, 1.0]`.
+    pub value: f64,
+}
+
+/// When the value of a named axis bound to physical input changed,
+/// see [`Bindings`](./bindings/struct.Bindings.html).
+pub trait AxisEvent: Sized {
+    /// Creates an axis event from arguments.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_axis_args(args: &AxisArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is an axis event.
+    fn axis<U, F>(&self, f: F) -> Option<U> where F: FnMut(&AxisArgs) -> U;
+    /// Returns axis arguments.
+    fn axis_args(&self) -> Option<AxisArgs> {
+        self.axis(|args| args.clone())
+    }
+}
+
+impl AxisEvent for Event {
+    fn from_axis_args(args: &AxisArgs, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Axis(args.clone()), timestamp))
+    }
+
+    fn axis<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(&AxisArgs) -> U
+    {
+        match *self {
+            Event::Input(Input::Axis(ref args), _) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_axis() {
+        let args = AxisArgs { axis: "steer".into(), value: -0.5 };
+        let e: Event = args.clone().into();
+        let x: Option<Event> = AxisEvent::from_axis_args(&args, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .axis(|args| AxisEvent::from_axis_args(args, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -0,0 +1,69 @@
+use {Event, Input};
+
+/// Stores how the user navigated back.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum BackSource {
+    /// The back button, e.g. of the Android navigation bar.
+    Button,
+    /// A system gesture, e.g. swiping from the edge of the screen on Android.
+    Gesture,
+}
+
+/// Back arguments.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct BackArgs {
+    /// How the user navigated back.
+    pub source: BackSource,
+}
+
+/// User navigated back, for example with the Android back button or back gesture.
+///
+/// This is distinct from pressing `Key::Escape`.
+/// Calling `AdvancedWindow::set_back_handled(true)` while handling the event
+/// marks it as handled, e.g. when closing a screen of a navigation stack.
+/// Otherwise, the platform performs its default action, such as closing the application.
+pub trait BackEvent: Sized {
+    /// Creates a back event from arguments.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_back_args(args: &BackArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a back event.
+    fn back<U, F>(&self, f: F) -> Option<U> where F: FnMut(&BackArgs) -> U;
+    /// Returns back arguments.
+    fn back_args(&self) -> Option<BackArgs> {
+        self.back(|args| *args)
+    }
+}
+
+impl BackEvent for Event {
+    fn from_back_args(args: &BackArgs, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Back(*args), timestamp))
+    }
+
+    fn back<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(&BackArgs) -> U
+    {
+        match *self {
+            Event::Input(Input::Back(ref args), _) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_back() {
+        let e: Event = BackArgs { source: BackSource::Button }.into();
+        let args = BackArgs { source: BackSource::Gesture };
+        let x: Option<Event> = BackEvent::from_back_args(&args, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .back(|args| BackEvent::from_back_args(args, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -0,0 +1,135 @@
+//! Capturing input for rebinding controls.
+
+use std::collections::HashMap;
+
+use {Button, ButtonArgs, ButtonState, Event, HatState, Input, Key, Motion, Touch};
+use super::{AxisSource, Gesture, Trigger};
+
+/// The input captured for rebinding.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Captured {
+    /// A trigger to bind to an action.
+    Trigger(Trigger),
+    /// A source to bind to an axis.
+    Axis(AxisSource),
+    /// The user pressed the cancel button.
+    Cancelled,
+}
+
+/// Listens for the next meaningful input, to build a "press any key" rebinding screen:
+///
+/// ```ignore
+/// let mut capture = Capture::new();
+/// while let Some(e) = events.next(&mut window) {
+///     match capture.event(&e) {
+///         Some(Captured::Trigger(trigger)) => {
+///             bindings.unbind_action("jump");
+///             bindings.bind_action("jump", trigger);
+///             break;
+///         }
+///         Some(_) => break,
+///         None => {}
+///     }
+/// }
+/// ```
+///
+/// Mouse motion is ignored to filter jitter.
+/// A controller axis is captured when it moves further than the threshold from where it
+/// was first seen, so drifting sticks and triggers that rest at `-1.0` are not captured.
+/// Input should not be passed to the bindings while capturing.
+#[derive(Clone, Debug)]
+pub struct Capture {
+    threshold: f64,
+    cancel: Option<Button>,
+    rest: HashMap<(i32, u8), f64>,
+}
+
+impl Capture {
+    /// Creates a new capture with threshold `0.5`, cancelled by `Escape`.
+    pub fn new() -> Capture {
+        Capture {
+            threshold: 0.5,
+            cancel: Some(Key::Escape.into()),
+            rest: HashMap::new(),
+        }
+    }
+
+    /// Sets how far a controller axis must move to be captured.
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Sets the button that cancels capturing, or `None` to capture all buttons.
+    pub fn cancel_button(mut self, button: Option<Button>) -> Self {
+        self.cancel = button;
+        self
+    }
+
+    /// Returns the captured input, if the event is meaningful input.
+    pub fn event(&mut self, e: &Event) -> Option<Captured> {
+        let input = match *e {
+            Event::Input(ref input, _) => input,
+            _ => return None,
+        };
+        match *input {
+            Input::Button(ButtonArgs { state: ButtonState::Press, button, .. }) => {
+                match button {
+                    Button::Keyboard(Key::Unknown) => None,
+                    Button::Hat(hat) if hat.state == HatState::Centered => None,
+                    _ if Some(button) == self.cancel => Some(Captured::Cancelled),
+                    _ => Some(Captured::Trigger(Trigger::Button(button))),
+                }
+            }
+            Input::Move(Motion::ControllerAxis(args)) => {
+                let rest = *self.rest.entry((args.id, args.axis)).or_insert(args.position);
+                if (args.position - rest).abs() >= self.threshold {
+                    Some(Captured::Axis(AxisSource::ControllerAxis {
+                        id: args.id,
+                        axis: args.axis,
+                    }))
+                } else {
+                    None
+                }
+            }
+            Input::Move(Motion::Touch(args)) if args.touch == Touch::Start => {
+                Some(Captured::Trigger(Trigger::Gesture(Gesture::Touch(1))))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Default for Capture {
+    fn default() -> Capture {
+        Capture::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {ControllerAxisArgs, MouseButton};
+
+    #[test]
+    fn test_capture() {
+        let press = |button: Button| -> Event {
+            ButtonArgs {
+                state: ButtonState::Press,
+                button: button,
+                scancode: None,
+                device: None,
+            }.into()
+        };
+        let mut capture = Capture::new();
+        assert_eq!(capture.event(&Input::Move(Motion::MouseRelative([1.0, 0.0])).into()), None);
+        // A trigger resting at `-1.0` drifts.
+        assert_eq!(capture.event(&ControllerAxisArgs::new(0, 2, -1.0).into()), None);
+        assert_eq!(capture.event(&ControllerAxisArgs::new(0, 2, -0.8).into()), None);
+        assert_eq!(capture.event(&ControllerAxisArgs::new(0, 2, 0.0).into()),
+                   Some(Captured::Axis(AxisSource::ControllerAxis { id: 0, axis: 2 })));
+        assert_eq!(capture.event(&press(MouseButton::Left.into())),
+                   Some(Captured::Trigger(Trigger::Button(MouseButton::Left.into()))));
+        assert_eq!(capture.event(&press(Key::Escape.into())), Some(Captured::Cancelled));
+    }
+}
@@ -0,0 +1,180 @@
+//! Matching sequences of button presses.
+
+use std::collections::VecDeque;
+
+use {Button, ButtonArgs, ButtonState, ComboArgs, Event, Input, Loop};
+
+/// A named sequence of button presses within a timing window,
+/// e.g. a double tap to dash or a quarter circle followed by punch.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct Combo {
+    /// The name of the combo.
+    pub combo: String,
+    /// The buttons to press in order.
+    pub sequence: Vec<Button>,
+    /// The maximum time in seconds from the first press to the last press.
+    pub window: f64,
+}
+
+/// Records button presses and matches them against combos.
+///
+/// Pass every event to [`event()`](#method.event),
+/// which returns a combo event when the latest presses match a combo:
+///
+/// ```ignore
+/// let mut combos = ComboBuffer::new();
+/// combos.register("dash", vec![Key::Right.into(), Key::Right.into()], 0.25);
+/// while let Some(e) = events.next(&mut window) {
+///     if let Some(e) = combos.event(&e) {
+///         // Handle combo event.
+///     }
+/// }
+/// ```
+///
+/// Presses are timed with the delta time of update events,
+/// so matching is deterministic when replaying input.
+/// The presses must follow each other without other presses in between.
+/// When several combos match, the longest sequence wins.
+/// The buffer is cleared after a match, so a triple tap matches a double tap once.
+#[derive(Clone, Debug, Default)]
+pub struct ComboBuffer {
+    combos: Vec<Combo>,
+    presses: VecDeque<(f64, Button)>,
+    time: f64,
+}
+
+impl ComboBuffer {
+    /// Creates a combo buffer without combos.
+    pub fn new() -> ComboBuffer {
+        ComboBuffer::default()
+    }
+
+    /// Registers a combo.
+    ///
+    /// Panics if the sequence is empty.
+    pub fn register(&mut self, combo: &str, sequence: Vec<Button>, window: f64) {
+        assert!(!sequence.is_empty(), "Expected a non-empty combo sequence");
+        self.combos.push(Combo {
+            combo: combo.into(),
+            sequence: sequence,
+            window: window,
+        });
+    }
+
+    /// Removes a combo.
+    pub fn unregister(&mut self, combo: &str) {
+        self.combos.retain(|c| c.combo != combo);
+    }
+
+    /// Returns the registered combos.
+    pub fn combos(&self) -> &[Combo] {
+        &self.combos
+    }
+
+    /// Moves time forward.
+    ///
+    /// This is done automatically on update events.
+    pub fn advance(&mut self, dt: f64) {
+        self.time += dt;
+    }
+
+    /// Removes all recorded presses.
+    pub fn clear(&mut self) {
+        self.presses.clear();
+    }
+
+    /// Records a button press, returning a combo event if a combo matched.
+    ///
+    /// The combo event preserves the time stamp of the input event.
+    pub fn event(&mut self, e: &Event) -> Option<Event> {
+        match *e {
+            Event::Loop(Loop::Update(args)) => {
+                self.advance(args.dt);
+                None
+            }
+            Event::Input(Input::Button(ButtonArgs { state: ButtonState::Press, button, .. }),
+                         timestamp) => {
+                self.press(button)
+                    .map(|combo| Event::Input(Input::Combo(ComboArgs { combo: combo }), timestamp))
+            }
+            _ => None,
+        }
+    }
+
+    /// Records a button press, returning the name of the matched combo, if any.
+    pub fn press(&mut self, button: Button) -> Option<String> {
+        let time = self.time;
+        self.presses.push_back((time, button));
+        let max_len = self.combos.iter().map(|c| c.sequence.len()).max().unwrap_or(0);
+        while self.presses.len() > max_len {
+            self.presses.pop_front();
+        }
+
+        let mut best: Option<&Combo> = None;
+        for combo in &self.combos {
+            let n = combo.sequence.len();
+            if n > self.presses.len() || best.map(|b| b.sequence.len() >= n).unwrap_or(false) {
+                continue;
+            }
+            let start = self.presses.len() - n;
+            let matches = self.presses.iter().skip(start).zip(&combo.sequence)
+                .all(|(&(_, pressed), &button)| pressed == button);
+            if matches && time - self.presses[start].0 <= combo.window {
+                best = Some(combo);
+            }
+        }
+        let res = best.map(|combo| combo.combo.clone());
+        if res.is_some() {
+            self.presses.clear();
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {ComboEvent, HatState, Key, UpdateArgs};
+    use controller::ControllerHat;
+
+    #[test]
+    fn test_combo_buffer() {
+        let press = |button: Button| -> Event {
+            ButtonArgs {
+                state: ButtonState::Press,
+                button: button,
+                scancode: None,
+                device: None,
+            }.into()
+        };
+        let update: Event = UpdateArgs { dt: 0.1, tick: 0, time: 0.0 }.into();
+        let hat = |state| Button::Hat(ControllerHat::new(0, 0, state));
+
+        let mut combos = ComboBuffer::new();
+        combos.register("dash", vec![Key::Right.into(), Key::Right.into()], 0.25);
+        combos.register("fireball", vec![
+            hat(HatState::Down),
+            hat(HatState::RightDown),
+            hat(HatState::Right),
+            Key::P.into(),
+        ], 0.5);
+
+        assert!(combos.event(&press(Key::Right.into())).is_none());
+        combos.event(&update);
+        let e = combos.event(&press(Key::Right.into())).unwrap();
+        assert_eq!(e.combo_args().unwrap().combo, "dash");
+
+        // Too slow.
+        combos.event(&press(Key::Right.into()));
+        for _ in 0..3 {
+            combos.event(&update);
+        }
+        assert!(combos.event(&press(Key::Right.into())).is_none());
+
+        for &state in &[HatState::Down, HatState::RightDown, HatState::Right] {
+            assert!(combos.event(&press(hat(state))).is_none());
+            combos.event(&update);
+        }
+        assert_eq!(combos.press(Key::P.into()), Some("fireball".into()));
+    }
+}
@@ -0,0 +1,94 @@
+//! Detecting physical input bound more than once.
+
+use super::{Bindings, PhysicalInput};
+
+/// An action or axis of a binding.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Eq, Hash, Debug)]
+pub enum BindingTarget {
+    /// The name of an action.
+    Action(String),
+    /// The name of an axis.
+    Axis(String),
+}
+
+/// Physical input bound to several actions or axes.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Conflict {
+    /// The physical input.
+    pub input: PhysicalInput,
+    /// The contexts and the actions or axes the input is bound to, in the order of binding.
+    pub bindings: Vec<(Option<String>, BindingTarget)>,
+}
+
+impl Conflict {
+    /// Returns `true` if the input is bound in different binding contexts.
+    ///
+    /// The context that is pushed last consumes the input.
+    pub fn is_across_contexts(&self) -> bool {
+        let first = &self.bindings[0].0;
+        self.bindings.iter().any(|&(ref context, _)| context != first)
+    }
+}
+
+impl Bindings {
+    /// Returns physical input bound to more than one action or axis,
+    /// so a settings screen can warn before saving.
+    ///
+    /// Conflicts within the same binding context make the input trigger several actions.
+    /// Use [`Conflict::is_across_contexts`](./struct.Conflict.html#method.is_across_contexts)
+    /// to tell them apart from input that means different things in layered contexts.
+    /// Binding the same input to the same action twice is not a conflict.
+    pub fn conflicts(&self) -> Vec<Conflict> {
+        let mut bound: Vec<Conflict> = vec![];
+        {
+            let mut add = |input: PhysicalInput, context: &Option<String>, target: BindingTarget| {
+                let binding = (context.clone(), target);
+                match bound.iter_mut().find(|c| c.input == input) {
+                    Some(c) => if !c.bindings.contains(&binding) {c.bindings.push(binding)},
+                    None => bound.push(Conflict { input: input, bindings: vec![binding] }),
+                }
+            };
+            for binding in &self.actions {
+                add(binding.trigger.physical_input(), &binding.context,
+                    BindingTarget::Action(binding.action.clone()));
+            }
+            for binding in &self.axes {
+                for input in binding.source.physical_inputs() {
+                    add(input, &binding.context, BindingTarget::Axis(binding.axis.clone()));
+                }
+            }
+        }
+        bound.retain(|c| c.bindings.len() > 1);
+        bound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Key;
+    use bindings::AxisSource;
+
+    #[test]
+    fn test_conflicts() {
+        let mut bindings = Bindings::new();
+        bindings.bind_action("jump", Key::Space);
+        bindings.bind_action("jump", Key::Space);
+        bindings.bind_action("fire", Key::F);
+        bindings.bind_action("interact", Key::F);
+        bindings.bind_action_in("menu", "confirm", Key::Space);
+        bindings.bind_axis("steer", AxisSource::Buttons {
+            negative: Key::A.into(),
+            positive: Key::D.into(),
+        });
+        let conflicts = bindings.conflicts();
+        assert_eq!(conflicts.len(), 2);
+        assert_eq!(conflicts[0].input, PhysicalInput::Button(Key::Space.into()));
+        assert!(conflicts[0].is_across_contexts());
+        assert_eq!(conflicts[1].bindings, vec![
+            (None, BindingTarget::Action("fire".into())),
+            (None, BindingTarget::Action("interact".into())),
+        ]);
+        assert!(!conflicts[1].is_across_contexts());
+    }
+}
@@ -0,0 +1,135 @@
+//! Layered binding contexts.
+
+use std::collections::HashSet;
+
+use Event;
+use super::{Bindings, PhysicalInput};
+
+/// How a binding context treats input that is not bound in the context.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, Hash, Debug)]
+pub enum ContextMode {
+    /// Input bound in the context is consumed,
+    /// other input passes through to lower contexts, e.g. for a vehicle.
+    PassThrough,
+    /// All input is consumed, e.g. for a menu or text entry.
+    Block,
+}
+
+impl Bindings {
+    /// Pushes a binding context on top of the active contexts,
+    /// returning events for actions and axes that changed.
+    ///
+    /// Bindings without a context are always active below all contexts.
+    /// Bindings in a context are active while the context is on the stack,
+    /// unless a higher context consumes their input.
+    pub fn push_context(&mut self, context: &str, mode: ContextMode) -> Vec<Event> {
+        self.state.contexts.push((context.into(), mode));
+        self.changes(None)
+    }
+
+    /// Pops the top binding context, returning events for actions and axes that changed.
+    ///
+    /// Actions that are only active in the popped context are released.
+    pub fn pop_context(&mut self) -> Vec<Event> {
+        self.state.contexts.pop();
+        self.changes(None)
+    }
+
+    /// Returns the active binding contexts, from bottom to top.
+    pub fn contexts(&self) -> Vec<&str> {
+        self.state.contexts.iter().map(|&(ref context, _)| &**context).collect()
+    }
+
+    /// Returns the layer of a context, where `0` is the layer without a context.
+    fn layer(&self, context: &Option<String>) -> Option<usize> {
+        match *context {
+            None => Some(0),
+            Some(ref name) => {
+                self.state.contexts.iter().rposition(|&(ref c, _)| c == name).map(|i| i + 1)
+            }
+        }
+    }
+
+    /// Returns which action and axis bindings receive input,
+    /// taking into account the input consumed by higher contexts.
+    pub(crate) fn usable_bindings(&self) -> (Vec<bool>, Vec<bool>) {
+        let layers = self.state.contexts.len() + 1;
+        let mut bound: Vec<HashSet<PhysicalInput>> = vec![HashSet::new(); layers];
+        let action_layers: Vec<Option<usize>> =
+            self.actions.iter().map(|binding| self.layer(&binding.context)).collect();
+        let axis_layers: Vec<Option<usize>> =
+            self.axes.iter().map(|binding| self.layer(&binding.context)).collect();
+        for (binding, layer) in self.actions.iter().zip(&action_layers) {
+            if let Some(layer) = *layer {
+                bound[layer].insert(binding.trigger.physical_input());
+            }
+        }
+        for (binding, layer) in self.axes.iter().zip(&axis_layers) {
+            if let Some(layer) = *layer {
+                bound[layer].extend(binding.source.physical_inputs());
+            }
+        }
+        // The lowest layer that receives all input.
+        let lowest = self.state.contexts.iter()
+            .rposition(|&(_, mode)| mode == ContextMode::Block)
+            .map(|i| i + 1)
+            .unwrap_or(0);
+        let usable = |layer: Option<usize>, physical: &[PhysicalInput]| match layer {
+            Some(layer) if layer >= lowest => {
+                !bound[layer + 1..].iter().any(|b| physical.iter().any(|p| b.contains(p)))
+            }
+            _ => false,
+        };
+        let actions = self.actions.iter().zip(&action_layers)
+            .map(|(binding, &layer)| usable(layer, &[binding.trigger.physical_input()]))
+            .collect();
+        let axes = self.axes.iter().zip(&axis_layers)
+            .map(|(binding, &layer)| usable(layer, &binding.source.physical_inputs()))
+            .collect();
+        (actions, axes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {ActionEvent, ButtonArgs, ButtonState, Key};
+
+    fn press(key: Key) -> Event {
+        ButtonArgs {
+            state: ButtonState::Press,
+            button: key.into(),
+            scancode: None,
+            device: None,
+        }.into()
+    }
+
+    fn actions(events: Vec<Event>) -> Vec<(String, ButtonState)> {
+        events.iter().filter_map(|e| e.action_args()).map(|a| (a.action, a.state)).collect()
+    }
+
+    #[test]
+    fn test_contexts() {
+        let mut bindings = Bindings::new();
+        bindings.bind_action("jump", Key::Space);
+        bindings.bind_action("fire", Key::F);
+        bindings.bind_action_in("menu", "confirm", Key::Space);
+
+        bindings.push_context("menu", ContextMode::PassThrough);
+        assert_eq!(bindings.contexts(), vec!["menu"]);
+        assert_eq!(actions(bindings.event(&press(Key::Space))),
+                   vec![("confirm".into(), ButtonState::Press)]);
+        assert_eq!(actions(bindings.event(&press(Key::F))),
+                   vec![("fire".into(), ButtonState::Press)]);
+
+        assert_eq!(actions(bindings.pop_context()), vec![
+            ("jump".into(), ButtonState::Press),
+            ("confirm".into(), ButtonState::Release),
+        ]);
+
+        assert_eq!(actions(bindings.push_context("text", ContextMode::Block)), vec![
+            ("jump".into(), ButtonState::Release),
+            ("fire".into(), ButtonState::Release),
+        ]);
+    }
+}
@@ -0,0 +1,66 @@
+//! Response curves for axes.
+
+/// Maps the value of an axis source to the value of the axis,
+/// e.g. to make small stick movements more precise.
+///
+/// Curves are symmetric: they map the magnitude of the value in the range `[0.0, 1.0]`
+/// and keep the sign.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub enum Curve {
+    /// The value is unchanged.
+    Linear,
+    /// The magnitude is raised to the power, e.g. `2.0` for a quadratic curve.
+    Exponential(f64),
+    /// Linear interpolation between control points `[input, output]`,
+    /// sorted by input.
+    ///
+    /// The curve starts at `[0.0, 0.0]` and is flat after the last control point.
+    Points(Vec<[f64; 2]>),
+}
+
+impl Default for Curve {
+    fn default() -> Curve {
+        Curve::Linear
+    }
+}
+
+impl Curve {
+    /// Applies the curve to a value.
+    pub fn apply(&self, value: f64) -> f64 {
+        let magnitude = value.abs();
+        let res = match *self {
+            Curve::Linear => magnitude,
+            Curve::Exponential(exponent) => magnitude.powf(exponent),
+            Curve::Points(ref points) => {
+                let mut prev = [0.0, 0.0];
+                let mut res = None;
+                for &point in points {
+                    if magnitude <= point[0] {
+                        let dx = point[0] - prev[0];
+                        let t = if dx > 0.0 {(magnitude - prev[0]) / dx} else {1.0};
+                        res = Some(prev[1] + t * (point[1] - prev[1]));
+                        break;
+                    }
+                    prev = point;
+                }
+                res.unwrap_or(if points.is_empty() {magnitude} else {prev[1]})
+            }
+        };
+        if value < 0.0 {-res} else {res}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_curve() {
+        assert_eq!(Curve::Linear.apply(-0.5), -0.5);
+        assert_eq!(Curve::Exponential(2.0).apply(-0.5), -0.25);
+        let points = Curve::Points(vec![[0.5, 0.2], [1.0, 1.0]]);
+        assert_eq!(points.apply(0.25), 0.1);
+        assert!((points.apply(-0.75) + 0.6).abs() < 1e-9);
+        assert_eq!(points.apply(2.0), 1.0);
+    }
+}
@@ -0,0 +1,575 @@
+//! Mapping physical input to named actions and axes.
+//!
+//! Games refer to actions such as `"jump"` and axes such as `"steer"`
+//! instead of keys and controller buttons,
+//! so players can change the controls in a config file.
+
+use std::collections::{HashMap, HashSet};
+
+pub use self::capture::{Capture, Captured};
+pub use self::combo::{Combo, ComboBuffer};
+pub use self::conflict::{BindingTarget, Conflict};
+pub use self::context::ContextMode;
+pub use self::curve::Curve;
+pub use self::profile::{InvalidBindingError, PROFILE_VERSION};
+
+use {ActionArgs, AxisArgs, Button, ButtonArgs, ButtonState, ControllerButton, Event, Input, Key,
+     Motion, MouseButton, TimeStamp, Touch};
+
+mod capture;
+mod combo;
+mod conflict;
+mod context;
+mod curve;
+mod preset;
+mod profile;
+
+/// Physical input that activates an action.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, Hash, Debug)]
+pub enum Trigger {
+    /// A keyboard key, mouse button, controller button or controller hat.
+    Button(Button),
+    /// A touch gesture.
+    Gesture(Gesture),
+}
+
+/// A touch gesture.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, Hash, Debug)]
+pub enum Gesture {
+    /// Touching with at least the number of fingers.
+    Touch(u8),
+}
+
+/// How a trigger activates an action.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, Hash, Debug)]
+pub enum ActionMode {
+    /// The action is active while the trigger is held.
+    Hold,
+    /// Each press of the trigger switches the action on or off,
+    /// e.g. for crouching without holding a key.
+    Toggle,
+}
+
+impl Default for ActionMode {
+    fn default() -> ActionMode {
+        ActionMode::Hold
+    }
+}
+
+/// Physical input that can be bound to actions and axes.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, Hash, Debug)]
+pub enum PhysicalInput {
+    /// A keyboard key, mouse button, controller button or controller hat.
+    Button(Button),
+    /// A touch gesture.
+    Gesture(Gesture),
+    /// A controller axis.
+    ControllerAxis {
+        /// Which controller.
+        id: i32,
+        /// The axis of the controller.
+        axis: u8,
+    },
+}
+
+/// Physical input that sets the value of an axis.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub enum AxisSource {
+    /// A controller axis.
+    ControllerAxis {
+        /// Which controller.
+        id: i32,
+        /// The axis of the controller.
+        axis: u8,
+    },
+    /// Two buttons, e.g. `A` and `D`.
+    ///
+    /// The value is `-1.0` or `1.0` while one of the buttons is held,
+    /// and `0.0` while none or both are held.
+    Buttons {
+        /// The button for the negative direction.
+        negative: Button,
+        /// The button for the positive direction.
+        positive: Button,
+    },
+}
+
+/// Binds a trigger to an action.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct ActionBinding {
+    /// The name of the action.
+    pub action: String,
+    /// The trigger of the action.
+    pub trigger: Trigger,
+    /// Whether the trigger is held or toggles the action.
+    #[serde(default)]
+    pub mode: ActionMode,
+    /// The binding context, or `None` to be active in all contexts.
+    #[serde(default)]
+    pub context: Option<String>,
+}
+
+/// Binds an axis source to an axis.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct AxisBinding {
+    /// The name of the axis.
+    pub axis: String,
+    /// The source of the value.
+    pub source: AxisSource,
+    /// The response curve applied to the value of the source.
+    #[serde(default)]
+    pub curve: Curve,
+    /// Whether to invert the value after applying the curve.
+    #[serde(default)]
+    pub invert: bool,
+    /// The binding context, or `None` to be active in all contexts.
+    #[serde(default)]
+    pub context: Option<String>,
+}
+
+impl AxisBinding {
+    /// Returns the value of the axis from the value of the source.
+    pub fn apply(&self, value: f64) -> f64 {
+        let value = self.curve.apply(value);
+        if self.invert {-value} else {value}
+    }
+}
+
+/// Maps physical input to named actions and axes.
+///
+/// Pass every event to [`event()`](#method.event),
+/// which returns action and axis events to handle in addition:
+///
+/// ```ignore
+/// let mut bindings = Bindings::new();
+/// bindings.bind_action("jump", Key::Space);
+/// bindings.bind_action("jump", ControllerButton::new(0, 0));
+/// bindings.bind_axis("steer", AxisSource::ControllerAxis { id: 0, axis: 0 });
+/// while let Some(e) = events.next(&mut window) {
+///     for e in bindings.event(&e) {
+///         if let Some(args) = e.action_args() {
+///             // Handle action.
+///         }
+///     }
+/// }
+/// ```
+///
+/// An action is active while any of its triggers is active.
+/// Triggers can also toggle actions, see [`set_action_mode()`](#method.set_action_mode).
+/// When an axis has several sources, the value with the largest magnitude is used.
+/// Bindings can belong to layered contexts, such as a menu on top of gameplay,
+/// so the same key can mean different things per context,
+/// see [`push_context()`](#method.push_context).
+/// The bindings can be serialized to and from config files;
+/// the state of the physical input is not serialized.
+#[derive(Clone, Deserialize, Serialize, Default, Debug)]
+pub struct Bindings {
+    actions: Vec<ActionBinding>,
+    axes: Vec<AxisBinding>,
+    #[serde(skip)]
+    state: State,
+}
+
+/// The state of physical input and emitted actions and axes.
+#[derive(Clone, Default, Debug)]
+struct State {
+    buttons: HashSet<Button>,
+    touches: HashSet<(i64, i64)>,
+    controller_axes: HashMap<(i32, u8), f64>,
+    actions: HashSet<String>,
+    axes: HashMap<String, f64>,
+    contexts: Vec<(String, ContextMode)>,
+    // Held toggle triggers by action, and actions that are switched on.
+    held: HashSet<(String, Trigger)>,
+    toggled: HashSet<String>,
+}
+
+impl Trigger {
+    /// Returns the physical input of the trigger.
+    pub fn physical_input(&self) -> PhysicalInput {
+        match *self {
+            Trigger::Button(button) => PhysicalInput::Button(button),
+            Trigger::Gesture(gesture) => PhysicalInput::Gesture(gesture),
+        }
+    }
+}
+
+impl AxisSource {
+    /// Returns the physical inputs of the source.
+    pub fn physical_inputs(&self) -> Vec<PhysicalInput> {
+        match *self {
+            AxisSource::ControllerAxis { id, axis } => {
+                vec![PhysicalInput::ControllerAxis { id: id, axis: axis }]
+            }
+            AxisSource::Buttons { negative, positive } => {
+                vec![PhysicalInput::Button(negative), PhysicalInput::Button(positive)]
+            }
+        }
+    }
+}
+
+impl From<Button> for Trigger {
+    fn from(button: Button) -> Trigger {
+        Trigger::Button(button)
+    }
+}
+
+impl From<Key> for Trigger {
+    fn from(key: Key) -> Trigger {
+        Trigger::Button(key.into())
+    }
+}
+
+impl From<MouseButton> for Trigger {
+    fn from(button: MouseButton) -> Trigger {
+        Trigger::Button(button.into())
+    }
+}
+
+impl From<ControllerButton> for Trigger {
+    fn from(button: ControllerButton) -> Trigger {
+        Trigger::Button(button.into())
+    }
+}
+
+impl From<Gesture> for Trigger {
+    fn from(gesture: Gesture) -> Trigger {
+        Trigger::Gesture(gesture)
+    }
+}
+
+impl Bindings {
+    /// Creates bindings without actions or axes.
+    pub fn new() -> Bindings {
+        Bindings::default()
+    }
+
+    /// Binds a trigger to an action.
+    ///
+    /// An action can have several triggers.
+    pub fn bind_action<T: Into<Trigger>>(&mut self, action: &str, trigger: T) {
+        self.actions.push(ActionBinding {
+            action: action.into(),
+            trigger: trigger.into(),
+            mode: ActionMode::Hold,
+            context: None,
+        });
+    }
+
+    /// Binds a trigger to an action in a binding context,
+    /// see [`push_context()`](#method.push_context).
+    pub fn bind_action_in<T: Into<Trigger>>(&mut self, context: &str, action: &str, trigger: T) {
+        self.actions.push(ActionBinding {
+            action: action.into(),
+            trigger: trigger.into(),
+            mode: ActionMode::Hold,
+            context: Some(context.into()),
+        });
+    }
+
+    /// Binds a source to an axis, returning the binding to change the curve:
+    ///
+    /// ```ignore
+    /// bindings.bind_axis("look_y", AxisSource::ControllerAxis { id: 0, axis: 1 }).invert = true;
+    /// ```
+    ///
+    /// An axis can have several sources.
+    pub fn bind_axis(&mut self, axis: &str, source: AxisSource) -> &mut AxisBinding {
+        self.axes.push(AxisBinding {
+            axis: axis.into(),
+            source: source,
+            curve: Curve::Linear,
+            invert: false,
+            context: None,
+        });
+        self.axes.last_mut().unwrap()
+    }
+
+    /// Binds a source to an axis in a binding context,
+    /// see [`push_context()`](#method.push_context).
+    pub fn bind_axis_in(&mut self, context: &str, axis: &str, source: AxisSource)
+                        -> &mut AxisBinding {
+        let binding = self.bind_axis(axis, source);
+        binding.context = Some(context.into());
+        binding
+    }
+
+    /// Sets the response curve of all sources of an axis.
+    pub fn set_axis_curve(&mut self, axis: &str, curve: Curve) {
+        for binding in self.axes.iter_mut().filter(|binding| binding.axis == axis) {
+            binding.curve = curve.clone();
+        }
+    }
+
+    /// Sets whether to invert all sources of an axis.
+    pub fn set_axis_inverted(&mut self, axis: &str, invert: bool) {
+        for binding in self.axes.iter_mut().filter(|binding| binding.axis == axis) {
+            binding.invert = invert;
+        }
+    }
+
+    /// Sets whether all triggers of an action are held or toggle the action.
+    ///
+    /// This is an accessibility option for players who can not hold buttons,
+    /// which requires no changes to the game code.
+    /// Toggled actions are switched off.
+    pub fn set_action_mode(&mut self, action: &str, mode: ActionMode) {
+        for binding in self.actions.iter_mut().filter(|binding| binding.action == action) {
+            binding.mode = mode;
+        }
+        self.state.clear_toggles(action);
+    }
+
+    /// Removes all triggers of an action.
+    pub fn unbind_action(&mut self, action: &str) {
+        self.actions.retain(|binding| binding.action != action);
+        self.state.actions.remove(action);
+        self.state.clear_toggles(action);
+    }
+
+    /// Removes all sources of an axis.
+    pub fn unbind_axis(&mut self, axis: &str) {
+        self.axes.retain(|binding| binding.axis != axis);
+        self.state.axes.remove(axis);
+    }
+
+    /// Returns the action bindings.
+    pub fn action_bindings(&self) -> &[ActionBinding] {
+        &self.actions
+    }
+
+    /// Returns the axis bindings.
+    pub fn axis_bindings(&self) -> &[AxisBinding] {
+        &self.axes
+    }
+
+    /// Returns `true` if the action is active.
+    pub fn is_active(&self, action: &str) -> bool {
+        self.state.actions.contains(action)
+    }
+
+    /// Returns the value of an axis.
+    pub fn axis_value(&self, axis: &str) -> f64 {
+        self.state.axes.get(axis).cloned().unwrap_or(0.0)
+    }
+
+    /// Updates the state of physical input from an event,
+    /// returning action and axis events for actions and axes that changed.
+    ///
+    /// The returned events preserve the time stamp of the input event.
+    pub fn event(&mut self, e: &Event) -> Vec<Event> {
+        let (input, timestamp) = match *e {
+            Event::Input(ref input, timestamp) => (input, timestamp),
+            _ => return vec![],
+        };
+        match *input {
+            Input::Button(ButtonArgs { state: ButtonState::Press, button, .. }) => {
+                self.state.buttons.insert(button);
+            }
+            Input::Button(ButtonArgs { state: ButtonState::Release, button, .. }) => {
+                self.state.buttons.remove(&button);
+            }
+            Input::Move(Motion::ControllerAxis(args)) => {
+                self.state.controller_axes.insert((args.id, args.axis), args.position);
+            }
+            Input::Move(Motion::Touch(args)) => {
+                let key = (args.device, args.id);
+                match args.touch {
+                    Touch::Start | Touch::Move => {
+                        self.state.touches.insert(key);
+                    }
+                    Touch::End | Touch::Cancel => {
+                        self.state.touches.remove(&key);
+                    }
+                }
+            }
+            Input::Focus(false) => {
+                // Release all input, because releases are not received without focus.
+                self.state.buttons.clear();
+                self.state.touches.clear();
+                self.state.controller_axes.clear();
+            }
+            _ => return vec![],
+        }
+        self.changes(timestamp)
+    }
+
+    /// Returns events for actions and axes that changed, in the order of the bindings.
+    fn changes(&mut self, timestamp: Option<TimeStamp>) -> Vec<Event> {
+        let (usable_actions, usable_axes) = self.usable_bindings();
+        // Each press of a toggle trigger switches its action on or off.
+        for (binding, &usable) in self.actions.iter().zip(&usable_actions) {
+            if binding.mode != ActionMode::Toggle {
+                continue;
+            }
+            let key = (binding.action.clone(), binding.trigger);
+            if !usable || !self.state.is_triggered(&binding.trigger) {
+                self.state.held.remove(&key);
+            } else if self.state.held.insert(key) &&
+                      !self.state.toggled.remove(&binding.action) {
+                self.state.toggled.insert(binding.action.clone());
+            }
+        }
+        let mut actions: Vec<(&str, bool)> = vec![];
+        for (binding, usable) in self.actions.iter().zip(usable_actions) {
+            // Unusable bindings are inactive, but toggled actions stay switched on.
+            let active = usable && match binding.mode {
+                ActionMode::Hold => self.state.is_triggered(&binding.trigger),
+                ActionMode::Toggle => self.state.toggled.contains(&binding.action),
+            };
+            match actions.iter_mut().find(|&&mut (action, _)| action == binding.action) {
+                Some(&mut (_, ref mut x)) => *x |= active,
+                None => actions.push((&binding.action, active)),
+            }
+        }
+        let mut axes: Vec<(&str, f64)> = vec![];
+        for (binding, usable) in self.axes.iter().zip(usable_axes) {
+            let value = if usable {binding.apply(self.state.value(&binding.source))} else {0.0};
+            match axes.iter_mut().find(|&&mut (axis, _)| axis == binding.axis) {
+                Some(&mut (_, ref mut x)) => if value.abs() > x.abs() {*x = value},
+                None => axes.push((&binding.axis, value)),
+            }
+        }
+
+        let mut events = vec![];
+        for (action, active) in actions {
+            if active == self.state.actions.contains(action) {
+                continue;
+            }
+            let state = if active {
+                self.state.actions.insert(action.into());
+                ButtonState::Press
+            } else {
+                self.state.actions.remove(action);
+                ButtonState::Release
+            };
+            let args = ActionArgs { action: action.into(), state: state };
+            events.push(Event::Input(Input::Action(args), timestamp));
+        }
+        for (axis, value) in axes {
+            if value == self.state.axes.get(axis).cloned().unwrap_or(0.0) {
+                continue;
+            }
+            self.state.axes.insert(axis.into(), value);
+            let args = AxisArgs { axis: axis.into(), value: value };
+            events.push(Event::Input(Input::Axis(args), timestamp));
+        }
+        events
+    }
+}
+
+impl State {
+    fn clear_toggles(&mut self, action: &str) {
+        self.held.retain(|&(ref a, _)| a != action);
+        self.toggled.remove(action);
+    }
+
+    fn is_triggered(&self, trigger: &Trigger) -> bool {
+        match *trigger {
+            Trigger::Button(button) => self.buttons.contains(&button),
+            Trigger::Gesture(Gesture::Touch(fingers)) => {
+                fingers > 0 && self.touches.len() >= fingers as usize
+            }
+        }
+    }
+
+    fn value(&self, source: &AxisSource) -> f64 {
+        match *source {
+            AxisSource::ControllerAxis { id, axis } => {
+                self.controller_axes.get(&(id, axis)).cloned().unwrap_or(0.0)
+            }
+            AxisSource::Buttons { negative, positive } => {
+                let negative = if self.buttons.contains(&negative) {1.0} else {0.0};
+                let positive = if self.buttons.contains(&positive) {1.0} else {0.0};
+                positive - negative
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {ActionEvent, AxisEvent, ControllerAxisArgs};
+
+    fn press<B: Into<Button>>(button: B) -> Event {
+        ButtonArgs {
+            state: ButtonState::Press,
+            button: button.into(),
+            scancode: None,
+            device: None,
+        }.into()
+    }
+
+    fn release<B: Into<Button>>(button: B) -> Event {
+        ButtonArgs {
+            state: ButtonState::Release,
+            button: button.into(),
+            scancode: None,
+            device: None,
+        }.into()
+    }
+
+    #[test]
+    fn test_bindings() {
+        let mut bindings = Bindings::new();
+        bindings.bind_action("jump", Key::Space);
+        bindings.bind_action("jump", ControllerButton::new(0, 0));
+        bindings.bind_axis("steer", AxisSource::ControllerAxis { id: 0, axis: 0 });
+        bindings.bind_axis("steer", AxisSource::Buttons {
+            negative: Key::A.into(),
+            positive: Key::D.into(),
+        });
+
+        let events = bindings.event(&press(Key::Space));
+        assert_eq!(events.len(), 1);
+        let args = events[0].action_args().unwrap();
+        assert_eq!(args.action, "jump");
+        assert_eq!(args.state, ButtonState::Press);
+
+        // The action stays active while any trigger is active.
+        assert!(bindings.event(&press(ControllerButton::new(0, 0))).is_empty());
+        assert!(bindings.event(&release(Key::Space)).is_empty());
+        let events = bindings.event(&release(ControllerButton::new(0, 0)));
+        assert_eq!(events[0].action_args().unwrap().state, ButtonState::Release);
+        assert!(!bindings.is_active("jump"));
+
+        let events = bindings.event(&press(Key::A));
+        assert_eq!(events[0].axis_args().unwrap().value, -1.0);
+        let events = bindings.event(&ControllerAxisArgs::new(0, 0, 0.5).into());
+        assert!(events.is_empty());
+        assert_eq!(bindings.axis_value("steer"), -1.0);
+        bindings.event(&release(Key::A));
+        assert_eq!(bindings.axis_value("steer"), 0.5);
+
+        bindings.set_axis_curve("steer", Curve::Exponential(2.0));
+        bindings.set_axis_inverted("steer", true);
+        bindings.event(&ControllerAxisArgs::new(0, 0, 0.6).into());
+        assert!((bindings.axis_value("steer") + 0.36).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_toggle() {
+        let mut bindings = Bindings::new();
+        bindings.bind_action("crouch", Key::C);
+        bindings.bind_action("crouch", ControllerButton::new(0, 1));
+        bindings.set_action_mode("crouch", ActionMode::Toggle);
+
+        let events = bindings.event(&press(Key::C));
+        assert_eq!(events[0].action_args().unwrap().state, ButtonState::Press);
+        assert!(bindings.event(&release(Key::C)).is_empty());
+        assert!(bindings.is_active("crouch"));
+        // Losing focus does not switch the toggle off.
+        assert!(bindings.event(&Input::Focus(false).into()).is_empty());
+        let events = bindings.event(&press(ControllerButton::new(0, 1)));
+        assert_eq!(events[0].action_args().unwrap().state, ButtonState::Release);
+        assert!(!bindings.is_active("crouch"));
+
+        bindings.event(&release(ControllerButton::new(0, 1)));
+        bindings.set_action_mode("crouch", ActionMode::Hold);
+        bindings.event(&press(Key::C));
+        assert!(bindings.is_active("crouch"));
+        bindings.event(&release(Key::C));
+        assert!(!bindings.is_active("crouch"));
+    }
+}
@@ -0,0 +1,101 @@
+//! Default bindings for controllers and keyboards.
+
+use {ControllerButton, ControllerKind, Key, KeyboardLayout};
+use super::{AxisSource, Bindings};
+
+impl Bindings {
+    /// Returns default bindings for a controller, as a starting point for players to change.
+    ///
+    /// The bindings use the following names:
+    ///
+    /// - actions `"confirm"` and `"cancel"` in the `"menu"` binding context
+    /// - actions `"menu"`, `"jump"` and `"interact"`
+    /// - axes `"move_x"`, `"move_y"`, `"look_x"` and `"look_y"`,
+    ///   where positive values are right and up
+    pub fn controller_preset(kind: ControllerKind, id: i32) -> Bindings {
+        // Nintendo controllers confirm with the right face button.
+        let (confirm, cancel) = match kind {
+            ControllerKind::Nintendo => (1, 0),
+            ControllerKind::Xbox | ControllerKind::PlayStation | ControllerKind::Generic => (0, 1),
+        };
+        let mut bindings = Bindings::new();
+        bindings.bind_action_in("menu", "confirm", ControllerButton::new(id, confirm));
+        bindings.bind_action_in("menu", "cancel", ControllerButton::new(id, cancel));
+        bindings.bind_action("menu", ControllerButton::new(id, 6));
+        bindings.bind_action("jump", ControllerButton::new(id, confirm));
+        bindings.bind_action("interact", ControllerButton::new(id, 2));
+        bindings.bind_axis("move_x", AxisSource::ControllerAxis { id: id, axis: 0 });
+        // Sticks are positive downwards.
+        bindings.bind_axis("move_y", AxisSource::ControllerAxis { id: id, axis: 1 }).invert = true;
+        bindings.bind_axis("look_x", AxisSource::ControllerAxis { id: id, axis: 2 });
+        bindings.bind_axis("look_y", AxisSource::ControllerAxis { id: id, axis: 3 }).invert = true;
+        bindings
+    }
+
+    /// Returns default bindings for a keyboard layout,
+    /// with the same names as [`controller_preset()`](#method.controller_preset).
+    ///
+    /// Movement uses the keys at the positions of `W`, `A`, `S` and `D` on a QWERTY keyboard,
+    /// and looking uses the arrow keys.
+    pub fn keyboard_preset(layout: KeyboardLayout) -> Bindings {
+        let (up, left, down, right, interact) = match layout {
+            KeyboardLayout::Qwerty | KeyboardLayout::Qwertz => {
+                (Key::W, Key::A, Key::S, Key::D, Key::E)
+            }
+            KeyboardLayout::Azerty => (Key::Z, Key::Q, Key::S, Key::D, Key::E),
+            KeyboardLayout::Dvorak => (Key::Comma, Key::A, Key::O, Key::E, Key::Period),
+        };
+        let mut bindings = Bindings::new();
+        bindings.bind_action_in("menu", "confirm", Key::Return);
+        bindings.bind_action_in("menu", "cancel", Key::Backspace);
+        bindings.bind_action("menu", Key::Escape);
+        bindings.bind_action("jump", Key::Space);
+        bindings.bind_action("interact", interact);
+        bindings.bind_axis("move_x", AxisSource::Buttons {
+            negative: left.into(),
+            positive: right.into(),
+        });
+        bindings.bind_axis("move_y", AxisSource::Buttons {
+            negative: down.into(),
+            positive: up.into(),
+        });
+        bindings.bind_axis("look_x", AxisSource::Buttons {
+            negative: Key::Left.into(),
+            positive: Key::Right.into(),
+        });
+        bindings.bind_axis("look_y", AxisSource::Buttons {
+            negative: Key::Down.into(),
+            positive: Key::Up.into(),
+        });
+        bindings
+    }
+
+    /// Adds the bindings of another set, e.g. to combine controller and keyboard presets.
+    pub fn extend(&mut self, other: Bindings) {
+        self.actions.extend(other.actions);
+        self.axes.extend(other.axes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets() {
+        let layouts = [
+            KeyboardLayout::Qwerty,
+            KeyboardLayout::Qwertz,
+            KeyboardLayout::Azerty,
+            KeyboardLayout::Dvorak,
+        ];
+        for &layout in &layouts {
+            assert!(Bindings::keyboard_preset(layout).conflicts().is_empty());
+        }
+        let mut bindings = Bindings::controller_preset(ControllerKind::Nintendo, 0);
+        assert!(bindings.conflicts().iter().all(|c| c.is_across_contexts()));
+        assert_eq!(bindings.action_bindings()[0].trigger, ControllerButton::new(0, 1).into());
+        bindings.extend(Bindings::keyboard_preset(KeyboardLayout::Azerty));
+        assert_eq!(bindings.action_bindings().len(), 10);
+    }
+}
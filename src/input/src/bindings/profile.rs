@@ -0,0 +1,266 @@
+//! Sharing bindings between installs as binding profiles.
+//!
+//! Requires the `json` or `ron` feature for importing and exporting.
+//!
+//! A binding profile is a versioned document with the action and axis bindings:
+//!
+//! ```ron
+//! (
+//!     version: 1,
+//!     actions: [
+//!         (action: "jump", trigger: Button(Keyboard(Space))),
+//!     ],
+//!     axes: [
+//!         (axis: "steer", source: ControllerAxis(id: 0, axis: 0), invert: true),
+//!     ],
+//! )
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use super::{AxisSource, Bindings, Curve, Gesture, Trigger};
+#[cfg(any(feature = "json", feature = "ron"))]
+use super::{ActionBinding, AxisBinding};
+
+/// The version of binding profiles written by this library.
+pub const PROFILE_VERSION: u32 = 1;
+
+/// An error for when a binding is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidBindingError {
+    /// The offending entry, e.g. `"actions[2]"`.
+    pub entry: String,
+    /// Describes what is wrong with the entry.
+    pub message: String,
+}
+
+impl fmt::Display for InvalidBindingError {
+    fn fmt(&self, w: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(w, "Invalid binding `{}`: {}", self.entry, self.message)
+    }
+}
+
+impl Error for InvalidBindingError {}
+
+#[cfg(any(feature = "json", feature = "ron"))]
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct Profile {
+    version: u32,
+    #[serde(default)]
+    actions: Vec<ActionBinding>,
+    #[serde(default)]
+    axes: Vec<AxisBinding>,
+}
+
+#[cfg(any(feature = "json", feature = "ron"))]
+impl Profile {
+    fn into_bindings(self) -> Result<Bindings, InvalidBindingError> {
+        if self.version == 0 || self.version > PROFILE_VERSION {
+            return Err(InvalidBindingError {
+                entry: "version".into(),
+                message: format!("Expected a version from 1 to {}, found {}",
+                                 PROFILE_VERSION, self.version),
+            });
+        }
+        let bindings = Bindings {
+            actions: self.actions,
+            axes: self.axes,
+            ..Default::default()
+        };
+        bindings.validate()?;
+        Ok(bindings)
+    }
+}
+
+fn invalid(entry: String, message: String) -> Result<(), InvalidBindingError> {
+    Err(InvalidBindingError { entry: entry, message: message })
+}
+
+fn validate_name(entry: &str, kind: &str, name: &str, context: &Option<String>)
+    -> Result<(), InvalidBindingError>
+{
+    if name.is_empty() {
+        return invalid(entry.into(), format!("Expected a non-empty {} name", kind));
+    }
+    if context.as_ref().map(|c| c.is_empty()).unwrap_or(false) {
+        return invalid(entry.into(), "Expected a non-empty context name".into());
+    }
+    Ok(())
+}
+
+fn validate_curve(entry: &str, curve: &Curve) -> Result<(), InvalidBindingError> {
+    match *curve {
+        Curve::Linear => {}
+        Curve::Exponential(exponent) => {
+            if !exponent.is_finite() || exponent <= 0.0 {
+                return invalid(entry.into(),
+                               format!("Expected a positive exponent, found {}", exponent));
+            }
+        }
+        Curve::Points(ref points) => {
+            let mut prev = 0.0;
+            for (i, point) in points.iter().enumerate() {
+                if !point[0].is_finite() || !point[1].is_finite() {
+                    return invalid(entry.into(),
+                                   format!("Expected finite control points, found {:?} at {}",
+                                           point, i));
+                }
+                if point[0] < prev {
+                    return invalid(entry.into(),
+                                   format!("Expected control points sorted by input, \
+                                            found {:?} at {}", point, i));
+                }
+                prev = point[0];
+            }
+        }
+    }
+    Ok(())
+}
+
+impl Bindings {
+    /// Checks that the bindings can be used.
+    ///
+    /// This is called when importing a binding profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the first invalid binding, for example
+    /// when an action has an empty name or a curve has unsorted control points.
+    pub fn validate(&self) -> Result<(), InvalidBindingError> {
+        for (i, binding) in self.actions.iter().enumerate() {
+            let entry = format!("actions[{}]", i);
+            validate_name(&entry, "action", &binding.action, &binding.context)?;
+            if let Trigger::Gesture(Gesture::Touch(0)) = binding.trigger {
+                return invalid(entry, "Expected a touch gesture with at least one finger".into());
+            }
+        }
+        for (i, binding) in self.axes.iter().enumerate() {
+            let entry = format!("axes[{}]", i);
+            validate_name(&entry, "axis", &binding.axis, &binding.context)?;
+            if let AxisSource::Buttons { negative, positive } = binding.source {
+                if negative == positive {
+                    return invalid(entry, format!("Expected different buttons for the negative \
+                                                   and positive direction, found {:?}", negative));
+                }
+            }
+            validate_curve(&entry, &binding.curve)?;
+        }
+        Ok(())
+    }
+
+    #[cfg(any(feature = "json", feature = "ron"))]
+    fn to_profile(&self) -> Profile {
+        Profile {
+            version: PROFILE_VERSION,
+            actions: self.actions.clone(),
+            axes: self.axes.clone(),
+        }
+    }
+
+    /// Exports the bindings as a JSON binding profile.
+    ///
+    /// Requires the `json` feature.
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> String {
+        ::serde_json::to_string_pretty(&self.to_profile())
+            .expect("Bindings can always be serialized")
+    }
+
+    /// Imports bindings from a JSON binding profile.
+    ///
+    /// Requires the `json` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with the location in the document when
+    /// the document can not be parsed, has unknown fields or fields of the wrong type.
+    /// Returns an [`InvalidBindingError`](./struct.InvalidBindingError.html)
+    /// when the version is not supported or a binding is invalid.
+    #[cfg(feature = "json")]
+    pub fn from_json(source: &str) -> Result<Bindings, Box<Error>> {
+        let profile: Profile = ::serde_json::from_str(source)?;
+        Ok(profile.into_bindings()?)
+    }
+
+    /// Exports the bindings as a RON binding profile.
+    ///
+    /// Requires the `ron` feature.
+    #[cfg(feature = "ron")]
+    pub fn to_ron(&self) -> String {
+        ::ron::ser::to_string_pretty(&self.to_profile(), Default::default())
+            .expect("Bindings can always be serialized")
+    }
+
+    /// Imports bindings from a RON binding profile.
+    ///
+    /// Requires the `ron` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with the location in the document when
+    /// the document can not be parsed, has unknown fields or fields of the wrong type.
+    /// Returns an [`InvalidBindingError`](./struct.InvalidBindingError.html)
+    /// when the version is not supported or a binding is invalid.
+    #[cfg(feature = "ron")]
+    pub fn from_ron(source: &str) -> Result<Bindings, Box<Error>> {
+        let profile: Profile = ::ron::from_str(source)?;
+        Ok(profile.into_bindings()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {Key, KeyboardLayout};
+
+    #[test]
+    fn test_validate() {
+        let mut bindings = Bindings::keyboard_preset(KeyboardLayout::Qwerty);
+        assert_eq!(bindings.validate(), Ok(()));
+        bindings.bind_action("jump", Gesture::Touch(0));
+        let entry = format!("actions[{}]", bindings.action_bindings().len() - 1);
+        assert_eq!(bindings.validate().unwrap_err().entry, entry);
+
+        let mut bindings = Bindings::new();
+        bindings.bind_axis("steer", AxisSource::Buttons {
+            negative: Key::A.into(),
+            positive: Key::D.into(),
+        }).curve = Curve::Points(vec![[0.5, 0.2], [0.2, 1.0]]);
+        assert_eq!(bindings.validate().unwrap_err().entry, "axes[0]");
+        bindings.set_axis_curve("steer", Curve::Exponential(2.0));
+        assert_eq!(bindings.validate(), Ok(()));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json() {
+        let bindings = Bindings::keyboard_preset(KeyboardLayout::Azerty);
+        let imported = Bindings::from_json(&bindings.to_json()).unwrap();
+        assert_eq!(imported.action_bindings(), bindings.action_bindings());
+        assert_eq!(imported.axis_bindings(), bindings.axis_bindings());
+        assert!(Bindings::from_json(r#"{"version": 2}"#).is_err());
+        let err = Bindings::from_json(r#"{
+            "version": 1,
+            "actions": [{"action": "", "trigger": {"Button": {"Keyboard": "Space"}}}]
+        }"#).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid binding `actions[0]`: \
+                                     Expected a non-empty action name");
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn test_ron() {
+        let bindings = Bindings::keyboard_preset(KeyboardLayout::Dvorak);
+        let imported = Bindings::from_ron(&bindings.to_ron()).unwrap();
+        assert_eq!(imported.action_bindings(), bindings.action_bindings());
+        assert_eq!(imported.axis_bindings(), bindings.axis_bindings());
+        let imported = Bindings::from_ron(r#"(
+            version: 1,
+            axes: [(axis: "steer", source: ControllerAxis(id: 0, axis: 0), invert: true)],
+        )"#).unwrap();
+        assert_eq!(imported.axis_bindings()[0].axis, "steer");
+        assert!(Bindings::from_ron("(version: 1, unknown: [])").is_err());
+    }
+}
@@ -26,6 +26,13 @@ pub struct ButtonArgs {
     /// Some window backends might not support scancodes.
     /// To test a window backend, use https://github.com/PistonDevelopers/piston-examples/tree/master/user_input
     pub scancode: Option<i32>,
+    /// The keyboard or mouse that sent the event,
+    /// for window backends that distinguish multiple keyboards and mice.
+    /// For controllers and when devices are not distinguished, this is set to `None`.
+    ///
+    /// See [`Seats`](./struct.Seats.html) to support multiple players on one computer.
+    #[serde(default)]
+    pub device: Option<i64>,
 }
 
 /// Changed button state.
@@ -61,7 +68,7 @@ impl ButtonEvent for Event {
 pub trait PressEvent: Sized {
     /// Creates a press event.
     ///
-    /// Preserves scancode from original button event, if any.
+    /// Preserves scancode and device from original button event, if any.
     /// Preserves time stamp from original input event, if any.
     fn from_button(button: Button, old_event: &Self) -> Option<Self>;
     /// Calls closure if this is a press event.
@@ -82,7 +89,8 @@ impl<T> PressEvent for T where T: ButtonEvent {
             ButtonEvent::from_button_args(ButtonArgs {
                 state: ButtonState::Press,
                 button: button,
-                scancode: None
+                scancode: None,
+                device: None
             }, old_event)
         }
     }
@@ -104,7 +112,7 @@ impl<T> PressEvent for T where T: ButtonEvent {
 pub trait ReleaseEvent: Sized {
     /// Creates a release event.
     ///
-    /// Preserves scancode from original button event, if any.
+    /// Preserves scancode and device from original button event, if any.
     /// Preserves time stamp from original input event, if any.
     fn from_button(button: Button, old_event: &Self) -> Option<Self>;
     /// Calls closure if this is a release event.
@@ -125,7 +133,8 @@ impl<T> ReleaseEvent for T where T: ButtonEvent {
             ButtonEvent::from_button_args(ButtonArgs {
                 state: ButtonState::Release,
                 button: button,
-                scancode: None
+                scancode: None,
+                device: None
             }, old_event)
         }
     }
@@ -155,6 +164,7 @@ mod tests {
             state: ButtonState::Press,
             button: Key::S.into(),
             scancode: None,
+            device: None,
         }.into();
         let button = Button::Keyboard(Key::A);
         let x: Option<Event> = PressEvent::from_button(button, &e);
@@ -173,6 +183,7 @@ mod tests {
             state: ButtonState::Release,
             button: Key::S.into(),
             scancode: None,
+            device: None,
         }.into();
         let button = Button::Keyboard(Key::A);
         let x: Option<Event> = ReleaseEvent::from_button(button, &e);
@@ -0,0 +1,57 @@
+use {Event, Loop};
+
+/// Update channel arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct ChannelUpdateArgs {
+    /// The id of the update channel.
+    pub id: u64,
+    /// Delta time in seconds, the fixed update interval of the channel.
+    pub dt: f64,
+}
+
+/// When an update channel registered on the event loop updates,
+/// e.g. physics or AI at their own fixed rate.
+pub trait ChannelUpdateEvent: Sized {
+    /// Creates a channel update event.
+    fn from_channel_update_args(args: &ChannelUpdateArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a channel update event.
+    fn channel_update<U, F>(&self, f: F) -> Option<U> where F: FnMut(&ChannelUpdateArgs) -> U;
+    /// Returns channel update arguments.
+    fn channel_update_args(&self) -> Option<ChannelUpdateArgs> {
+        self.channel_update(|args| *args)
+    }
+}
+
+impl ChannelUpdateEvent for Event {
+    fn from_channel_update_args(args: &ChannelUpdateArgs, _old_event: &Self) -> Option<Self> {
+        Some(Event::Loop(Loop::ChannelUpdate(*args)))
+    }
+
+    fn channel_update<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(&ChannelUpdateArgs) -> U
+    {
+        match *self {
+            Event::Loop(Loop::ChannelUpdate(ref args)) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_channel_update() {
+        let e: Event = ChannelUpdateArgs { id: 0, dt: 0.0 }.into();
+        let x: Option<Event> = ChannelUpdateEvent::from_channel_update_args(
+            &ChannelUpdateArgs { id: 1, dt: 0.1 }, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .channel_update(|args| {
+                ChannelUpdateEvent::from_channel_update_args(args, x.as_ref().unwrap())
+            })
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -5,6 +5,9 @@ use {Event, Input};
 pub struct CloseArgs;
 
 /// Window is closing.
+///
+/// For attempts to close the window that can be vetoed,
+/// see [`CloseRequestedEvent`](./trait.CloseRequestedEvent.html).
 pub trait CloseEvent: Sized {
     /// Creates a close event from arguments.
     ///
@@ -34,6 +37,49 @@ impl CloseEvent for Event {
     }
 }
 
+/// Close requested arguments.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize, Hash)]
+pub struct CloseRequestedArgs;
+
+/// User attempts to close the window, for example by clicking the close button.
+///
+/// The application can veto the request, e.g. to show an "unsaved changes" dialog.
+///
+/// When automatic close is enabled, the window backend marks the window
+/// as closing before emitting this event. Calling `Window::set_should_close(false)`
+/// while handling the event keeps the window open.
+///
+/// When automatic close is disabled, the window stays open
+/// until the application calls `Window::set_should_close(true)`.
+pub trait CloseRequestedEvent: Sized {
+    /// Creates a close requested event from arguments.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_close_requested_args(args: &CloseRequestedArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a close requested event.
+    fn close_requested<U, F>(&self, f: F) -> Option<U> where F: FnMut(&CloseRequestedArgs) -> U;
+    /// Returns close requested arguments.
+    fn close_requested_args(&self) -> Option<CloseRequestedArgs> {
+        self.close_requested(|args| args.clone())
+    }
+}
+
+impl CloseRequestedEvent for Event {
+    fn from_close_requested_args(args: &CloseRequestedArgs, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::CloseRequested(*args), timestamp))
+    }
+
+    fn close_requested<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(&CloseRequestedArgs) -> U
+    {
+        match *self {
+            Event::Input(Input::CloseRequested(ref args), _) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -51,4 +97,21 @@ mod tests {
             .unwrap();
         assert_eq!(x, y);
     }
+
+    #[test]
+    fn test_input_close_requested() {
+        use CloseRequestedArgs;
+        use Event;
+
+        let e: Event = CloseRequestedArgs.into();
+        let x: Option<Event> =
+            CloseRequestedEvent::from_close_requested_args(&CloseRequestedArgs, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .close_requested(|args| {
+                CloseRequestedEvent::from_close_requested_args(args, x.as_ref().unwrap())
+            })
+            .unwrap();
+        assert_eq!(x, y);
+    }
 }
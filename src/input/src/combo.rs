@@ -0,0 +1,56 @@
+use {Event, Input};
+
+/// Combo arguments, such as the name of the combo.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize, Hash)]
+pub struct ComboArgs {
+    /// The name of the combo.
+    pub combo: String,
+}
+
+/// When a sequence of button presses matched a combo,
+/// see [`ComboBuffer`](./bindings/struct.ComboBuffer.html).
+pub trait ComboEvent: Sized {
+    /// Creates a combo event from arguments.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_combo_args(args: &ComboArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a combo event.
+    fn combo<U, F>(&self, f: F) -> Option<U> where F: FnMut(&ComboArgs) -> U;
+    /// Returns combo arguments.
+    fn combo_args(&self) -> Option<ComboArgs> {
+        self.combo(|args| args.clone())
+    }
+}
+
+impl ComboEvent for Event {
+    fn from_combo_args(args: &ComboArgs, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Combo(args.clone()), timestamp))
+    }
+
+    fn combo<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(&ComboArgs) -> U
+    {
+        match *self {
+            Event::Input(Input::Combo(ref args), _) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_combo() {
+        let args = ComboArgs { combo: "dash".into() };
+        let e: Event = args.clone().into();
+        let x: Option<Event> = ComboEvent::from_combo_args(&args, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .combo(|args| ComboEvent::from_combo_args(args, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -0,0 +1,71 @@
+use {Event, Input};
+
+/// Stores whether the graphics context was lost or restored.
+///
+/// A graphics context might be lost when an Android application is suspended,
+/// or when the graphics driver is reset.
+/// Whatever was lost, graphics resources must be created again before rendering.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum ContextState {
+    /// The graphics context was lost.
+    ///
+    /// Graphics resources, such as textures and buffers, are no longer valid.
+    Lost,
+    /// The rendering surface was lost, e.g. when the window was minimized
+    /// or the swapchain became out of date.
+    SurfaceLost,
+    /// The graphics device was removed or disconnected.
+    DeviceRemoved,
+    /// The graphics context was restored.
+    ///
+    /// Graphics resources must be created again before rendering.
+    Restored,
+}
+
+/// When the graphics context of the window is lost or restored.
+pub trait ContextEvent: Sized {
+    /// Creates a context event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_context_state(state: ContextState, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a context event.
+    fn context<U, F>(&self, f: F) -> Option<U> where F: FnMut(ContextState) -> U;
+    /// Returns context arguments.
+    fn context_args(&self) -> Option<ContextState> {
+        self.context(|state| state)
+    }
+}
+
+impl ContextEvent for Event {
+    fn from_context_state(state: ContextState, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Context(state), timestamp))
+    }
+
+    fn context<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(ContextState) -> U
+    {
+        match *self {
+            Event::Input(Input::Context(state), _) => Some(f(state)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_context() {
+        use super::super::Input;
+
+        let e: Event = Input::Context(ContextState::Lost).into();
+        let x: Option<Event> = ContextEvent::from_context_state(ContextState::Restored, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .context(|state| ContextEvent::from_context_state(state, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -45,6 +45,28 @@ impl ControllerHat {
   }
 }
 
+/// The kind of a controller, used to choose default bindings.
+///
+/// Buttons and axes are assumed to be numbered like the SDL game controller API,
+/// where button `0` is the bottom face button and axes `0` and `1` are the left stick.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum ControllerKind {
+    /// An Xbox controller.
+    Xbox,
+    /// A PlayStation controller.
+    PlayStation,
+    /// A Nintendo controller, where confirm and cancel are swapped.
+    Nintendo,
+    /// An unknown controller, bound like an Xbox controller.
+    Generic,
+}
+
+impl Default for ControllerKind {
+    fn default() -> ControllerKind {
+        ControllerKind::Generic
+    }
+}
+
 /// Components of a controller axis move event. Not guaranteed consistent across
 /// backends.
 #[derive(Copy, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Debug)]
@@ -0,0 +1,160 @@
+//! Connected input devices.
+
+use {Event, Input};
+
+bitflags!(
+    /// Describes what an input device can do.
+    #[derive(Deserialize, Serialize)]
+    pub struct DeviceCapabilities: u8 {
+        /// Has buttons or keys.
+        const BUTTONS = 0b00000001;
+        /// Has axes, such as sticks or triggers.
+        const AXES    = 0b00000010;
+        /// Has hats (d-pads).
+        const HATS    = 0b00000100;
+        /// Moves a pointer, such as a mouse.
+        const POINTER = 0b00001000;
+        /// Reports touches.
+        const TOUCH   = 0b00010000;
+        /// Can rumble.
+        const RUMBLE  = 0b00100000;
+    }
+);
+
+/// The kind of an input device.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum DeviceKind {
+    /// A keyboard.
+    Keyboard,
+    /// A mouse or a touchpad moving a mouse cursor.
+    Mouse,
+    /// A controller, such as a gamepad or a joystick.
+    Controller,
+    /// A touch screen.
+    Touch,
+    /// Another kind of device.
+    Other,
+}
+
+/// Describes a connected input device.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct DeviceInfo {
+    /// The kind of device.
+    pub kind: DeviceKind,
+    /// The id of the device.
+    ///
+    /// Matches the id used by events from the device,
+    /// such as [`ButtonArgs::device`](./struct.ButtonArgs.html#structfield.device),
+    /// the controller id and the touch device.
+    pub id: i64,
+    /// The name of the device reported by the platform.
+    pub name: String,
+    /// What the device can do.
+    pub capabilities: DeviceCapabilities,
+    /// The USB vendor id, if known.
+    #[serde(default)]
+    pub vendor_id: Option<u16>,
+    /// The USB product id, if known.
+    #[serde(default)]
+    pub product_id: Option<u16>,
+    /// The serial number, if known.
+    #[serde(default)]
+    pub serial: Option<String>,
+}
+
+impl DeviceInfo {
+    /// Returns the USB vendor and product id, if both are known.
+    ///
+    /// Used to apply workarounds and profiles for specific hardware.
+    pub fn usb_id(&self) -> Option<(u16, u16)> {
+        Some((self.vendor_id?, self.product_id?))
+    }
+}
+
+/// Whether an input device was connected or disconnected.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum DeviceState {
+    /// The device was connected.
+    Added,
+    /// The device was disconnected.
+    Removed,
+}
+
+/// Device arguments, such as the device and whether it was connected or disconnected.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct DeviceArgs {
+    /// Whether the device was connected or disconnected.
+    pub state: DeviceState,
+    /// The device.
+    pub info: DeviceInfo,
+}
+
+/// When an input device was connected or disconnected.
+pub trait DeviceEvent: Sized {
+    /// Creates a device event from arguments.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_device_args(args: &DeviceArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a device event.
+    fn device<U, F>(&self, f: F) -> Option<U> where F: FnMut(&DeviceArgs) -> U;
+    /// Returns device arguments.
+    fn device_args(&self) -> Option<DeviceArgs> {
+        self.device(|args| args.clone())
+    }
+    /// Returns the device if it was connected.
+    fn device_added_args(&self) -> Option<DeviceInfo> {
+        self.device_args()
+            .and_then(|args| if args.state == DeviceState::Added {Some(args.info)} else {None})
+    }
+    /// Returns the device if it was disconnected.
+    fn device_removed_args(&self) -> Option<DeviceInfo> {
+        self.device_args()
+            .and_then(|args| if args.state == DeviceState::Removed {Some(args.info)} else {None})
+    }
+}
+
+impl DeviceEvent for Event {
+    fn from_device_args(args: &DeviceArgs, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Device(args.clone()), timestamp))
+    }
+
+    fn device<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(&DeviceArgs) -> U
+    {
+        match *self {
+            Event::Input(Input::Device(ref args), _) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_device() {
+        let info = DeviceInfo {
+            kind: DeviceKind::Controller,
+            id: 0,
+            name: "Gamepad".into(),
+            capabilities: DeviceCapabilities::BUTTONS | DeviceCapabilities::AXES,
+            vendor_id: Some(0x045e),
+            product_id: Some(0x028e),
+            serial: None,
+        };
+        assert_eq!(info.usb_id(), Some((0x045e, 0x028e)));
+        let e: Event = DeviceArgs { state: DeviceState::Added, info: info.clone() }.into();
+        assert_eq!(e.device_added_args(), Some(info.clone()));
+        assert_eq!(e.device_removed_args(), None);
+
+        let x: Option<Event> = DeviceEvent::from_device_args(
+            &DeviceArgs { state: DeviceState::Removed, info: info }, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .device(|args| DeviceEvent::from_device_args(args, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -0,0 +1,184 @@
+//! Logical and physical coordinates.
+//!
+//! Logical coordinates are measured in points,
+//! while physical coordinates are measured in pixels.
+//! The scale factor is the number of pixels per point.
+//!
+//! Window events, such as mouse cursor and resize events, use points.
+//! The draw size of the window is measured in pixels.
+
+/// A size measured in points.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Debug, Default)]
+pub struct LogicalSize {
+    /// The width.
+    pub width: f64,
+    /// The height.
+    pub height: f64,
+}
+
+impl LogicalSize {
+    /// Creates a new logical size.
+    pub fn new(width: f64, height: f64) -> LogicalSize {
+        LogicalSize {
+            width: width,
+            height: height,
+        }
+    }
+
+    /// Converts to physical size using scale factor.
+    ///
+    /// The result is rounded to the nearest pixel.
+    pub fn to_physical(&self, scale_factor: f64) -> PhysicalSize {
+        PhysicalSize {
+            width: (self.width * scale_factor).round() as u32,
+            height: (self.height * scale_factor).round() as u32,
+        }
+    }
+}
+
+impl From<[f64; 2]> for LogicalSize {
+    fn from(value: [f64; 2]) -> LogicalSize {
+        LogicalSize::new(value[0], value[1])
+    }
+}
+
+impl From<LogicalSize> for [f64; 2] {
+    fn from(value: LogicalSize) -> [f64; 2] {
+        [value.width, value.height]
+    }
+}
+
+/// A size measured in pixels.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash, Debug,
+         Default)]
+pub struct PhysicalSize {
+    /// The width.
+    pub width: u32,
+    /// The height.
+    pub height: u32,
+}
+
+impl PhysicalSize {
+    /// Creates a new physical size.
+    pub fn new(width: u32, height: u32) -> PhysicalSize {
+        PhysicalSize {
+            width: width,
+            height: height,
+        }
+    }
+
+    /// Converts to logical size using scale factor.
+    pub fn to_logical(&self, scale_factor: f64) -> LogicalSize {
+        LogicalSize {
+            width: self.width as f64 / scale_factor,
+            height: self.height as f64 / scale_factor,
+        }
+    }
+}
+
+impl From<[u32; 2]> for PhysicalSize {
+    fn from(value: [u32; 2]) -> PhysicalSize {
+        PhysicalSize::new(value[0], value[1])
+    }
+}
+
+impl From<PhysicalSize> for [u32; 2] {
+    fn from(value: PhysicalSize) -> [u32; 2] {
+        [value.width, value.height]
+    }
+}
+
+/// A position measured in points.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Debug, Default)]
+pub struct LogicalPosition {
+    /// The x coordinate.
+    pub x: f64,
+    /// The y coordinate.
+    pub y: f64,
+}
+
+impl LogicalPosition {
+    /// Creates a new logical position.
+    pub fn new(x: f64, y: f64) -> LogicalPosition {
+        LogicalPosition {x: x, y: y}
+    }
+
+    /// Converts to physical position using scale factor.
+    pub fn to_physical(&self, scale_factor: f64) -> PhysicalPosition {
+        PhysicalPosition {
+            x: self.x * scale_factor,
+            y: self.y * scale_factor,
+        }
+    }
+}
+
+impl From<[f64; 2]> for LogicalPosition {
+    fn from(value: [f64; 2]) -> LogicalPosition {
+        LogicalPosition::new(value[0], value[1])
+    }
+}
+
+impl From<LogicalPosition> for [f64; 2] {
+    fn from(value: LogicalPosition) -> [f64; 2] {
+        [value.x, value.y]
+    }
+}
+
+/// A position measured in pixels.
+///
+/// Uses floating point to preserve sub-pixel precision of input devices.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Debug, Default)]
+pub struct PhysicalPosition {
+    /// The x coordinate.
+    pub x: f64,
+    /// The y coordinate.
+    pub y: f64,
+}
+
+impl PhysicalPosition {
+    /// Creates a new physical position.
+    pub fn new(x: f64, y: f64) -> PhysicalPosition {
+        PhysicalPosition {x: x, y: y}
+    }
+
+    /// Converts to logical position using scale factor.
+    pub fn to_logical(&self, scale_factor: f64) -> LogicalPosition {
+        LogicalPosition {
+            x: self.x / scale_factor,
+            y: self.y / scale_factor,
+        }
+    }
+}
+
+impl From<[f64; 2]> for PhysicalPosition {
+    fn from(value: [f64; 2]) -> PhysicalPosition {
+        PhysicalPosition::new(value[0], value[1])
+    }
+}
+
+impl From<PhysicalPosition> for [f64; 2] {
+    fn from(value: PhysicalPosition) -> [f64; 2] {
+        [value.x, value.y]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_round_trip() {
+        let logical = LogicalSize::new(100.0, 50.0);
+        let physical = logical.to_physical(2.0);
+        assert_eq!(physical, PhysicalSize::new(200, 100));
+        assert_eq!(physical.to_logical(2.0), logical);
+    }
+
+    #[test]
+    fn test_position_round_trip() {
+        let logical = LogicalPosition::new(10.5, 20.0);
+        let physical = logical.to_physical(2.0);
+        assert_eq!(physical, PhysicalPosition::new(21.0, 40.0));
+        assert_eq!(physical.to_logical(2.0), logical);
+    }
+}
@@ -0,0 +1,77 @@
+//! Dropping text and URLs onto the window.
+
+use {Event, Input};
+
+/// Data dropped onto the window, other than files.
+///
+/// Files are reported as `FileDrag::Drop`.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum DropData {
+    /// A text snippet, e.g. selected text dragged from another application.
+    Text(String),
+    /// A URL, e.g. a link dragged from a browser.
+    Url(String),
+}
+
+/// When text or a URL was dropped onto the window.
+pub trait DropDataEvent: Sized {
+    /// Creates a drop data event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_drop_data(data: &DropData, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a drop data event.
+    fn drop_data<U, F>(&self, f: F) -> Option<U> where F: FnMut(&DropData) -> U;
+    /// Returns drop data arguments.
+    fn drop_data_args(&self) -> Option<DropData> {
+        self.drop_data(|data| data.clone())
+    }
+    /// Returns the dropped text, if text was dropped.
+    fn drop_text_args(&self) -> Option<String> {
+        match self.drop_data_args() {
+            Some(DropData::Text(text)) => Some(text),
+            _ => None,
+        }
+    }
+    /// Returns the dropped URL, if a URL was dropped.
+    fn drop_url_args(&self) -> Option<String> {
+        match self.drop_data_args() {
+            Some(DropData::Url(url)) => Some(url),
+            _ => None,
+        }
+    }
+}
+
+impl DropDataEvent for Event {
+    fn from_drop_data(data: &DropData, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::DropData(data.clone()), timestamp))
+    }
+
+    fn drop_data<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(&DropData) -> U
+    {
+        match *self {
+            Event::Input(Input::DropData(ref data), _) => Some(f(data)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_drop_data() {
+        let e: Event = DropData::Url("https://example.com".into()).into();
+        assert_eq!(e.drop_url_args(), Some("https://example.com".into()));
+        assert_eq!(e.drop_text_args(), None);
+
+        let x: Option<Event> = DropDataEvent::from_drop_data(&DropData::Text("note".into()), &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .drop_data(|data| DropDataEvent::from_drop_data(data, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
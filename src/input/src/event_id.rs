@@ -18,6 +18,24 @@ pub const MOUSE_SCROLL: EventId = EventId("piston/mouse_scroll");
 pub const MOUSE_RELATIVE: EventId = EventId("piston/mouse_relative");
 /// Event id for mouse cursor event.
 pub const MOUSE_CURSOR: EventId = EventId("piston/mouse_cursor");
+/// Event id for mouse device event.
+pub const MOUSE_DEVICE: EventId = EventId("piston/mouse_device");
+/// Event id for MIDI event.
+pub const MIDI: EventId = EventId("piston/midi");
+/// Event id for gaze event.
+pub const GAZE: EventId = EventId("piston/gaze");
+/// Event id for pose event.
+pub const POSE: EventId = EventId("piston/pose");
+/// Event id for 3D mouse event.
+pub const SPACE_MOUSE: EventId = EventId("piston/space_mouse");
+/// Event id for accelerometer event.
+pub const ACCELEROMETER: EventId = EventId("piston/accelerometer");
+/// Event id for gyroscope event.
+pub const GYROSCOPE: EventId = EventId("piston/gyroscope");
+/// Event id for magnetometer event.
+pub const MAGNETOMETER: EventId = EventId("piston/magnetometer");
+/// Event id for pointer event.
+pub const POINTER: EventId = EventId("piston/pointer");
 /// Event id for button event.
 pub const BUTTON: EventId = EventId("piston/button");
 /// Event id for render event.
@@ -32,6 +50,64 @@ pub const TOUCH: EventId = EventId("piston/touch");
 pub const UPDATE: EventId = EventId("piston/update");
 /// Event id for file drag event.
 pub const FILE_DRAG: EventId = EventId("piston/file_drag");
+/// Event id for scale factor changed event.
+pub const SCALE_FACTOR_CHANGED: EventId = EventId("piston/scale_factor_changed");
+/// Event id for moved event.
+pub const MOVED: EventId = EventId("piston/moved");
+/// Event id for window state event.
+pub const WINDOW_STATE: EventId = EventId("piston/window_state");
+/// Event id for close requested event.
+pub const CLOSE_REQUESTED: EventId = EventId("piston/close_requested");
+/// Event id for context event.
+pub const CONTEXT: EventId = EventId("piston/context");
+/// Event id for theme event.
+pub const THEME: EventId = EventId("piston/theme");
+/// Event id for safe area event.
+pub const SAFE_AREA: EventId = EventId("piston/safe_area");
+/// Event id for wake event.
+pub const WAKE: EventId = EventId("piston/wake");
+/// Event id for lag warning event.
+pub const LAG_WARNING: EventId = EventId("piston/lag_warning");
+/// Event id for timer event.
+pub const TIMER: EventId = EventId("piston/timer");
+/// Event id for after update event.
+pub const AFTER_UPDATE: EventId = EventId("piston/after_update");
+/// Event id for channel update event.
+pub const CHANNEL_UPDATE: EventId = EventId("piston/channel_update");
+/// Event id for stall event.
+pub const STALL: EventId = EventId("piston/stall");
+/// Event id for handler panicked event.
+pub const HANDLER_PANICKED: EventId = EventId("piston/handler_panicked");
+/// Event id for action event.
+pub const ACTION: EventId = EventId("piston/action");
+/// Event id for axis event.
+pub const AXIS: EventId = EventId("piston/axis");
+/// Event id for combo event.
+pub const COMBO: EventId = EventId("piston/combo");
+/// Event id for accessibility event.
+pub const ACCESSIBILITY: EventId = EventId("piston/accessibility");
+/// Event id for inactivity event.
+pub const INACTIVITY: EventId = EventId("piston/inactivity");
+/// Event id for device event.
+pub const DEVICE: EventId = EventId("piston/device");
+/// Event id for tablet event.
+pub const TABLET: EventId = EventId("piston/tablet");
+/// Event id for orientation changed event.
+pub const ORIENTATION_CHANGED: EventId = EventId("piston/orientation_changed");
+/// Event id for lifecycle event.
+pub const LIFECYCLE: EventId = EventId("piston/lifecycle");
+/// Event id for low memory event.
+pub const LOW_MEMORY: EventId = EventId("piston/low_memory");
+/// Event id for back event.
+pub const BACK: EventId = EventId("piston/back");
+/// Event id for open URL event.
+pub const OPEN_URL: EventId = EventId("piston/open_url");
+/// Event id for open file event.
+pub const OPEN_FILE: EventId = EventId("piston/open_file");
+/// Event id for drop data event.
+pub const DROP_DATA: EventId = EventId("piston/drop_data");
+/// Event id for swap failed event.
+pub const SWAP_FAILED: EventId = EventId("piston/swap_failed");
 
 /// Used to identify events arguments provided by traits.
 ///
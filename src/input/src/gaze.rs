@@ -0,0 +1,71 @@
+//! Eye tracking.
+
+use {Event, Input, Motion};
+
+/// Gaze arguments, such as where the user is looking.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Debug)]
+pub struct GazeArgs {
+    /// The eye tracker that reported the gaze.
+    pub device: i64,
+    /// The gaze point, normalized 0..1 across the window.
+    ///
+    /// Can be outside the range when the user looks outside the window.
+    pub position: [f64; 2],
+    /// How confident the eye tracker is about the gaze point, normalized 0..1.
+    pub confidence: f64,
+}
+
+/// When the gaze point of an eye tracker changed.
+///
+/// Eye trackers are supported by some window backends,
+/// see `WindowCapabilities::gaze`.
+/// Other eye trackers can send gaze events to the event loop through an `EventLoopProxy`.
+pub trait GazeEvent: Sized {
+    /// Creates a gaze event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_gaze_args(args: GazeArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a gaze event.
+    fn gaze<U, F>(&self, f: F) -> Option<U> where F: FnMut(GazeArgs) -> U;
+    /// Returns gaze arguments.
+    fn gaze_args(&self) -> Option<GazeArgs> {
+        self.gaze(|args| args)
+    }
+}
+
+impl GazeEvent for Event {
+    fn from_gaze_args(args: GazeArgs, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Move(Motion::Gaze(args)), timestamp))
+    }
+
+    fn gaze<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(GazeArgs) -> U
+    {
+        match *self {
+            Event::Input(Input::Move(Motion::Gaze(args)), _) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_gaze() {
+        let e: Event = Motion::Gaze(GazeArgs {
+            device: 0,
+            position: [0.5, 0.5],
+            confidence: 1.0,
+        }).into();
+        let args = GazeArgs { device: 0, position: [0.25, 0.75], confidence: 0.8 };
+        let x: Option<Event> = GazeEvent::from_gaze_args(args, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .gaze(|args| GazeEvent::from_gaze_args(args, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
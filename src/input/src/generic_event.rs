@@ -2,9 +2,16 @@
 
 use std::any::Any;
 
-use {AfterRenderEvent, ButtonEvent, CloseEvent, ControllerAxisEvent, CursorEvent, FocusEvent,
-     IdleEvent, MouseCursorEvent, MouseRelativeEvent, MouseScrollEvent, PressEvent, ReleaseEvent,
-     RenderEvent, ResizeEvent, TextEvent, TimeStamp, TouchEvent, UpdateEvent};
+use {AccelerometerEvent, AccessibilityEvent, ActionEvent, AfterRenderEvent, AfterUpdateEvent,
+     AxisEvent, BackEvent, ButtonEvent, ChannelUpdateEvent, CloseEvent, CloseRequestedEvent,
+     ComboEvent, ContextEvent, ControllerAxisEvent, CursorEvent, DeviceEvent, DropDataEvent,
+     FocusEvent, GazeEvent, GyroscopeEvent, HandlerPanickedEvent, IdleEvent, InactivityEvent,
+     LagWarningEvent, LifecycleEvent, LowMemoryEvent, MagnetometerEvent, MidiEvent,
+     MouseCursorEvent, MouseDeviceEvent, MouseRelativeEvent, MouseScrollEvent, MovedEvent,
+     OpenFileEvent, OpenUrlEvent, OrientationChangedEvent, PointerEvent, PoseEvent, PressEvent,
+     ReleaseEvent, RenderEvent, ResizeEvent, SafeAreaEvent, ScaleFactorChangedEvent,
+     SpaceMouseEvent, StallEvent, SwapFailedEvent, TabletEvent, TextEvent, ThemeEvent, TimeStamp,
+     TimerEvent, TouchEvent, UpdateEvent, WakeEvent, WindowStateEvent};
 use {Event, EventId, Input, Loop, Motion};
 
 /// Implemented by all events.
@@ -20,8 +27,15 @@ use {Event, EventId, Input, Loop, Motion};
 pub trait GenericEvent: Sized +
     AfterRenderEvent + CloseEvent + ControllerAxisEvent + CursorEvent + FocusEvent + IdleEvent +
     MouseCursorEvent + MouseRelativeEvent + MouseScrollEvent + ButtonEvent + PressEvent +
-    ReleaseEvent + RenderEvent + ResizeEvent + TextEvent + TouchEvent + UpdateEvent +
-    From<Input> + From<Loop> + Into<Option<Input>> + Into<Option<Loop>>
+    ReleaseEvent + RenderEvent + ResizeEvent + ScaleFactorChangedEvent + TextEvent + TouchEvent +
+    UpdateEvent + MovedEvent + WindowStateEvent + CloseRequestedEvent + ContextEvent + ThemeEvent +
+    SafeAreaEvent + WakeEvent + LagWarningEvent + TimerEvent + AfterUpdateEvent +
+    ChannelUpdateEvent + StallEvent + HandlerPanickedEvent + ActionEvent + AxisEvent + ComboEvent +
+    AccessibilityEvent + InactivityEvent + MouseDeviceEvent + DeviceEvent + MidiEvent + GazeEvent +
+    PoseEvent + SpaceMouseEvent + TabletEvent + AccelerometerEvent + GyroscopeEvent +
+    MagnetometerEvent + OrientationChangedEvent + LifecycleEvent + LowMemoryEvent + BackEvent +
+    PointerEvent + OpenUrlEvent + OpenFileEvent + DropDataEvent + SwapFailedEvent + From<Input> +
+    From<Loop> + Into<Option<Input>> + Into<Option<Loop>>
 {
     /// The id of this event.
     fn event_id(&self) -> EventId;
@@ -47,14 +61,52 @@ impl GenericEvent for Event {
             Event::Input(Input::Move(Motion::MouseScroll(_)), _) => MOUSE_SCROLL,
             Event::Input(Input::Move(Motion::ControllerAxis(_)), _) => CONTROLLER_AXIS,
             Event::Input(Input::Move(Motion::Touch(_)), _) => TOUCH,
+            Event::Input(Input::Move(Motion::MouseDevice(_)), _) => MOUSE_DEVICE,
+            Event::Input(Input::Move(Motion::Midi(_)), _) => MIDI,
+            Event::Input(Input::Move(Motion::Gaze(_)), _) => GAZE,
+            Event::Input(Input::Move(Motion::Pose(_)), _) => POSE,
+            Event::Input(Input::Move(Motion::SpaceMouse(_)), _) => SPACE_MOUSE,
+            Event::Input(Input::Move(Motion::Accelerometer(_)), _) => ACCELEROMETER,
+            Event::Input(Input::Move(Motion::Gyroscope(_)), _) => GYROSCOPE,
+            Event::Input(Input::Move(Motion::Magnetometer(_)), _) => MAGNETOMETER,
+            Event::Input(Input::Move(Motion::Pointer(_)), _) => POINTER,
             Event::Input(Input::Button(_), _) => BUTTON,
             Event::Input(Input::Resize(_), _) => RESIZE,
             Event::Input(Input::Text(_), _) => TEXT,
             Event::Input(Input::FileDrag(_), _) => FILE_DRAG,
+            Event::Input(Input::ScaleFactorChanged(_), _) => SCALE_FACTOR_CHANGED,
+            Event::Input(Input::Moved(_), _) => MOVED,
+            Event::Input(Input::WindowState(_), _) => WINDOW_STATE,
+            Event::Input(Input::CloseRequested(_), _) => CLOSE_REQUESTED,
+            Event::Input(Input::Context(_), _) => CONTEXT,
+            Event::Input(Input::Theme(_), _) => THEME,
+            Event::Input(Input::SafeArea(_), _) => SAFE_AREA,
+            Event::Input(Input::Wake(_), _) => WAKE,
+            Event::Input(Input::Action(_), _) => ACTION,
+            Event::Input(Input::Axis(_), _) => AXIS,
+            Event::Input(Input::Combo(_), _) => COMBO,
+            Event::Input(Input::Accessibility(_), _) => ACCESSIBILITY,
+            Event::Input(Input::Device(_), _) => DEVICE,
+            Event::Input(Input::Tablet(_), _) => TABLET,
+            Event::Input(Input::OrientationChanged(_), _) => ORIENTATION_CHANGED,
+            Event::Input(Input::Lifecycle(_), _) => LIFECYCLE,
+            Event::Input(Input::LowMemory(_), _) => LOW_MEMORY,
+            Event::Input(Input::Back(_), _) => BACK,
+            Event::Input(Input::OpenUrl(_), _) => OPEN_URL,
+            Event::Input(Input::OpenFile(_), _) => OPEN_FILE,
+            Event::Input(Input::DropData(_), _) => DROP_DATA,
             Event::Loop(Loop::Update(_)) => UPDATE,
             Event::Loop(Loop::Render(_)) => RENDER,
             Event::Loop(Loop::AfterRender(_)) => AFTER_RENDER,
             Event::Loop(Loop::Idle(_)) => IDLE,
+            Event::Loop(Loop::LagWarning(_)) => LAG_WARNING,
+            Event::Loop(Loop::Timer(_)) => TIMER,
+            Event::Loop(Loop::AfterUpdate(_)) => AFTER_UPDATE,
+            Event::Loop(Loop::ChannelUpdate(_)) => CHANNEL_UPDATE,
+            Event::Loop(Loop::Stall(_)) => STALL,
+            Event::Loop(Loop::Inactivity(_)) => INACTIVITY,
+            Event::Loop(Loop::HandlerPanicked(_)) => HANDLER_PANICKED,
+            Event::Loop(Loop::SwapFailed(_)) => SWAP_FAILED,
             Event::Custom(event_id, _, _) => event_id,
         }
     }
@@ -67,18 +119,56 @@ impl GenericEvent for Event {
             Event::Input(Input::Focus(focused), _) => f(&focused as &Any),
             Event::Input(Input::Close(ref args), _) => f(args as &Any),
             Event::Input(Input::Move(Motion::ControllerAxis(args)), _) => f(&args as &Any),
-            Event::Input(Input::Move(Motion::MouseCursor(pos)), _) => f(&pos as &Any),
+            Event::Input(Input::Move(Motion::MouseCursor(ref pos)), _) => f(pos as &Any),
             Event::Input(Input::Move(Motion::MouseRelative(pos)), _) => f(&pos as &Any),
             Event::Input(Input::Move(Motion::MouseScroll(pos)), _) => f(&pos as &Any),
             Event::Input(Input::Move(Motion::Touch(args)), _) => f(&args as &Any),
+            Event::Input(Input::Move(Motion::MouseDevice(args)), _) => f(&args as &Any),
+            Event::Input(Input::Move(Motion::Midi(args)), _) => f(&args as &Any),
+            Event::Input(Input::Move(Motion::Gaze(args)), _) => f(&args as &Any),
+            Event::Input(Input::Move(Motion::Pose(args)), _) => f(&args as &Any),
+            Event::Input(Input::Move(Motion::SpaceMouse(args)), _) => f(&args as &Any),
+            Event::Input(Input::Move(Motion::Accelerometer(args)), _) => f(&args as &Any),
+            Event::Input(Input::Move(Motion::Gyroscope(args)), _) => f(&args as &Any),
+            Event::Input(Input::Move(Motion::Magnetometer(args)), _) => f(&args as &Any),
+            Event::Input(Input::Move(Motion::Pointer(args)), _) => f(&args as &Any),
             Event::Input(Input::Button(ref args), _) => f(args as &Any),
             Event::Input(Input::Resize(ref args), _) => f(args as &Any),
             Event::Input(Input::Text(ref text), _) => f(text as &Any),
             Event::Input(Input::FileDrag(ref file_drag), _) => f(file_drag as &Any),
+            Event::Input(Input::ScaleFactorChanged(scale_factor), _) => f(&scale_factor as &Any),
+            Event::Input(Input::Moved(pos), _) => f(&pos as &Any),
+            Event::Input(Input::WindowState(state), _) => f(&state as &Any),
+            Event::Input(Input::CloseRequested(ref args), _) => f(args as &Any),
+            Event::Input(Input::Context(state), _) => f(&state as &Any),
+            Event::Input(Input::Theme(theme), _) => f(&theme as &Any),
+            Event::Input(Input::SafeArea(insets), _) => f(&insets as &Any),
+            Event::Input(Input::Wake(ref args), _) => f(args as &Any),
+            Event::Input(Input::Action(ref args), _) => f(args as &Any),
+            Event::Input(Input::Axis(ref args), _) => f(args as &Any),
+            Event::Input(Input::Combo(ref args), _) => f(args as &Any),
+            Event::Input(Input::Accessibility(settings), _) => f(&settings as &Any),
+            Event::Input(Input::Device(ref args), _) => f(args as &Any),
+            Event::Input(Input::Tablet(tablet), _) => f(&tablet as &Any),
+            Event::Input(Input::OrientationChanged(orientation), _) => f(&orientation as &Any),
+            Event::Input(Input::Lifecycle(lifecycle), _) => f(&lifecycle as &Any),
+            Event::Input(Input::LowMemory(pressure), _) => f(&pressure as &Any),
+            Event::Input(Input::Back(ref args), _) => f(args as &Any),
+            Event::Input(Input::OpenUrl(ref url), _) => f(url as &Any),
+            Event::Input(Input::OpenFile(ref path), _) => f(path as &Any),
+            Event::Input(Input::DropData(ref data), _) => f(data as &Any),
             Event::Loop(Loop::Update(ref args)) => f(args as &Any),
             Event::Loop(Loop::Render(ref args)) => f(args as &Any),
             Event::Loop(Loop::AfterRender(ref args)) => f(args as &Any),
             Event::Loop(Loop::Idle(ref args)) => f(args as &Any),
+            Event::Loop(Loop::LagWarning(ref args)) => f(args as &Any),
+            Event::Loop(Loop::Timer(ref args)) => f(args as &Any),
+            Event::Loop(Loop::AfterUpdate(ref args)) => f(args as &Any),
+            Event::Loop(Loop::ChannelUpdate(ref args)) => f(args as &Any),
+            Event::Loop(Loop::Stall(ref args)) => f(args as &Any),
+            Event::Loop(Loop::Inactivity(ref args)) => f(args as &Any),
+            Event::Loop(Loop::HandlerPanicked(ref args)) => f(args as &Any),
+            Event::Loop(Loop::SwapFailed(ref args)) => f(args as &Any),
             Event::Custom(_, ref args, _) => f(args),
         }
     }
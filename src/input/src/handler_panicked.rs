@@ -0,0 +1,57 @@
+use {Event, Loop};
+
+/// Handler panicked arguments.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize, Hash)]
+pub struct HandlerPanickedArgs {
+    /// Numbers the caught panics, starting at `0`.
+    /// The event loop keeps the message, see `Events::last_panic_message`.
+    pub id: u64,
+}
+
+/// When an event handler panicked and the event loop caught the panic,
+/// so the application can save its state before closing.
+pub trait HandlerPanickedEvent: Sized {
+    /// Creates a handler panicked event from arguments.
+    fn from_handler_panicked_args(args: &HandlerPanickedArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a handler panicked event.
+    fn handler_panicked<U, F>(&self, f: F) -> Option<U>
+        where F: FnMut(&HandlerPanickedArgs) -> U;
+    /// Returns handler panicked arguments.
+    fn handler_panicked_args(&self) -> Option<HandlerPanickedArgs> {
+        self.handler_panicked(|args| *args)
+    }
+}
+
+impl HandlerPanickedEvent for Event {
+    fn from_handler_panicked_args(args: &HandlerPanickedArgs, _old_event: &Self) -> Option<Self> {
+        Some(Event::Loop(Loop::HandlerPanicked(*args)))
+    }
+
+    fn handler_panicked<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(&HandlerPanickedArgs) -> U
+    {
+        match *self {
+            Event::Loop(Loop::HandlerPanicked(ref args)) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_handler_panicked() {
+        let args = HandlerPanickedArgs { id: 0 };
+        let e: Event = args.into();
+        let x: Option<Event> = HandlerPanickedEvent::from_handler_panicked_args(&args, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .handler_panicked(|args| {
+                HandlerPanickedEvent::from_handler_panicked_args(args, x.as_ref().unwrap())
+            })
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -5,6 +5,13 @@ use {Event, Loop};
 pub struct IdleArgs {
     /// Expected idle time in seconds.
     pub dt: f64,
+    /// Estimated time in seconds the event loop intends to sleep
+    /// before the next scheduled event.
+    ///
+    /// This includes timers and update channels, so it can be shorter than `dt`.
+    /// Background work that fits within this time does not delay any event.
+    #[serde(default)]
+    pub sleep_dt: f64,
 }
 
 /// When background tasks should be performed.
@@ -13,7 +20,7 @@ pub trait IdleEvent: Sized {
     fn from_idle_args(args: &IdleArgs, old_event: &Self) -> Option<Self>;
     /// Creates an update event with delta time.
     fn from_dt(dt: f64, old_event: &Self) -> Option<Self> {
-        IdleEvent::from_idle_args(&IdleArgs { dt: dt }, old_event)
+        IdleEvent::from_idle_args(&IdleArgs { dt: dt, sleep_dt: dt }, old_event)
     }
     /// Calls closure if this is an idle event.
     fn idle<U, F>(&self, f: F) -> Option<U> where F: FnMut(&IdleArgs) -> U;
@@ -46,8 +53,9 @@ mod tests {
     fn test_input_idle() {
         use IdleArgs;
 
-        let e: Event = IdleArgs { dt: 1.0 }.into();
-        let x: Option<Event> = IdleEvent::from_idle_args(&IdleArgs { dt: 1.0 }, &e);
+        let args = IdleArgs { dt: 1.0, sleep_dt: 0.5 };
+        let e: Event = args.into();
+        let x: Option<Event> = IdleEvent::from_idle_args(&args, &e);
         let y: Option<Event> = x.clone()
             .unwrap()
             .idle(|args| IdleEvent::from_idle_args(args, x.as_ref().unwrap()))
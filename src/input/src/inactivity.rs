@@ -0,0 +1,57 @@
+use {Event, Loop};
+
+/// Inactivity arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct InactivityArgs {
+    /// `true` when the user became inactive, `false` when the user is active again.
+    pub inactive: bool,
+    /// The time in seconds since the last user input.
+    pub idle_time: f64,
+}
+
+/// When the user became inactive after a period without user input,
+/// or is active again.
+///
+/// Used for attract modes, pausing automatically and screensaver-like behavior.
+pub trait InactivityEvent: Sized {
+    /// Creates an inactivity event.
+    fn from_inactivity_args(args: &InactivityArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is an inactivity event.
+    fn inactivity<U, F>(&self, f: F) -> Option<U> where F: FnMut(&InactivityArgs) -> U;
+    /// Returns inactivity arguments.
+    fn inactivity_args(&self) -> Option<InactivityArgs> {
+        self.inactivity(|args| *args)
+    }
+}
+
+impl InactivityEvent for Event {
+    fn from_inactivity_args(args: &InactivityArgs, _old_event: &Self) -> Option<Self> {
+        Some(Event::Loop(Loop::Inactivity(*args)))
+    }
+
+    fn inactivity<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(&InactivityArgs) -> U
+    {
+        match *self {
+            Event::Loop(Loop::Inactivity(ref args)) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_inactivity() {
+        let e: Event = InactivityArgs { inactive: true, idle_time: 60.0 }.into();
+        let x: Option<Event> = InactivityEvent::from_inactivity_args(
+            &InactivityArgs { inactive: false, idle_time: 75.0 }, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .inactivity(|args| InactivityEvent::from_inactivity_args(args, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -627,6 +627,26 @@ impl From<Key> for u32 {
     }
 }
 
+/// The layout of a keyboard, used to choose default bindings,
+/// since keys are identified by the symbol they produce.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum KeyboardLayout {
+    /// QWERTY, common in English speaking countries.
+    Qwerty,
+    /// QWERTZ, common in German speaking countries.
+    Qwertz,
+    /// AZERTY, common in French speaking countries.
+    Azerty,
+    /// Dvorak.
+    Dvorak,
+}
+
+impl Default for KeyboardLayout {
+    fn default() -> KeyboardLayout {
+        KeyboardLayout::Qwerty
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
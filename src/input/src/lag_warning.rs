@@ -0,0 +1,75 @@
+use {Event, Loop};
+
+/// Why the event loop fell behind.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize, Hash)]
+pub enum LagKind {
+    /// Delayed updates accumulated beyond the catch up threshold.
+    UpdateDebt,
+    /// The time between two frames exceeded the frame budget.
+    FrameBudget,
+}
+
+/// Lag warning arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct LagWarningArgs {
+    /// Why the event loop fell behind.
+    pub kind: LagKind,
+    /// Time in seconds the event loop was behind,
+    /// the delayed update time or the frame time.
+    pub lag_dt: f64,
+    /// Simulation time in seconds that was dropped to catch up.
+    pub dropped_dt: f64,
+}
+
+/// When the event loop fell behind and dropped updates to catch up,
+/// or a frame took longer than the frame budget.
+///
+/// This happens after a long stall, for example when loading assets,
+/// instead of freezing while running all the missed updates.
+/// Applications can log hitches or reduce quality settings.
+pub trait LagWarningEvent: Sized {
+    /// Creates a lag warning event.
+    fn from_lag_warning_args(args: &LagWarningArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a lag warning event.
+    fn lag_warning<U, F>(&self, f: F) -> Option<U> where F: FnMut(&LagWarningArgs) -> U;
+    /// Returns lag warning arguments.
+    fn lag_warning_args(&self) -> Option<LagWarningArgs> {
+        self.lag_warning(|args| *args)
+    }
+}
+
+impl LagWarningEvent for Event {
+    fn from_lag_warning_args(args: &LagWarningArgs, _old_event: &Self) -> Option<Self> {
+        Some(Event::Loop(Loop::LagWarning(*args)))
+    }
+
+    fn lag_warning<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(&LagWarningArgs) -> U
+    {
+        match *self {
+            Event::Loop(Loop::LagWarning(ref args)) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_lag_warning() {
+        let e: Event = LagWarningArgs {
+            kind: LagKind::UpdateDebt,
+            lag_dt: 0.0,
+            dropped_dt: 0.0,
+        }.into();
+        let x: Option<Event> = LagWarningEvent::from_lag_warning_args(
+            &LagWarningArgs { kind: LagKind::FrameBudget, lag_dt: 1.0, dropped_dt: 0.0 }, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .lag_warning(|args| LagWarningEvent::from_lag_warning_args(args, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
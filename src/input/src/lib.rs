@@ -11,6 +11,10 @@ extern crate bitflags;
 extern crate serde_derive;
 extern crate serde;
 extern crate viewport;
+#[cfg(feature = "json")]
+extern crate serde_json;
+#[cfg(feature = "ron")]
+extern crate ron;
 
 use std::fmt;
 use std::any::Any;
@@ -19,44 +23,123 @@ use std::path::PathBuf;
 use std::cmp::Ordering;
 
 pub use mouse::MouseButton;
-pub use keyboard::Key;
-pub use controller::{ControllerAxisArgs, ControllerButton, ControllerHat};
+pub use keyboard::{Key, KeyboardLayout};
+pub use bindings::Bindings;
+pub use controller::{ControllerAxisArgs, ControllerButton, ControllerHat, ControllerKind};
 
+pub mod bindings;
 pub mod controller;
 pub mod keyboard;
 pub mod mouse;
 
+pub use accessibility::{AccessibilityEvent, AccessibilitySettings};
+pub use action::{ActionArgs, ActionEvent};
 pub use after_render::{AfterRenderArgs, AfterRenderEvent};
-pub use close::{CloseArgs, CloseEvent};
+pub use after_update::{AfterUpdateArgs, AfterUpdateEvent};
+pub use axis::{AxisArgs, AxisEvent};
+pub use back::{BackArgs, BackEvent, BackSource};
+pub use close::{CloseArgs, CloseEvent, CloseRequestedArgs, CloseRequestedEvent};
+pub use combo::{ComboArgs, ComboEvent};
+pub use context::{ContextEvent, ContextState};
 pub use controller::ControllerAxisEvent;
 pub use cursor::CursorEvent;
+pub use device::{DeviceArgs, DeviceCapabilities, DeviceEvent, DeviceInfo, DeviceKind, DeviceState};
+pub use dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize};
+pub use drop_data::{DropData, DropDataEvent};
 pub use focus::FocusEvent;
+pub use gaze::{GazeArgs, GazeEvent};
 pub use generic_event::GenericEvent;
+pub use handler_panicked::{HandlerPanickedArgs, HandlerPanickedEvent};
 pub use idle::{IdleArgs, IdleEvent};
-pub use mouse::{MouseCursorEvent, MouseRelativeEvent, MouseScrollEvent};
+pub use inactivity::{InactivityArgs, InactivityEvent};
+pub use lag_warning::{LagKind, LagWarningArgs, LagWarningEvent};
+pub use lifecycle::{Lifecycle, LifecycleEvent};
+pub use low_memory::{LowMemoryEvent, MemoryPressure};
+pub use midi::{MidiArgs, MidiEvent, MidiMessage};
+pub use mouse::{MouseCursorEvent, MouseDeviceArgs, MouseDeviceEvent, MouseRelativeEvent,
+                MouseScrollEvent};
+pub use moved::MovedEvent;
 pub use button::{ButtonState, ButtonArgs, ButtonEvent, PressEvent, ReleaseEvent};
+pub use channel_update::{ChannelUpdateArgs, ChannelUpdateEvent};
+pub use open_file::OpenFileEvent;
+pub use open_url::OpenUrlEvent;
+pub use orientation::{Orientation, OrientationChangedEvent};
+pub use pointer::{PointerArgs, PointerEvent, PointerKind};
+pub use pose::{PoseArgs, PoseEvent};
 pub use resize::{ResizeArgs, ResizeEvent};
 pub use render::{RenderArgs, RenderEvent};
+pub use safe_area::{SafeAreaEvent, SafeAreaInsets};
+pub use state::InputState;
+pub use scale_factor::ScaleFactorChangedEvent;
+pub use seat::Seats;
+pub use sensor::{AccelerometerEvent, GyroscopeEvent, MagnetometerEvent, SensorArgs};
+pub use space_mouse::{SpaceMouseArgs, SpaceMouseEvent};
+pub use stall::{StallArgs, StallEvent, StallPhase};
+pub use swap_failed::{SwapFailedArgs, SwapFailedEvent};
+pub use tablet::{Tablet, TabletEvent, TabletTool};
 pub use text::TextEvent;
+pub use theme::{Theme, ThemeEvent};
+pub use timer::{TimerArgs, TimerEvent};
 pub use touch::{Touch, TouchArgs, TouchEvent};
 pub use update::{UpdateArgs, UpdateEvent};
+pub use wake::{WakeArgs, WakeEvent};
+pub use web_gamepad::{WebGamepadButton, WebGamepadState, WebGamepads};
+pub use window_state::{WindowState, WindowStateEvent};
 
 use event_id::EventId;
 
 pub mod event_id;
 pub mod generic_event;
 
+mod accessibility;
+mod action;
 mod after_render;
+mod after_update;
+mod axis;
+mod back;
 mod button;
+mod channel_update;
 mod close;
+mod combo;
+mod context;
 mod cursor;
+mod device;
+mod dpi;
+mod drop_data;
 mod focus;
+mod gaze;
+mod handler_panicked;
 mod idle;
+mod inactivity;
+mod lag_warning;
+mod lifecycle;
+mod low_memory;
+mod midi;
+mod moved;
+mod open_file;
+mod open_url;
+mod orientation;
+mod pointer;
+mod pose;
 mod render;
 mod resize;
+mod safe_area;
+mod scale_factor;
+mod seat;
+mod sensor;
+mod space_mouse;
+mod stall;
+mod state;
+mod swap_failed;
+mod tablet;
 mod text;
+mod theme;
+mod timer;
 mod touch;
 mod update;
+mod wake;
+mod web_gamepad;
+mod window_state;
 
 /// The type of time stamp.
 ///
@@ -79,8 +162,8 @@ pub enum Button {
 /// Models different kinds of motion.
 #[derive(Copy, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Debug)]
 pub enum Motion {
-    /// Position in window coordinates.
-    MouseCursor([f64; 2]),
+    /// Position in window coordinates, measured in points.
+    MouseCursor(LogicalPosition),
     /// Position in relative coordinates.
     MouseRelative([f64; 2]),
     /// Position in scroll ticks.
@@ -89,6 +172,24 @@ pub enum Motion {
     ControllerAxis(ControllerAxisArgs),
     /// Touch event.
     Touch(TouchArgs),
+    /// Relative movement of a single mouse.
+    MouseDevice(MouseDeviceArgs),
+    /// A MIDI message.
+    Midi(MidiArgs),
+    /// The gaze point of an eye tracker.
+    Gaze(GazeArgs),
+    /// The pose of a tracked device, such as a virtual reality controller.
+    Pose(PoseArgs),
+    /// Translation and rotation of a 3D mouse.
+    SpaceMouse(SpaceMouseArgs),
+    /// Acceleration measured by an accelerometer.
+    Accelerometer(SensorArgs),
+    /// Rotation rate measured by a gyroscope.
+    Gyroscope(SensorArgs),
+    /// Magnetic field measured by a magnetometer.
+    Magnetometer(SensorArgs),
+    /// A pointer event, such as from web browsers.
+    Pointer(PointerArgs),
 }
 
 /// Stores controller hat state.
@@ -144,6 +245,52 @@ pub enum Input {
     FileDrag(FileDrag),
     /// Window closed.
     Close(CloseArgs),
+    /// The scale factor of the window changed.
+    ///
+    /// The scale factor is the number of pixels per point.
+    ScaleFactorChanged(f64),
+    /// Window moved, the new position in points.
+    Moved([i32; 2]),
+    /// Window got minimized, maximized or restored.
+    WindowState(WindowState),
+    /// User attempted to close the window.
+    ///
+    /// The request can be vetoed, see `CloseRequestedEvent`.
+    CloseRequested(CloseRequestedArgs),
+    /// Graphics context was lost or restored.
+    Context(ContextState),
+    /// The appearance of the operating system changed.
+    Theme(Theme),
+    /// The safe area insets of the window changed.
+    SafeArea(SafeAreaInsets),
+    /// Waiting for events was interrupted by a window waker.
+    Wake(WakeArgs),
+    /// A named action bound to physical input started or stopped.
+    Action(ActionArgs),
+    /// The value of a named axis bound to physical input changed.
+    Axis(AxisArgs),
+    /// A sequence of button presses matched a combo.
+    Combo(ComboArgs),
+    /// The accessibility settings of the operating system changed.
+    Accessibility(AccessibilitySettings),
+    /// An input device was connected or disconnected.
+    Device(DeviceArgs),
+    /// A graphics tablet reported a tool, an express key, a ring or a strip.
+    Tablet(Tablet),
+    /// The orientation of the screen changed.
+    OrientationChanged(Orientation),
+    /// The application was suspended, resumed or is about to be terminated.
+    Lifecycle(Lifecycle),
+    /// The operating system warned that memory is running low.
+    LowMemory(MemoryPressure),
+    /// User navigated back, e.g. with the Android back button.
+    Back(BackArgs),
+    /// The application was asked to open a URL, such as `mygame://join/1234`.
+    OpenUrl(String),
+    /// The operating system asked the application to open a file.
+    OpenFile(PathBuf),
+    /// Text or a URL was dropped onto the window.
+    DropData(DropData),
 }
 
 /// Models loop events.
@@ -155,8 +302,24 @@ pub enum Loop {
     AfterRender(AfterRenderArgs),
     /// Update the state of the application.
     Update(UpdateArgs),
+    /// After updating the state of the application.
+    AfterUpdate(AfterUpdateArgs),
+    /// Update an update channel at its own fixed rate.
+    ChannelUpdate(ChannelUpdateArgs),
     /// Do background tasks that can be done incrementally.
     Idle(IdleArgs),
+    /// The event loop fell behind and dropped updates to catch up.
+    LagWarning(LagWarningArgs),
+    /// A timer set on the event loop fired.
+    Timer(TimerArgs),
+    /// Handling an update or render event stalled the event loop.
+    Stall(StallArgs),
+    /// The user became inactive or is active again.
+    Inactivity(InactivityArgs),
+    /// An event handler panicked and the event loop caught the panic.
+    HandlerPanicked(HandlerPanickedArgs),
+    /// The window could not swap buffers.
+    SwapFailed(SwapFailedArgs),
 }
 
 /// Models all events.
@@ -300,6 +463,102 @@ impl From<AfterRenderArgs> for Event {
     }
 }
 
+impl From<AfterUpdateArgs> for Loop {
+    fn from(args: AfterUpdateArgs) -> Self {
+        Loop::AfterUpdate(args)
+    }
+}
+
+impl From<AfterUpdateArgs> for Event {
+    fn from(args: AfterUpdateArgs) -> Self {
+        Event::Loop(Loop::AfterUpdate(args))
+    }
+}
+
+impl From<ChannelUpdateArgs> for Loop {
+    fn from(args: ChannelUpdateArgs) -> Self {
+        Loop::ChannelUpdate(args)
+    }
+}
+
+impl From<ChannelUpdateArgs> for Event {
+    fn from(args: ChannelUpdateArgs) -> Self {
+        Event::Loop(Loop::ChannelUpdate(args))
+    }
+}
+
+impl From<LagWarningArgs> for Loop {
+    fn from(args: LagWarningArgs) -> Self {
+        Loop::LagWarning(args)
+    }
+}
+
+impl From<LagWarningArgs> for Event {
+    fn from(args: LagWarningArgs) -> Self {
+        Event::Loop(Loop::LagWarning(args))
+    }
+}
+
+impl From<TimerArgs> for Loop {
+    fn from(args: TimerArgs) -> Self {
+        Loop::Timer(args)
+    }
+}
+
+impl From<TimerArgs> for Event {
+    fn from(args: TimerArgs) -> Self {
+        Event::Loop(Loop::Timer(args))
+    }
+}
+
+impl From<StallArgs> for Loop {
+    fn from(args: StallArgs) -> Self {
+        Loop::Stall(args)
+    }
+}
+
+impl From<StallArgs> for Event {
+    fn from(args: StallArgs) -> Self {
+        Event::Loop(Loop::Stall(args))
+    }
+}
+
+impl From<InactivityArgs> for Loop {
+    fn from(args: InactivityArgs) -> Self {
+        Loop::Inactivity(args)
+    }
+}
+
+impl From<InactivityArgs> for Event {
+    fn from(args: InactivityArgs) -> Self {
+        Event::Loop(Loop::Inactivity(args))
+    }
+}
+
+impl From<HandlerPanickedArgs> for Loop {
+    fn from(args: HandlerPanickedArgs) -> Self {
+        Loop::HandlerPanicked(args)
+    }
+}
+
+impl From<HandlerPanickedArgs> for Event {
+    fn from(args: HandlerPanickedArgs) -> Self {
+        Event::Loop(Loop::HandlerPanicked(args))
+    }
+}
+
+impl From<SwapFailedArgs> for Loop {
+    fn from(args: SwapFailedArgs) -> Self {
+        Loop::SwapFailed(args)
+    }
+}
+
+impl From<SwapFailedArgs> for Event {
+    fn from(args: SwapFailedArgs) -> Self {
+        Event::Loop(Loop::SwapFailed(args))
+    }
+}
+
 impl From<UpdateArgs> for Loop {
     fn from(args: UpdateArgs) -> Self {
         Loop::Update(args)
@@ -330,6 +589,60 @@ impl From<CloseArgs> for Input {
     }
 }
 
+impl From<CloseRequestedArgs> for Input {
+    fn from(args: CloseRequestedArgs) -> Self {
+        Input::CloseRequested(args)
+    }
+}
+
+impl From<BackArgs> for Input {
+    fn from(args: BackArgs) -> Self {
+        Input::Back(args)
+    }
+}
+
+impl From<Tablet> for Input {
+    fn from(tablet: Tablet) -> Self {
+        Input::Tablet(tablet)
+    }
+}
+
+impl From<DeviceArgs> for Input {
+    fn from(args: DeviceArgs) -> Self {
+        Input::Device(args)
+    }
+}
+
+impl From<DropData> for Input {
+    fn from(data: DropData) -> Self {
+        Input::DropData(data)
+    }
+}
+
+impl From<ActionArgs> for Input {
+    fn from(args: ActionArgs) -> Self {
+        Input::Action(args)
+    }
+}
+
+impl From<AxisArgs> for Input {
+    fn from(args: AxisArgs) -> Self {
+        Input::Axis(args)
+    }
+}
+
+impl From<ComboArgs> for Input {
+    fn from(args: ComboArgs) -> Self {
+        Input::Combo(args)
+    }
+}
+
+impl From<WakeArgs> for Input {
+    fn from(args: WakeArgs) -> Self {
+        Input::Wake(args)
+    }
+}
+
 impl<T> From<T> for Event
     where Input: From<T>
 {
@@ -0,0 +1,65 @@
+use {Event, Input};
+
+/// Models changes in the lifecycle of the application.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Lifecycle {
+    /// The application is about to be suspended, e.g. moved to the background on mobile.
+    ///
+    /// Save state, pause audio and release GPU resources that the platform discards.
+    /// The application might be terminated while suspended without further events.
+    Suspend,
+    /// The application resumed after being suspended.
+    ///
+    /// Recreate the GPU resources released when suspending.
+    Resume,
+    /// The application is about to be terminated by the operating system.
+    WillTerminate,
+}
+
+/// When the application is suspended, resumed or about to be terminated.
+pub trait LifecycleEvent: Sized {
+    /// Creates a lifecycle event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_lifecycle(lifecycle: Lifecycle, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a lifecycle event.
+    fn lifecycle<U, F>(&self, f: F) -> Option<U> where F: FnMut(Lifecycle) -> U;
+    /// Returns lifecycle arguments.
+    fn lifecycle_args(&self) -> Option<Lifecycle> {
+        self.lifecycle(|lifecycle| lifecycle)
+    }
+}
+
+impl LifecycleEvent for Event {
+    fn from_lifecycle(lifecycle: Lifecycle, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Lifecycle(lifecycle), timestamp))
+    }
+
+    fn lifecycle<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(Lifecycle) -> U
+    {
+        match *self {
+            Event::Input(Input::Lifecycle(lifecycle), _) => Some(f(lifecycle)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_lifecycle() {
+        use super::super::Input;
+
+        let e: Event = Input::Lifecycle(Lifecycle::Suspend).into();
+        let x: Option<Event> = LifecycleEvent::from_lifecycle(Lifecycle::Resume, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .lifecycle(|lifecycle| LifecycleEvent::from_lifecycle(lifecycle, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
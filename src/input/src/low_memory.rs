@@ -0,0 +1,62 @@
+use {Event, Input};
+
+/// Stores how urgently the operating system needs memory.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum MemoryPressure {
+    /// Memory is running low, caches that are cheap to rebuild should be dropped.
+    Moderate,
+    /// Memory is critically low, the application is likely to be terminated
+    /// unless it releases as much memory as possible.
+    Critical,
+}
+
+/// When the operating system warns that memory is running low,
+/// for example on iOS and Android.
+pub trait LowMemoryEvent: Sized {
+    /// Creates a low memory event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_memory_pressure(pressure: MemoryPressure, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a low memory event.
+    fn low_memory<U, F>(&self, f: F) -> Option<U> where F: FnMut(MemoryPressure) -> U;
+    /// Returns low memory arguments.
+    fn low_memory_args(&self) -> Option<MemoryPressure> {
+        self.low_memory(|pressure| pressure)
+    }
+}
+
+impl LowMemoryEvent for Event {
+    fn from_memory_pressure(pressure: MemoryPressure, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::LowMemory(pressure), timestamp))
+    }
+
+    fn low_memory<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(MemoryPressure) -> U
+    {
+        match *self {
+            Event::Input(Input::LowMemory(pressure), _) => Some(f(pressure)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_low_memory() {
+        use super::super::Input;
+
+        let e: Event = Input::LowMemory(MemoryPressure::Moderate).into();
+        let x: Option<Event> = LowMemoryEvent::from_memory_pressure(MemoryPressure::Critical, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .low_memory(|pressure| {
+                LowMemoryEvent::from_memory_pressure(pressure, x.as_ref().unwrap())
+            })
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
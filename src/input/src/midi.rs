@@ -0,0 +1,146 @@
+//! MIDI input.
+
+use {Event, Input, Motion};
+
+/// A MIDI message.
+///
+/// Channels are numbered from `0` to `15`, other values from `0` to `127`.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum MidiMessage {
+    /// A note started playing.
+    NoteOn {
+        /// The channel.
+        channel: u8,
+        /// The note, where `60` is middle C.
+        note: u8,
+        /// How hard the note was played.
+        velocity: u8,
+    },
+    /// A note stopped playing.
+    NoteOff {
+        /// The channel.
+        channel: u8,
+        /// The note, where `60` is middle C.
+        note: u8,
+        /// How fast the note was released.
+        velocity: u8,
+    },
+    /// A controller, such as a knob, slider or pedal, changed.
+    ControlChange {
+        /// The channel.
+        channel: u8,
+        /// The controller.
+        controller: u8,
+        /// The new value.
+        value: u8,
+    },
+}
+
+impl MidiMessage {
+    /// Parses a MIDI message from raw bytes, as received from a MIDI port.
+    ///
+    /// A note on with velocity `0` is a note off.
+    /// Returns `None` for other kinds of messages.
+    pub fn from_bytes(bytes: &[u8]) -> Option<MidiMessage> {
+        if bytes.len() < 3 || bytes[1] > 127 || bytes[2] > 127 {
+            return None;
+        }
+        let (channel, data, value) = (bytes[0] & 0x0f, bytes[1], bytes[2]);
+        match bytes[0] & 0xf0 {
+            0x80 => Some(MidiMessage::NoteOff {channel: channel, note: data, velocity: value}),
+            // A note on with velocity `0` is a note off.
+            0x90 if value == 0 => {
+                Some(MidiMessage::NoteOff {channel: channel, note: data, velocity: 0})
+            }
+            0x90 => Some(MidiMessage::NoteOn {channel: channel, note: data, velocity: value}),
+            0xb0 => Some(MidiMessage::ControlChange {
+                channel: channel,
+                controller: data,
+                value: value,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// MIDI arguments, such as the device and the message.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Debug)]
+pub struct MidiArgs {
+    /// The MIDI device that sent the message.
+    pub device: i64,
+    /// The message.
+    pub message: MidiMessage,
+    /// The time in seconds reported by the MIDI source, if any.
+    ///
+    /// More precise than the time stamp of the input event,
+    /// e.g. for judging the timing of notes in rhythm games.
+    pub time: Option<f64>,
+}
+
+/// When a MIDI message was received.
+///
+/// MIDI messages can be sent to the event loop from the callback of a MIDI library,
+/// see `EventLoopProxy::send_midi`.
+pub trait MidiEvent: Sized {
+    /// Creates a MIDI event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_midi_args(args: MidiArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a MIDI event.
+    fn midi<U, F>(&self, f: F) -> Option<U> where F: FnMut(MidiArgs) -> U;
+    /// Returns MIDI arguments.
+    fn midi_args(&self) -> Option<MidiArgs> {
+        self.midi(|args| args)
+    }
+}
+
+impl MidiEvent for Event {
+    fn from_midi_args(args: MidiArgs, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Move(Motion::Midi(args)), timestamp))
+    }
+
+    fn midi<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(MidiArgs) -> U
+    {
+        match *self {
+            Event::Input(Input::Move(Motion::Midi(args)), _) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_midi() {
+        let args = MidiArgs {
+            device: 0,
+            message: MidiMessage::from_bytes(&[0x91, 60, 100]).unwrap(),
+            time: Some(1.5),
+        };
+        assert_eq!(args.message, MidiMessage::NoteOn {channel: 1, note: 60, velocity: 100});
+        let e: Event = Motion::Midi(args).into();
+        let x: Option<Event> = MidiEvent::from_midi_args(args, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .midi(|args| MidiEvent::from_midi_args(args, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+
+    #[test]
+    fn test_midi_from_bytes() {
+        assert_eq!(MidiMessage::from_bytes(&[0x90, 64, 0]),
+                   Some(MidiMessage::NoteOff {channel: 0, note: 64, velocity: 0}));
+        assert_eq!(MidiMessage::from_bytes(&[0x8f, 64, 10]),
+                   Some(MidiMessage::NoteOff {channel: 15, note: 64, velocity: 10}));
+        assert_eq!(MidiMessage::from_bytes(&[0xb0, 7, 127]),
+                   Some(MidiMessage::ControlChange {channel: 0, controller: 7, value: 127}));
+        // Pitch bend and truncated messages are not supported.
+        assert_eq!(MidiMessage::from_bytes(&[0xe0, 0, 64]), None);
+        assert_eq!(MidiMessage::from_bytes(&[0x90, 64]), None);
+    }
+}
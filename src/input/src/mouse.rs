@@ -1,7 +1,7 @@
 
 //! Back-end agnostic mouse buttons.
 
-use {Event, Input, Motion};
+use {Event, Input, LogicalPosition, Motion};
 
 /// Represent a mouse button.
 #[derive(Copy, Clone, Deserialize, Serialize, PartialEq,
@@ -86,19 +86,25 @@ pub trait MouseCursorEvent: Sized {
     fn mouse_cursor_args(&self) -> Option<[f64; 2]> {
         self.mouse_cursor(|pos| pos)
     }
+    /// Returns mouse cursor position in points.
+    ///
+    /// Use `to_physical` with the scale factor of the window to get pixels.
+    fn mouse_cursor_logical(&self) -> Option<LogicalPosition> {
+        self.mouse_cursor(|pos| pos.into())
+    }
 }
 
 impl MouseCursorEvent for Event {
     fn from_pos(pos: [f64; 2], old_event: &Self) -> Option<Self> {
         let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
-        Some(Event::Input(Input::Move(Motion::MouseCursor(pos)), timestamp))
+        Some(Event::Input(Input::Move(Motion::MouseCursor(pos.into())), timestamp))
     }
 
     fn mouse_cursor<U, F>(&self, mut f: F) -> Option<U>
         where F: FnMut([f64; 2]) -> U
     {
         match *self {
-            Event::Input(Input::Move(Motion::MouseCursor(pos)), _) => Some(f(pos)),
+            Event::Input(Input::Move(Motion::MouseCursor(pos)), _) => Some(f(pos.into())),
             _ => None,
         }
     }
@@ -136,6 +142,49 @@ impl MouseRelativeEvent for Event {
     }
 }
 
+/// Stores the relative movement of a single mouse.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Debug)]
+pub struct MouseDeviceArgs {
+    /// The mouse that moved, the same as `ButtonArgs::device` for its buttons.
+    pub device: i64,
+    /// The relative movement.
+    pub relative: [f64; 2],
+}
+
+/// The relative movement of a single mouse,
+/// for window backends that distinguish multiple mice.
+///
+/// These events are emitted in addition to mouse relative events,
+/// which combine the movement of all mice.
+pub trait MouseDeviceEvent: Sized {
+    /// Creates a mouse device event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_mouse_device_args(args: MouseDeviceArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a mouse device event.
+    fn mouse_device<U, F>(&self, f: F) -> Option<U> where F: FnMut(MouseDeviceArgs) -> U;
+    /// Returns mouse device arguments.
+    fn mouse_device_args(&self) -> Option<MouseDeviceArgs> {
+        self.mouse_device(|args| args)
+    }
+}
+
+impl MouseDeviceEvent for Event {
+    fn from_mouse_device_args(args: MouseDeviceArgs, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Move(Motion::MouseDevice(args)), timestamp))
+    }
+
+    fn mouse_device<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(MouseDeviceArgs) -> U
+    {
+        match *self {
+            Event::Input(Input::Move(Motion::MouseDevice(args)), _) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
 /// The scroll of the mouse wheel.
 pub trait MouseScrollEvent: Sized {
     /// Creates a mouse scroll event.
@@ -174,7 +223,7 @@ mod mouse_event_tests {
     fn test_input_mouse_cursor() {
         use super::super::Motion;
 
-        let e: Event = Motion::MouseCursor([0.0, 0.0]).into();
+        let e: Event = Motion::MouseCursor([0.0, 0.0].into()).into();
         let a: Option<Event> = MouseCursorEvent::from_pos([1.0, 0.0], &e);
         let b: Option<Event> = a.clone()
             .unwrap()
@@ -196,6 +245,23 @@ mod mouse_event_tests {
         assert_eq!(a, b);
     }
 
+    #[test]
+    fn test_input_mouse_device() {
+        use super::super::Motion;
+
+        let args = MouseDeviceArgs { device: 1, relative: [0.0, 0.0] };
+        let e: Event = Motion::MouseDevice(args).into();
+        let a: Option<Event> = MouseDeviceEvent::from_mouse_device_args(
+            MouseDeviceArgs { device: 2, relative: [1.0, 0.0] }, &e);
+        let b: Option<Event> = a.clone()
+            .unwrap()
+            .mouse_device(|args| {
+                MouseDeviceEvent::from_mouse_device_args(args, a.as_ref().unwrap())
+            })
+            .unwrap();
+        assert_eq!(a, b);
+    }
+
     #[test]
     fn test_input_mouse_scroll() {
         use super::super::Motion;
@@ -0,0 +1,52 @@
+use {Event, Input};
+
+/// When the window is moved.
+///
+/// The position is the upper left corner of the window in points,
+/// relative to the upper left corner of the virtual desktop.
+pub trait MovedEvent: Sized {
+    /// Creates a moved event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_position(pos: [i32; 2], old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a moved event.
+    fn moved<U, F>(&self, f: F) -> Option<U> where F: FnMut([i32; 2]) -> U;
+    /// Returns moved arguments.
+    fn moved_args(&self) -> Option<[i32; 2]> {
+        self.moved(|pos| pos)
+    }
+}
+
+impl MovedEvent for Event {
+    fn from_position(pos: [i32; 2], old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Moved(pos), timestamp))
+    }
+
+    fn moved<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut([i32; 2]) -> U
+    {
+        match *self {
+            Event::Input(Input::Moved(pos), _) => Some(f(pos)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_moved() {
+        use super::super::Input;
+
+        let e: Event = Input::Moved([0, 0]).into();
+        let x: Option<Event> = MovedEvent::from_position([10, 20], &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .moved(|pos| MovedEvent::from_position(pos, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -0,0 +1,56 @@
+//! Opening files with the application.
+
+use std::path::{Path, PathBuf};
+
+use {Event, Input};
+
+/// When the operating system asks the application to open a file,
+/// e.g. after double-clicking a file associated with the application.
+///
+/// Lets tools load documents handed to a running instance.
+/// A file that launched the application is delivered as the first events,
+/// see `Events::open_startup_files`.
+pub trait OpenFileEvent: Sized {
+    /// Creates an open file event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_open_file(path: &Path, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is an open file event.
+    fn open_file<U, F>(&self, f: F) -> Option<U> where F: FnMut(&Path) -> U;
+    /// Returns open file arguments.
+    fn open_file_args(&self) -> Option<PathBuf> {
+        self.open_file(|path| path.to_path_buf())
+    }
+}
+
+impl OpenFileEvent for Event {
+    fn from_open_file(path: &Path, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::OpenFile(path.to_path_buf()), timestamp))
+    }
+
+    fn open_file<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(&Path) -> U
+    {
+        match *self {
+            Event::Input(Input::OpenFile(ref path), _) => Some(f(path)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_open_file() {
+        let e: Event = Input::OpenFile(PathBuf::new()).into();
+        let x: Option<Event> = OpenFileEvent::from_open_file(Path::new("level.map"), &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .open_file(|path| OpenFileEvent::from_open_file(path, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
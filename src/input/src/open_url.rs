@@ -0,0 +1,56 @@
+//! Opening URLs with the application.
+
+use std::borrow::ToOwned;
+
+use {Event, Input};
+
+/// When the application is asked to open a URL,
+/// such as a custom protocol link like `mygame://join/1234`.
+///
+/// Used for invite links and editor integrations.
+/// A URL that launched the application is delivered as the first events,
+/// see `Events::open_startup_urls`.
+pub trait OpenUrlEvent: Sized {
+    /// Creates an open URL event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_open_url(url: &str, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is an open URL event.
+    fn open_url<U, F>(&self, f: F) -> Option<U> where F: FnMut(&str) -> U;
+    /// Returns open URL arguments.
+    fn open_url_args(&self) -> Option<String> {
+        self.open_url(|url| url.to_owned())
+    }
+}
+
+impl OpenUrlEvent for Event {
+    fn from_open_url(url: &str, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::OpenUrl(url.into()), timestamp))
+    }
+
+    fn open_url<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(&str) -> U
+    {
+        match *self {
+            Event::Input(Input::OpenUrl(ref url), _) => Some(f(url)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_open_url() {
+        let e: Event = Input::OpenUrl("".into()).into();
+        let x: Option<Event> = OpenUrlEvent::from_open_url("mygame://join/1234", &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .open_url(|url| OpenUrlEvent::from_open_url(url, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -0,0 +1,89 @@
+use {Event, Input};
+
+/// Stores the orientation of the screen of a mobile device.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Orientation {
+    /// Upright, taller than wide.
+    Portrait,
+    /// Upside down, taller than wide.
+    PortraitUpsideDown,
+    /// Rotated counter-clockwise from portrait, wider than tall.
+    LandscapeLeft,
+    /// Rotated clockwise from portrait, wider than tall.
+    LandscapeRight,
+}
+
+impl Orientation {
+    /// All orientations.
+    pub const ALL: [Orientation; 4] = [
+        Orientation::Portrait,
+        Orientation::PortraitUpsideDown,
+        Orientation::LandscapeLeft,
+        Orientation::LandscapeRight,
+    ];
+
+    /// Returns `true` if the screen is taller than wide.
+    pub fn is_portrait(&self) -> bool {
+        match *self {
+            Orientation::Portrait | Orientation::PortraitUpsideDown => true,
+            Orientation::LandscapeLeft | Orientation::LandscapeRight => false,
+        }
+    }
+
+    /// Returns `true` if the screen is wider than tall.
+    pub fn is_landscape(&self) -> bool {
+        !self.is_portrait()
+    }
+}
+
+/// When the orientation of the screen changes, for example when a mobile device is rotated.
+pub trait OrientationChangedEvent: Sized {
+    /// Creates an orientation changed event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_orientation(orientation: Orientation, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is an orientation changed event.
+    fn orientation_changed<U, F>(&self, f: F) -> Option<U> where F: FnMut(Orientation) -> U;
+    /// Returns orientation changed arguments.
+    fn orientation_changed_args(&self) -> Option<Orientation> {
+        self.orientation_changed(|orientation| orientation)
+    }
+}
+
+impl OrientationChangedEvent for Event {
+    fn from_orientation(orientation: Orientation, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::OrientationChanged(orientation), timestamp))
+    }
+
+    fn orientation_changed<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(Orientation) -> U
+    {
+        match *self {
+            Event::Input(Input::OrientationChanged(orientation), _) => Some(f(orientation)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_orientation_changed() {
+        use super::super::Input;
+
+        let e: Event = Input::OrientationChanged(Orientation::Portrait).into();
+        let x: Option<Event> =
+            OrientationChangedEvent::from_orientation(Orientation::LandscapeLeft, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .orientation_changed(|orientation| {
+                OrientationChangedEvent::from_orientation(orientation, x.as_ref().unwrap())
+            })
+            .unwrap();
+        assert_eq!(x, y);
+        assert!(Orientation::LandscapeLeft.is_landscape());
+    }
+}
@@ -0,0 +1,91 @@
+//! Pointers as unified by web browsers, such as mice, pens and touches.
+
+use {Event, Input, Motion, Touch};
+
+/// The kind of device moving a pointer.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum PointerKind {
+    /// A mouse.
+    Mouse,
+    /// A pen or stylus.
+    Pen,
+    /// A finger on a touch screen.
+    Touch,
+}
+
+/// Pointer arguments, such as the pointer id, kind and pressure.
+///
+/// Maps to the `PointerEvent` of web browsers,
+/// so a web backend can emit pointer events in addition to mouse and touch events.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Debug)]
+pub struct PointerArgs {
+    /// A unique identifier for the pointer while it is active.
+    pub id: i64,
+    /// The kind of device moving the pointer.
+    pub kind: PointerKind,
+    /// Whether this is the primary pointer of its kind, e.g. the first finger.
+    pub primary: bool,
+    /// The position in window coordinates.
+    pub position: [f64; 2],
+    /// The pressure, normalized 0..1.
+    ///
+    /// Pointers without pressure report `0.5` while pressed and `0.0` otherwise.
+    pub pressure: f64,
+    /// The pointer state, where `Start` and `End` are pressing and releasing the pointer.
+    pub touch: Touch,
+}
+
+/// When a pointer was pressed, moved, released or cancelled.
+pub trait PointerEvent: Sized {
+    /// Creates a pointer event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_pointer_args(args: PointerArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a pointer event.
+    fn pointer<U, F>(&self, f: F) -> Option<U> where F: FnMut(PointerArgs) -> U;
+    /// Returns pointer arguments.
+    fn pointer_args(&self) -> Option<PointerArgs> {
+        self.pointer(|args| args)
+    }
+}
+
+impl PointerEvent for Event {
+    fn from_pointer_args(args: PointerArgs, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Move(Motion::Pointer(args)), timestamp))
+    }
+
+    fn pointer<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(PointerArgs) -> U
+    {
+        match *self {
+            Event::Input(Input::Move(Motion::Pointer(args)), _) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_pointer() {
+        let args = PointerArgs {
+            id: 1,
+            kind: PointerKind::Pen,
+            primary: true,
+            position: [10.0, 20.0],
+            pressure: 0.75,
+            touch: Touch::Move,
+        };
+        let e: Event = Motion::Pointer(args).into();
+        let x: Option<Event> =
+            PointerEvent::from_pointer_args(PointerArgs { touch: Touch::End, ..args }, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .pointer(|args| PointerEvent::from_pointer_args(args, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
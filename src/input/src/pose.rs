@@ -0,0 +1,79 @@
+//! Tracked poses for virtual reality.
+
+use {Event, Input, Motion};
+
+/// Pose arguments, such as the position and orientation of a tracked device.
+///
+/// Uses the coordinate system of the tracking runtime, usually in meters,
+/// where `y` points up and `-z` points forward, like OpenXR.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Debug)]
+pub struct PoseArgs {
+    /// The tracked device, such as a controller or a head mounted display.
+    pub device: i64,
+    /// The position.
+    pub position: [f64; 3],
+    /// The orientation as a unit quaternion `[x, y, z, w]`.
+    pub orientation: [f64; 4],
+    /// The time in seconds reported by the tracking runtime, if any.
+    ///
+    /// More precise than the time stamp of the input event,
+    /// e.g. for the predicted display time of the pose.
+    pub time: Option<f64>,
+}
+
+/// When the pose of a tracked device changed, with 6 degrees of freedom.
+pub trait PoseEvent: Sized {
+    /// Creates a pose event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_pose_args(args: PoseArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a pose event.
+    fn pose<U, F>(&self, f: F) -> Option<U> where F: FnMut(PoseArgs) -> U;
+    /// Returns pose arguments.
+    fn pose_args(&self) -> Option<PoseArgs> {
+        self.pose(|args| args)
+    }
+}
+
+impl PoseEvent for Event {
+    fn from_pose_args(args: PoseArgs, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Move(Motion::Pose(args)), timestamp))
+    }
+
+    fn pose<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(PoseArgs) -> U
+    {
+        match *self {
+            Event::Input(Input::Move(Motion::Pose(args)), _) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_pose() {
+        let e: Event = Motion::Pose(PoseArgs {
+            device: 0,
+            position: [0.0, 1.6, 0.0],
+            orientation: [0.0, 0.0, 0.0, 1.0],
+            time: None,
+        }).into();
+        let args = PoseArgs {
+            device: 1,
+            position: [0.2, 1.2, -0.3],
+            orientation: [0.0, 0.7071, 0.0, 0.7071],
+            time: Some(2.5),
+        };
+        let x: Option<Event> = PoseEvent::from_pose_args(args, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .pose(|args| PoseEvent::from_pose_args(args, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -7,6 +7,22 @@ use {Event, Loop};
 pub struct RenderArgs {
     /// Extrapolated time in seconds, used to do smooth animation.
     pub ext_dt: f64,
+    /// Interpolation factor between the last two updates, in the range `0.0..=1.0`.
+    ///
+    /// This is the time since the last update divided by the update delta time.
+    /// Games with a fixed update rate can render entities at
+    /// `previous + (current - previous) * ext_alpha` to avoid visual stutter.
+    /// It is `0.0` when update events are disabled.
+    #[serde(default)]
+    pub ext_alpha: f64,
+    /// The index of the frame, starting at `0` and increasing by one every frame.
+    #[serde(default)]
+    pub frame: u64,
+    /// Time in seconds since the event loop started, at the start of the frame.
+    ///
+    /// This is monotonic, so effects and video capture can use it instead of a clock.
+    #[serde(default)]
+    pub time: f64,
     /// The width and height of rendered area in points.
     pub window_size: [f64; 2],
     /// The width and height of rendered area in pixels.
@@ -61,11 +77,17 @@ mod tests {
 
         let e: Event = RenderArgs {
             ext_dt: 0.0,
+            ext_alpha: 0.0,
+            frame: 0,
+            time: 0.0,
             window_size: [0.0, 0.0],
             draw_size: [0, 0],
         }.into();
         let x: Option<Event> = RenderEvent::from_render_args(&RenderArgs {
                                                                  ext_dt: 1.0,
+                                                                 ext_alpha: 0.5,
+                                                                 frame: 1,
+                                                                 time: 0.5,
                                                                  window_size: [10.0, 10.0],
                                                                  draw_size: [10, 10],
                                                              },
@@ -1,23 +1,34 @@
 use viewport::Viewport;
 
-use {Event, Input};
+use {Event, Input, LogicalSize, PhysicalSize};
 
 /// Resize arguments.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct ResizeArgs {
     /// The width and height of rendered area in points.
-    pub window_size: [f64; 2],
-    /// The width of rendered area in pixels.
-    pub draw_size: [u32; 2],
+    pub window_size: LogicalSize,
+    /// The width and height of rendered area in pixels.
+    pub draw_size: PhysicalSize,
 }
 
 impl ResizeArgs {
     /// Returns viewport information filling entire render area.
     pub fn viewport(&self) -> Viewport {
         Viewport {
-            rect: [0, 0, self.draw_size[0] as i32, self.draw_size[1] as i32],
-            window_size: self.window_size,
-            draw_size: self.draw_size,
+            rect: [0, 0, self.draw_size.width as i32, self.draw_size.height as i32],
+            window_size: self.window_size.into(),
+            draw_size: self.draw_size.into(),
+        }
+    }
+
+    /// Returns the number of pixels per point.
+    ///
+    /// Returns `1.0` if the window width is zero.
+    pub fn scale_factor(&self) -> f64 {
+        if self.window_size.width == 0.0 {
+            1.0
+        } else {
+            self.draw_size.width as f64 / self.window_size.width
         }
     }
 }
@@ -61,8 +72,9 @@ mod tests {
         use super::super::Input;
 
         let args = ResizeArgs {
-            window_size: [100.0, 100.0], draw_size: [100, 100],
+            window_size: [100.0, 100.0].into(), draw_size: [200, 200].into(),
         };
+        assert_eq!(args.scale_factor(), 2.0);
         let e: Event = Input::Resize(args).into();
         let x: Option<Event> = ResizeEvent::from_resize_args(&args, &e);
         let y: Option<Event> = x.clone()
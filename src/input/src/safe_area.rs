@@ -0,0 +1,88 @@
+use {Event, Input};
+
+/// Stores the safe area insets of the window, in points.
+///
+/// The safe area is the part of the window that is not covered by
+/// display cutouts, rounded corners or system UI such as a home indicator.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Debug, Default)]
+pub struct SafeAreaInsets {
+    /// The inset from the top edge.
+    pub top: f64,
+    /// The inset from the bottom edge.
+    pub bottom: f64,
+    /// The inset from the left edge.
+    pub left: f64,
+    /// The inset from the right edge.
+    pub right: f64,
+}
+
+impl SafeAreaInsets {
+    /// Returns the safe rectangle `[x, y, w, h]` within a window of the given size.
+    ///
+    /// The width and height are never negative.
+    pub fn safe_rect(&self, window_size: [f64; 2]) -> [f64; 4] {
+        [
+            self.left,
+            self.top,
+            (window_size[0] - self.left - self.right).max(0.0),
+            (window_size[1] - self.top - self.bottom).max(0.0),
+        ]
+    }
+}
+
+/// When the safe area insets of the window change,
+/// for example when a mobile device is rotated.
+pub trait SafeAreaEvent: Sized {
+    /// Creates a safe area event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_safe_area_insets(insets: SafeAreaInsets, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a safe area event.
+    fn safe_area<U, F>(&self, f: F) -> Option<U> where F: FnMut(SafeAreaInsets) -> U;
+    /// Returns safe area arguments.
+    fn safe_area_args(&self) -> Option<SafeAreaInsets> {
+        self.safe_area(|insets| insets)
+    }
+}
+
+impl SafeAreaEvent for Event {
+    fn from_safe_area_insets(insets: SafeAreaInsets, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::SafeArea(insets), timestamp))
+    }
+
+    fn safe_area<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(SafeAreaInsets) -> U
+    {
+        match *self {
+            Event::Input(Input::SafeArea(insets), _) => Some(f(insets)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_safe_area() {
+        use super::super::Input;
+
+        let e: Event = Input::SafeArea(SafeAreaInsets::default()).into();
+        let insets = SafeAreaInsets {top: 44.0, bottom: 34.0, left: 0.0, right: 0.0};
+        let x: Option<Event> = SafeAreaEvent::from_safe_area_insets(insets, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .safe_area(|insets| SafeAreaEvent::from_safe_area_insets(insets, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+
+    #[test]
+    fn test_safe_rect() {
+        let insets = SafeAreaInsets {top: 44.0, bottom: 34.0, left: 10.0, right: 10.0};
+        assert_eq!(insets.safe_rect([400.0, 800.0]), [10.0, 44.0, 380.0, 722.0]);
+        assert_eq!(insets.safe_rect([10.0, 10.0]), [10.0, 44.0, 0.0, 0.0]);
+    }
+}
@@ -0,0 +1,54 @@
+use {Event, Input};
+
+/// When the scale factor of the window changes,
+/// for example when moved to a monitor with different DPI.
+///
+/// The scale factor is the number of pixels per point.
+pub trait ScaleFactorChangedEvent: Sized {
+    /// Creates a scale factor changed event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_scale_factor(scale_factor: f64, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a scale factor changed event.
+    fn scale_factor_changed<U, F>(&self, f: F) -> Option<U> where F: FnMut(f64) -> U;
+    /// Returns scale factor changed arguments.
+    fn scale_factor_changed_args(&self) -> Option<f64> {
+        self.scale_factor_changed(|val| val)
+    }
+}
+
+impl ScaleFactorChangedEvent for Event {
+    fn from_scale_factor(scale_factor: f64, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::ScaleFactorChanged(scale_factor), timestamp))
+    }
+
+    fn scale_factor_changed<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(f64) -> U
+    {
+        match *self {
+            Event::Input(Input::ScaleFactorChanged(val), _) => Some(f(val)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_scale_factor_changed() {
+        use super::super::Input;
+
+        let e: Event = Input::ScaleFactorChanged(1.0).into();
+        let x: Option<Event> = ScaleFactorChangedEvent::from_scale_factor(2.0, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .scale_factor_changed(|val| {
+                ScaleFactorChangedEvent::from_scale_factor(val, x.as_ref().unwrap())
+            })
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -0,0 +1,132 @@
+//! Assigning keyboards and mice to players.
+
+use std::collections::HashMap;
+
+use {ButtonArgs, ButtonState, Event, Input, Motion, MouseDeviceArgs};
+
+/// Assigns keyboards and mice to seats, so several players can share one computer.
+///
+/// A seat is usually a player, with a keyboard and a mouse each.
+/// Requires a window backend that distinguishes devices,
+/// see [`ButtonArgs::device`](./struct.ButtonArgs.html#structfield.device).
+///
+/// ```ignore
+/// let mut seats = Seats::new();
+/// while let Some(e) = events.next(&mut window) {
+///     // The second player joins by pressing a key or mouse button.
+///     seats.join(&e, 1);
+///     match seats.seat_of(&e) {
+///         Some(seat) => players[seat].event(&e),
+///         None => players[0].event(&e),
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Seats {
+    devices: HashMap<i64, usize>,
+}
+
+impl Seats {
+    /// Creates seats without devices.
+    pub fn new() -> Seats {
+        Seats::default()
+    }
+
+    /// Returns the keyboard or mouse that sent an event.
+    pub fn device_of(e: &Event) -> Option<i64> {
+        match *e {
+            Event::Input(Input::Button(ButtonArgs { device, .. }), _) => device,
+            Event::Input(Input::Move(Motion::MouseDevice(MouseDeviceArgs { device, .. })), _) => {
+                Some(device)
+            }
+            _ => None,
+        }
+    }
+
+    /// Assigns a device to a seat, replacing its previous seat.
+    pub fn assign(&mut self, device: i64, seat: usize) {
+        self.devices.insert(device, seat);
+    }
+
+    /// Removes a device from its seat, returning the seat.
+    pub fn unassign(&mut self, device: i64) -> Option<usize> {
+        self.devices.remove(&device)
+    }
+
+    /// Removes all devices from their seats.
+    pub fn clear(&mut self) {
+        self.devices.clear();
+    }
+
+    /// Returns the seat of a device.
+    pub fn seat(&self, device: i64) -> Option<usize> {
+        self.devices.get(&device).cloned()
+    }
+
+    /// Returns the devices of a seat, sorted by id.
+    pub fn devices(&self, seat: usize) -> Vec<i64> {
+        let mut devices: Vec<i64> = self.devices.iter()
+            .filter(|&(_, &s)| s == seat)
+            .map(|(&device, _)| device)
+            .collect();
+        devices.sort();
+        devices
+    }
+
+    /// Returns the seat of the device that sent an event.
+    pub fn seat_of(&self, e: &Event) -> Option<usize> {
+        self.seat(Seats::device_of(e)?)
+    }
+
+    /// Assigns the device to a seat when pressing a button on a device without a seat,
+    /// returning `true` if the device was assigned.
+    pub fn join(&mut self, e: &Event, seat: usize) -> bool {
+        if let Event::Input(Input::Button(ButtonArgs {
+            state: ButtonState::Press,
+            device: Some(device),
+            ..
+        }), _) = *e {
+            if !self.devices.contains_key(&device) {
+                self.assign(device, seat);
+                return true;
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {Key, MouseButton};
+
+    #[test]
+    fn test_seats() {
+        let press = |button, device| -> Event {
+            ButtonArgs {
+                state: ButtonState::Press,
+                button: button,
+                scancode: None,
+                device: device,
+            }.into()
+        };
+
+        let mut seats = Seats::new();
+        assert!(!seats.join(&press(Key::A.into(), None), 0));
+        assert!(seats.join(&press(Key::A.into(), Some(1)), 0));
+        assert!(seats.join(&press(MouseButton::Left.into(), Some(2)), 0));
+        assert!(seats.join(&press(Key::Space.into(), Some(3)), 1));
+        // Devices with a seat do not join again.
+        assert!(!seats.join(&press(Key::Space.into(), Some(1)), 1));
+        assert_eq!(seats.devices(0), vec![1, 2]);
+
+        let motion: Event = Motion::MouseDevice(MouseDeviceArgs {
+            device: 2,
+            relative: [1.0, 0.0],
+        }).into();
+        assert_eq!(seats.seat_of(&motion), Some(0));
+        assert_eq!(seats.seat_of(&press(Key::Space.into(), Some(3))), Some(1));
+        assert_eq!(seats.unassign(3), Some(1));
+        assert_eq!(seats.seat_of(&press(Key::Space.into(), Some(3))), None);
+    }
+}
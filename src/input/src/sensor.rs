@@ -0,0 +1,166 @@
+//! Motion sensors of mobile devices and controllers.
+
+use {Event, Input, Motion};
+
+/// Sensor arguments, such as the device and the measured values.
+///
+/// - Accelerometers measure in m/s², including gravity
+/// - Gyroscopes measure in radians per second
+/// - Magnetometers measure in microtesla
+///
+/// Uses the coordinate system of the device, where `x` points right,
+/// `y` points up and `z` points towards the user.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Debug)]
+pub struct SensorArgs {
+    /// The device with the sensor, such as a controller.
+    pub device: i64,
+    /// The measured values along the `x`, `y` and `z` axes.
+    pub values: [f64; 3],
+    /// The time in seconds reported by the sensor, if any.
+    ///
+    /// More precise than the time stamp of the input event.
+    pub time: Option<f64>,
+    /// The number of samples per second the sensor reports, if known.
+    pub sample_rate: Option<f64>,
+}
+
+/// When the acceleration of the device changed, including gravity.
+pub trait AccelerometerEvent: Sized {
+    /// Creates an accelerometer event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_accelerometer_args(args: SensorArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is an accelerometer event.
+    fn accelerometer<U, F>(&self, f: F) -> Option<U> where F: FnMut(SensorArgs) -> U;
+    /// Returns accelerometer arguments.
+    fn accelerometer_args(&self) -> Option<SensorArgs> {
+        self.accelerometer(|args| args)
+    }
+}
+
+impl AccelerometerEvent for Event {
+    fn from_accelerometer_args(args: SensorArgs, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Move(Motion::Accelerometer(args)), timestamp))
+    }
+
+    fn accelerometer<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(SensorArgs) -> U
+    {
+        match *self {
+            Event::Input(Input::Move(Motion::Accelerometer(args)), _) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+/// When the rotation rate of the device changed.
+pub trait GyroscopeEvent: Sized {
+    /// Creates a gyroscope event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_gyroscope_args(args: SensorArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a gyroscope event.
+    fn gyroscope<U, F>(&self, f: F) -> Option<U> where F: FnMut(SensorArgs) -> U;
+    /// Returns gyroscope arguments.
+    fn gyroscope_args(&self) -> Option<SensorArgs> {
+        self.gyroscope(|args| args)
+    }
+}
+
+impl GyroscopeEvent for Event {
+    fn from_gyroscope_args(args: SensorArgs, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Move(Motion::Gyroscope(args)), timestamp))
+    }
+
+    fn gyroscope<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(SensorArgs) -> U
+    {
+        match *self {
+            Event::Input(Input::Move(Motion::Gyroscope(args)), _) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+/// When the magnetic field around the device changed.
+pub trait MagnetometerEvent: Sized {
+    /// Creates a magnetometer event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_magnetometer_args(args: SensorArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a magnetometer event.
+    fn magnetometer<U, F>(&self, f: F) -> Option<U> where F: FnMut(SensorArgs) -> U;
+    /// Returns magnetometer arguments.
+    fn magnetometer_args(&self) -> Option<SensorArgs> {
+        self.magnetometer(|args| args)
+    }
+}
+
+impl MagnetometerEvent for Event {
+    fn from_magnetometer_args(args: SensorArgs, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Move(Motion::Magnetometer(args)), timestamp))
+    }
+
+    fn magnetometer<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(SensorArgs) -> U
+    {
+        match *self {
+            Event::Input(Input::Move(Motion::Magnetometer(args)), _) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: [f64; 3]) -> SensorArgs {
+        SensorArgs { device: 0, values: values, time: Some(0.5), sample_rate: Some(100.0) }
+    }
+
+    #[test]
+    fn test_input_accelerometer() {
+        let e: Event = Motion::Accelerometer(args([0.0; 3])).into();
+        let x: Option<Event> =
+            AccelerometerEvent::from_accelerometer_args(args([0.0, -9.8, 0.0]), &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .accelerometer(|args| {
+                AccelerometerEvent::from_accelerometer_args(args, x.as_ref().unwrap())
+            })
+            .unwrap();
+        assert_eq!(x, y);
+    }
+
+    #[test]
+    fn test_input_gyroscope() {
+        let e: Event = Motion::Gyroscope(args([0.0; 3])).into();
+        let x: Option<Event> =
+            GyroscopeEvent::from_gyroscope_args(args([0.0, -9.8, 0.0]), &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .gyroscope(|args| {
+                GyroscopeEvent::from_gyroscope_args(args, x.as_ref().unwrap())
+            })
+            .unwrap();
+        assert_eq!(x, y);
+    }
+
+    #[test]
+    fn test_input_magnetometer() {
+        let e: Event = Motion::Magnetometer(args([0.0; 3])).into();
+        let x: Option<Event> =
+            MagnetometerEvent::from_magnetometer_args(args([0.0, -9.8, 0.0]), &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .magnetometer(|args| {
+                MagnetometerEvent::from_magnetometer_args(args, x.as_ref().unwrap())
+            })
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -0,0 +1,73 @@
+//! 3D mice with 6 axes.
+
+use {Event, Input, Motion};
+
+/// 3D mouse arguments, such as the translation and rotation of the cap.
+///
+/// Values are normalized -1..1 for the full range of the device.
+/// Uses a right handed coordinate system where `y` points up and `-z` points forward,
+/// so pushing the cap forward moves in the `-z` direction.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Debug)]
+pub struct SpaceMouseArgs {
+    /// The 3D mouse that moved.
+    pub device: i64,
+    /// The translation along the `x`, `y` and `z` axes.
+    pub translation: [f64; 3],
+    /// The rotation around the `x`, `y` and `z` axes.
+    pub rotation: [f64; 3],
+}
+
+/// When the cap of a 3D mouse, such as a SpaceMouse, moved.
+pub trait SpaceMouseEvent: Sized {
+    /// Creates a 3D mouse event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_space_mouse_args(args: SpaceMouseArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a 3D mouse event.
+    fn space_mouse<U, F>(&self, f: F) -> Option<U> where F: FnMut(SpaceMouseArgs) -> U;
+    /// Returns 3D mouse arguments.
+    fn space_mouse_args(&self) -> Option<SpaceMouseArgs> {
+        self.space_mouse(|args| args)
+    }
+}
+
+impl SpaceMouseEvent for Event {
+    fn from_space_mouse_args(args: SpaceMouseArgs, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Move(Motion::SpaceMouse(args)), timestamp))
+    }
+
+    fn space_mouse<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(SpaceMouseArgs) -> U
+    {
+        match *self {
+            Event::Input(Input::Move(Motion::SpaceMouse(args)), _) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_space_mouse() {
+        let e: Event = Motion::SpaceMouse(SpaceMouseArgs {
+            device: 0,
+            translation: [0.0; 3],
+            rotation: [0.0; 3],
+        }).into();
+        let args = SpaceMouseArgs {
+            device: 0,
+            translation: [0.1, 0.0, -0.5],
+            rotation: [0.0, 0.25, 0.0],
+        };
+        let x: Option<Event> = SpaceMouseEvent::from_space_mouse_args(args, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .space_mouse(|args| SpaceMouseEvent::from_space_mouse_args(args, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -0,0 +1,65 @@
+use {Event, Loop};
+
+/// The phase of the event loop that stalled.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize, Hash)]
+pub enum StallPhase {
+    /// Handling an update event.
+    Update,
+    /// Handling a render event.
+    Render,
+}
+
+/// Stall arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct StallArgs {
+    /// The phase that stalled.
+    pub phase: StallPhase,
+    /// The time in seconds spent handling the event.
+    pub dt: f64,
+}
+
+/// When handling a single update or render event took longer than the stall threshold.
+///
+/// Used to track down frame spikes.
+pub trait StallEvent: Sized {
+    /// Creates a stall event.
+    fn from_stall_args(args: &StallArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a stall event.
+    fn stall<U, F>(&self, f: F) -> Option<U> where F: FnMut(&StallArgs) -> U;
+    /// Returns stall arguments.
+    fn stall_args(&self) -> Option<StallArgs> {
+        self.stall(|args| *args)
+    }
+}
+
+impl StallEvent for Event {
+    fn from_stall_args(args: &StallArgs, _old_event: &Self) -> Option<Self> {
+        Some(Event::Loop(Loop::Stall(*args)))
+    }
+
+    fn stall<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(&StallArgs) -> U
+    {
+        match *self {
+            Event::Loop(Loop::Stall(ref args)) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_stall() {
+        let e: Event = StallArgs { phase: StallPhase::Update, dt: 0.0 }.into();
+        let x: Option<Event> = StallEvent::from_stall_args(
+            &StallArgs { phase: StallPhase::Render, dt: 1.0 }, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .stall(|args| StallEvent::from_stall_args(args, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -0,0 +1,260 @@
+//! Polling the state of input devices.
+
+use std::collections::{HashMap, HashSet};
+use std::mem;
+
+use {Button, ButtonArgs, ButtonState, ControllerHat, Event, HatState, Input, Loop, Motion, Touch,
+     TouchArgs};
+
+/// A snapshot of the keyboard, mouse, controllers and touch,
+/// updated from events and queried by game systems.
+///
+/// Call [`event()`](#method.event) for every event, before handling it:
+///
+/// ```ignore
+/// let mut input_state = InputState::new();
+/// while let Some(e) = events.next(&mut window) {
+///     input_state.event(&e);
+///     if let Some(args) = e.update_args() {
+///         if input_state.just_pressed(Key::Space) {
+///             // Jump.
+///         }
+///         player.x += input_state.controller_axis(0, 0) * args.dt;
+///     }
+/// }
+/// ```
+///
+/// Presses, releases and relative mouse motion are collected between update events,
+/// and can be queried while handling the next update event.
+/// Without update events, e.g. in lazy mode,
+/// call [`end_frame()`](#method.end_frame) to make them available instead.
+/// All buttons are released when the window loses focus.
+#[derive(Clone, Debug, Default)]
+pub struct InputState {
+    pressed: HashSet<Button>,
+    hats: HashMap<(i32, u8), HatState>,
+    axes: HashMap<(i32, u8), f64>,
+    touches: HashMap<(i64, i64), TouchArgs>,
+    mouse_cursor: Option<[f64; 2]>,
+    focused: bool,
+    frame: Frame,
+    next_frame: Frame,
+}
+
+/// Input collected between two update events.
+#[derive(Clone, Debug, Default)]
+struct Frame {
+    just_pressed: HashSet<Button>,
+    just_released: HashSet<Button>,
+    mouse_relative: [f64; 2],
+    mouse_scroll: [f64; 2],
+}
+
+impl InputState {
+    /// Creates a new input state with no buttons pressed.
+    pub fn new() -> InputState {
+        InputState {
+            focused: true,
+            ..Default::default()
+        }
+    }
+
+    /// Updates the input state from an event.
+    pub fn event(&mut self, e: &Event) {
+        match *e {
+            Event::Loop(Loop::Update(_)) => self.end_frame(),
+            Event::Input(ref input, _) => self.input(input),
+            _ => {}
+        }
+    }
+
+    fn input(&mut self, input: &Input) {
+        match *input {
+            Input::Button(ButtonArgs { state, button, .. }) => {
+                if let Button::Hat(ControllerHat { id, which, state: hat }) = button {
+                    let hat = if state == ButtonState::Press {hat} else {HatState::Centered};
+                    self.hats.insert((id, which), hat);
+                }
+                match state {
+                    ButtonState::Press => {
+                        if self.pressed.insert(button) {
+                            self.next_frame.just_pressed.insert(button);
+                        }
+                    }
+                    ButtonState::Release => {
+                        if self.pressed.remove(&button) {
+                            self.next_frame.just_released.insert(button);
+                        }
+                    }
+                }
+            }
+            Input::Move(Motion::MouseCursor(pos)) => self.mouse_cursor = Some(pos.into()),
+            Input::Move(Motion::MouseRelative(d)) => {
+                self.next_frame.mouse_relative[0] += d[0];
+                self.next_frame.mouse_relative[1] += d[1];
+            }
+            Input::Move(Motion::MouseScroll(d)) => {
+                self.next_frame.mouse_scroll[0] += d[0];
+                self.next_frame.mouse_scroll[1] += d[1];
+            }
+            Input::Move(Motion::ControllerAxis(args)) => {
+                self.axes.insert((args.id, args.axis), args.position);
+            }
+            Input::Move(Motion::Touch(args)) => {
+                let key = (args.device, args.id);
+                match args.touch {
+                    Touch::Start | Touch::Move => {
+                        self.touches.insert(key, args);
+                    }
+                    Touch::End | Touch::Cancel => {
+                        self.touches.remove(&key);
+                    }
+                }
+            }
+            Input::Cursor(false) => self.mouse_cursor = None,
+            Input::Focus(focused) => {
+                self.focused = focused;
+                if !focused {
+                    self.release_all();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Releases all buttons and ends all touches.
+    pub fn release_all(&mut self) {
+        for button in self.pressed.drain() {
+            self.next_frame.just_released.insert(button);
+        }
+        for hat in self.hats.values_mut() {
+            *hat = HatState::Centered;
+        }
+        self.touches.clear();
+    }
+
+    /// Makes the presses, releases and relative mouse motion collected so far
+    /// available for queries, and starts collecting anew.
+    ///
+    /// This is done automatically on update events.
+    pub fn end_frame(&mut self) {
+        self.frame = mem::replace(&mut self.next_frame, Frame::default());
+    }
+
+    /// Returns `true` if the button is held down.
+    pub fn is_pressed<B: Into<Button>>(&self, button: B) -> bool {
+        self.pressed.contains(&button.into())
+    }
+
+    /// Returns `true` if the button was pressed since the previous update.
+    pub fn just_pressed<B: Into<Button>>(&self, button: B) -> bool {
+        self.frame.just_pressed.contains(&button.into())
+    }
+
+    /// Returns `true` if the button was released since the previous update.
+    pub fn just_released<B: Into<Button>>(&self, button: B) -> bool {
+        self.frame.just_released.contains(&button.into())
+    }
+
+    /// Returns an iterator over the buttons held down.
+    pub fn pressed_buttons<'a>(&'a self) -> Box<Iterator<Item = Button> + 'a> {
+        Box::new(self.pressed.iter().cloned())
+    }
+
+    /// Returns the mouse cursor position in window coordinates,
+    /// or `None` if the cursor is outside the window.
+    pub fn mouse_cursor(&self) -> Option<[f64; 2]> {
+        self.mouse_cursor
+    }
+
+    /// Returns the relative mouse motion since the previous update.
+    pub fn mouse_relative(&self) -> [f64; 2] {
+        self.frame.mouse_relative
+    }
+
+    /// Returns the scroll ticks since the previous update.
+    pub fn mouse_scroll(&self) -> [f64; 2] {
+        self.frame.mouse_scroll
+    }
+
+    /// Returns the position of a controller axis, or `0.0` if it never moved.
+    pub fn controller_axis(&self, id: i32, axis: u8) -> f64 {
+        self.axes.get(&(id, axis)).cloned().unwrap_or(0.0)
+    }
+
+    /// Returns the state of a controller hat.
+    pub fn controller_hat(&self, id: i32, which: u8) -> HatState {
+        self.hats.get(&(id, which)).cloned().unwrap_or(HatState::Centered)
+    }
+
+    /// Returns an iterator over the active touches.
+    pub fn touches<'a>(&'a self) -> Box<Iterator<Item = &'a TouchArgs> + 'a> {
+        Box::new(self.touches.values())
+    }
+
+    /// Returns the active touch with the device and id.
+    pub fn touch(&self, device: i64, id: i64) -> Option<&TouchArgs> {
+        self.touches.get(&(device, id))
+    }
+
+    /// Returns `true` if the window has focus.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {Key, MouseButton, UpdateArgs};
+
+    #[test]
+    fn test_input_state() {
+        let press = |button: Button| -> Event {
+            ButtonArgs {
+                state: ButtonState::Press,
+                button: button,
+                scancode: None,
+                device: None,
+            }.into()
+        };
+        let release = |button: Button| -> Event {
+            ButtonArgs {
+                state: ButtonState::Release,
+                button: button,
+                scancode: None,
+                device: None,
+            }.into()
+        };
+        let update: Event = UpdateArgs { dt: 0.1, tick: 0, time: 0.0 }.into();
+
+        let mut state = InputState::new();
+        state.event(&press(Key::Space.into()));
+        state.event(&Input::Move(Motion::MouseRelative([1.0, 2.0])).into());
+        state.event(&Input::Move(Motion::MouseRelative([1.0, 2.0])).into());
+        assert!(state.is_pressed(Key::Space));
+        assert!(!state.just_pressed(Key::Space));
+
+        state.event(&update);
+        assert!(state.just_pressed(Key::Space));
+        assert_eq!(state.mouse_relative(), [2.0, 4.0]);
+
+        state.event(&press(MouseButton::Left.into()));
+        state.event(&release(Key::Space.into()));
+        state.event(&update);
+        assert!(!state.just_pressed(Key::Space));
+        assert!(state.just_released(Key::Space));
+        assert!(state.is_pressed(MouseButton::Left));
+        assert_eq!(state.mouse_relative(), [0.0, 0.0]);
+
+        state.event(&Input::Focus(false).into());
+        assert!(!state.is_pressed(MouseButton::Left));
+        state.event(&update);
+        assert!(state.just_released(MouseButton::Left));
+
+        state.event(&TouchArgs::new(0, 1, [0.5, 0.5], 1.0, Touch::Start).into());
+        assert_eq!(state.touches().count(), 1);
+        state.event(&TouchArgs::new(0, 1, [0.5, 0.5], 1.0, Touch::End).into());
+        assert!(state.touch(0, 1).is_none());
+    }
+}
@@ -0,0 +1,59 @@
+use {Event, Loop};
+
+/// Swap failed arguments.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize, Hash)]
+pub struct SwapFailedArgs {
+    /// Numbers the failed swaps, starting at `0`.
+    /// The event loop keeps the message, see `Events::last_swap_failed_message`.
+    pub id: u64,
+}
+
+/// When the window could not swap buffers for a reason other than
+/// a lost context, surface or device.
+///
+/// The after render event of the frame is emitted next.
+pub trait SwapFailedEvent: Sized {
+    /// Creates a swap failed event from arguments.
+    fn from_swap_failed_args(args: &SwapFailedArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a swap failed event.
+    fn swap_failed<U, F>(&self, f: F) -> Option<U>
+        where F: FnMut(&SwapFailedArgs) -> U;
+    /// Returns swap failed arguments.
+    fn swap_failed_args(&self) -> Option<SwapFailedArgs> {
+        self.swap_failed(|args| *args)
+    }
+}
+
+impl SwapFailedEvent for Event {
+    fn from_swap_failed_args(args: &SwapFailedArgs, _old_event: &Self) -> Option<Self> {
+        Some(Event::Loop(Loop::SwapFailed(*args)))
+    }
+
+    fn swap_failed<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(&SwapFailedArgs) -> U
+    {
+        match *self {
+            Event::Loop(Loop::SwapFailed(ref args)) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_swap_failed() {
+        let args = SwapFailedArgs { id: 0 };
+        let e: Event = args.into();
+        let x: Option<Event> = SwapFailedEvent::from_swap_failed_args(&args, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .swap_failed(|args| {
+                SwapFailedEvent::from_swap_failed_args(args, x.as_ref().unwrap())
+            })
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -0,0 +1,119 @@
+//! Graphics tablets.
+
+use {ButtonState, Event, Input};
+
+/// The tool used on a graphics tablet.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum TabletTool {
+    /// The tip of a pen.
+    Pen,
+    /// The eraser at the back of a pen.
+    Eraser,
+    /// An airbrush with a finger wheel.
+    Airbrush,
+    /// A puck or a mouse used on the tablet.
+    Mouse,
+    /// Another kind of tool.
+    Other,
+}
+
+/// Models graphics tablet events, in addition to pen strokes.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Debug)]
+pub enum Tablet {
+    /// A tool came close to the tablet or moved away from it.
+    Proximity {
+        /// The tablet.
+        device: i64,
+        /// The tool.
+        tool: TabletTool,
+        /// `true` when the tool came close to the tablet.
+        entered: bool,
+    },
+    /// An express key on the tablet was pressed or released.
+    ExpressKey {
+        /// The tablet.
+        device: i64,
+        /// The express key, starting at `0`.
+        key: u8,
+        /// Whether the key was pressed or released.
+        state: ButtonState,
+    },
+    /// A touch ring on the tablet changed.
+    Ring {
+        /// The tablet.
+        device: i64,
+        /// The ring, starting at `0`.
+        ring: u8,
+        /// The position, normalized 0..1 clockwise from the top.
+        position: f64,
+    },
+    /// A touch strip on the tablet changed.
+    Strip {
+        /// The tablet.
+        device: i64,
+        /// The strip, starting at `0`.
+        strip: u8,
+        /// The position, normalized 0..1 from the top or left.
+        position: f64,
+    },
+}
+
+impl Tablet {
+    /// Returns the tablet that sent the event.
+    pub fn device(&self) -> i64 {
+        match *self {
+            Tablet::Proximity { device, .. } |
+            Tablet::ExpressKey { device, .. } |
+            Tablet::Ring { device, .. } |
+            Tablet::Strip { device, .. } => device,
+        }
+    }
+}
+
+/// When a graphics tablet reported a tool, an express key, a ring or a strip.
+pub trait TabletEvent: Sized {
+    /// Creates a tablet event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_tablet(tablet: Tablet, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a tablet event.
+    fn tablet<U, F>(&self, f: F) -> Option<U> where F: FnMut(Tablet) -> U;
+    /// Returns tablet arguments.
+    fn tablet_args(&self) -> Option<Tablet> {
+        self.tablet(|tablet| tablet)
+    }
+}
+
+impl TabletEvent for Event {
+    fn from_tablet(tablet: Tablet, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Tablet(tablet), timestamp))
+    }
+
+    fn tablet<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(Tablet) -> U
+    {
+        match *self {
+            Event::Input(Input::Tablet(tablet), _) => Some(f(tablet)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_tablet() {
+        let e: Event = Tablet::Proximity {device: 0, tool: TabletTool::Pen, entered: true}.into();
+        let tablet = Tablet::Ring {device: 2, ring: 0, position: 0.25};
+        assert_eq!(tablet.device(), 2);
+        let x: Option<Event> = TabletEvent::from_tablet(tablet, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .tablet(|tablet| TabletEvent::from_tablet(tablet, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -0,0 +1,58 @@
+use {Event, Input};
+
+/// Stores the appearance of the operating system.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Theme {
+    /// Light mode.
+    Light,
+    /// Dark mode.
+    Dark,
+}
+
+/// When the appearance of the operating system changes between light and dark mode.
+pub trait ThemeEvent: Sized {
+    /// Creates a theme event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_theme(theme: Theme, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a theme event.
+    fn theme<U, F>(&self, f: F) -> Option<U> where F: FnMut(Theme) -> U;
+    /// Returns theme arguments.
+    fn theme_args(&self) -> Option<Theme> {
+        self.theme(|theme| theme)
+    }
+}
+
+impl ThemeEvent for Event {
+    fn from_theme(theme: Theme, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Theme(theme), timestamp))
+    }
+
+    fn theme<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(Theme) -> U
+    {
+        match *self {
+            Event::Input(Input::Theme(theme), _) => Some(f(theme)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_theme() {
+        use super::super::Input;
+
+        let e: Event = Input::Theme(Theme::Light).into();
+        let x: Option<Event> = ThemeEvent::from_theme(Theme::Dark, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .theme(|theme| ThemeEvent::from_theme(theme, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
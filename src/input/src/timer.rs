@@ -0,0 +1,51 @@
+use {Event, Loop};
+
+/// Timer arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct TimerArgs {
+    /// The id of the timer.
+    pub id: u64,
+}
+
+/// When a timer set on the event loop fires.
+pub trait TimerEvent: Sized {
+    /// Creates a timer event.
+    fn from_timer_args(args: &TimerArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a timer event.
+    fn timer<U, F>(&self, f: F) -> Option<U> where F: FnMut(&TimerArgs) -> U;
+    /// Returns timer arguments.
+    fn timer_args(&self) -> Option<TimerArgs> {
+        self.timer(|args| *args)
+    }
+}
+
+impl TimerEvent for Event {
+    fn from_timer_args(args: &TimerArgs, _old_event: &Self) -> Option<Self> {
+        Some(Event::Loop(Loop::Timer(*args)))
+    }
+
+    fn timer<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(&TimerArgs) -> U
+    {
+        match *self {
+            Event::Loop(Loop::Timer(ref args)) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_timer() {
+        let e: Event = TimerArgs { id: 0 }.into();
+        let x: Option<Event> = TimerEvent::from_timer_args(&TimerArgs { id: 1 }, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .timer(|args| TimerEvent::from_timer_args(args, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
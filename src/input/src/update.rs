@@ -5,6 +5,15 @@ use {Event, Loop};
 pub struct UpdateArgs {
     /// Delta time in seconds.
     pub dt: f64,
+    /// The number of updates before this update.
+    #[serde(default)]
+    pub tick: u64,
+    /// Total simulation time in seconds before this update.
+    ///
+    /// This is computed from the tick count instead of accumulating delta time,
+    /// so deterministic systems, such as lockstep networking, do not drift.
+    #[serde(default)]
+    pub time: f64,
 }
 
 /// When the application state should be updated.
@@ -13,7 +22,7 @@ pub trait UpdateEvent: Sized {
     fn from_update_args(args: &UpdateArgs, old_event: &Self) -> Option<Self>;
     /// Creates an update event with delta time.
     fn from_dt(dt: f64, old_event: &Self) -> Option<Self> {
-        UpdateEvent::from_update_args(&UpdateArgs { dt: dt }, old_event)
+        UpdateEvent::from_update_args(&UpdateArgs { dt: dt, tick: 0, time: 0.0 }, old_event)
     }
     /// Calls closure if this is an update event.
     fn update<U, F>(&self, f: F) -> Option<U> where F: FnMut(&UpdateArgs) -> U;
@@ -47,8 +56,9 @@ mod tests {
         use Event;
         use UpdateArgs;
 
-        let e: Event = UpdateArgs { dt: 0.0 }.into();
-        let x: Option<Event> = UpdateEvent::from_update_args(&UpdateArgs { dt: 1.0 }, &e);
+        let e: Event = UpdateArgs { dt: 0.0, tick: 0, time: 0.0 }.into();
+        let x: Option<Event> = UpdateEvent::from_update_args(
+            &UpdateArgs { dt: 1.0, tick: 1, time: 1.0 }, &e);
         let y: Option<Event> = x.clone()
             .unwrap()
             .update(|args| UpdateEvent::from_update_args(args, x.as_ref().unwrap()))
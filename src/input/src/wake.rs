@@ -0,0 +1,54 @@
+use {Event, Input};
+
+/// Wake arguments.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize, Hash)]
+pub struct WakeArgs;
+
+/// When waiting for events was interrupted by a window waker,
+/// for example from another thread when a network message arrives.
+pub trait WakeEvent: Sized {
+    /// Creates a wake event from arguments.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_wake_args(args: &WakeArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a wake event.
+    fn wake<U, F>(&self, f: F) -> Option<U> where F: FnMut(&WakeArgs) -> U;
+    /// Returns wake arguments.
+    fn wake_args(&self) -> Option<WakeArgs> {
+        self.wake(|args| *args)
+    }
+}
+
+impl WakeEvent for Event {
+    fn from_wake_args(args: &WakeArgs, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::Wake(*args), timestamp))
+    }
+
+    fn wake<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(&WakeArgs) -> U
+    {
+        match *self {
+            Event::Input(Input::Wake(ref args), _) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_wake() {
+        use super::super::Input;
+
+        let e: Event = Input::Wake(WakeArgs).into();
+        let x: Option<Event> = WakeEvent::from_wake_args(&WakeArgs, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .wake(|args| WakeEvent::from_wake_args(args, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -0,0 +1,261 @@
+//! Mapping the browser Gamepad API to controller events.
+
+use std::collections::HashMap;
+
+use {Button, ButtonArgs, ButtonState, ControllerAxisArgs, ControllerButton, DeviceArgs,
+     DeviceCapabilities, DeviceInfo, DeviceKind, DeviceState, Event};
+
+/// The SDL buttons of the buttons in the W3C standard gamepad mapping.
+///
+/// The triggers, buttons `6` and `7`, are reported as axes.
+const STANDARD_BUTTONS: [Option<u8>; 17] = [
+    Some(0), Some(1), Some(2), Some(3),
+    Some(9), Some(10), None, None,
+    Some(4), Some(6), Some(7), Some(8),
+    Some(11), Some(12), Some(13), Some(14),
+    Some(5),
+];
+
+/// The SDL axes of the triggers in the W3C standard gamepad mapping.
+const STANDARD_TRIGGERS: [(usize, u8); 2] = [(6, 4), (7, 5)];
+
+/// A button of a gamepad, as reported by the browser Gamepad API.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, PartialOrd, Debug, Default)]
+pub struct WebGamepadButton {
+    /// Whether the button is pressed.
+    pub pressed: bool,
+    /// How far the button is pressed, from `0.0` to `1.0`.
+    pub value: f64,
+}
+
+/// The state of a gamepad, as reported by `navigator.getGamepads()`.
+#[derive(Clone, Deserialize, Serialize, PartialEq, PartialOrd, Debug)]
+pub struct WebGamepadState {
+    /// The index of the gamepad, used as controller id.
+    pub index: u32,
+    /// The id string of the gamepad.
+    pub id: String,
+    /// Whether the browser uses the standard mapping, `mapping == "standard"`.
+    pub standard: bool,
+    /// The buttons.
+    pub buttons: Vec<WebGamepadButton>,
+    /// The axes, from `-1.0` to `1.0`.
+    pub axes: Vec<f64>,
+}
+
+impl WebGamepadState {
+    /// Returns the USB vendor and product id found in the id string, if any.
+    ///
+    /// Chrome reports `"Name (STANDARD GAMEPAD Vendor: 045e Product: 028e)"`,
+    /// Firefox reports `"045e-028e-Name"`.
+    pub fn usb_id(&self) -> Option<(u16, u16)> {
+        let hex = |s: &str| if s.len() == 4 {u16::from_str_radix(s, 16).ok()} else {None};
+        if let Some(start) = self.id.find("Vendor: ") {
+            let rest = &self.id[start + "Vendor: ".len()..];
+            let vendor = hex(rest.get(..4)?)?;
+            let rest = &rest[rest.find("Product: ")? + "Product: ".len()..];
+            return Some((vendor, hex(rest.get(..4)?)?));
+        }
+        let mut parts = self.id.splitn(3, '-');
+        Some((hex(parts.next()?)?, hex(parts.next()?)?))
+    }
+
+    /// Returns the name of the gamepad, without the vendor and product id.
+    pub fn name(&self) -> &str {
+        if self.id.contains("Vendor: ") {
+            if let Some(end) = self.id.rfind(" (") {
+                return &self.id[..end];
+            }
+        }
+        if self.id.splitn(3, '-').count() == 3 && self.usb_id().is_some() {
+            return &self.id[10..];
+        }
+        &self.id
+    }
+
+    /// Returns the device info of the gamepad.
+    pub fn device_info(&self) -> DeviceInfo {
+        let usb_id = self.usb_id();
+        DeviceInfo {
+            kind: DeviceKind::Controller,
+            id: self.index as i64,
+            name: self.name().into(),
+            capabilities: DeviceCapabilities::BUTTONS | DeviceCapabilities::AXES,
+            vendor_id: usb_id.map(|(vendor, _)| vendor),
+            product_id: usb_id.map(|(_, product)| product),
+            serial: None,
+        }
+    }
+
+    /// Returns the pressed buttons and axis positions of the gamepad,
+    /// numbered like the SDL game controller API if it uses the standard mapping.
+    ///
+    /// With the standard mapping, the analog triggers are reported as axes `4` and `5`,
+    /// from `0.0` to `1.0`.
+    /// Gamepads without the standard mapping keep the numbering of the browser.
+    fn controls(&self) -> (Vec<u8>, Vec<(u8, f64)>) {
+        let mut buttons = vec![];
+        let mut axes: Vec<(u8, f64)> =
+            self.axes.iter().enumerate().map(|(i, &position)| (i as u8, position)).collect();
+        for (i, button) in self.buttons.iter().enumerate() {
+            let sdl = if self.standard {
+                STANDARD_BUTTONS.get(i).cloned().unwrap_or(Some(i as u8))
+            } else {
+                Some(i as u8)
+            };
+            match sdl {
+                Some(sdl) if button.pressed => buttons.push(sdl),
+                _ => {}
+            }
+        }
+        if self.standard {
+            axes.truncate(4);
+            for &(i, axis) in &STANDARD_TRIGGERS {
+                let value = self.buttons.get(i).map(|b| b.value).unwrap_or(0.0);
+                axes.push((axis, value));
+            }
+        }
+        (buttons, axes)
+    }
+}
+
+/// Turns polled browser gamepad states into controller and device events.
+///
+/// The browser Gamepad API reports the state of gamepads instead of events.
+/// Call `update` with the connected gamepads once per frame,
+/// e.g. from `navigator.getGamepads()`,
+/// to get the events that a desktop backend would emit.
+///
+/// ```ignore
+/// let events = gamepads.update(&states);
+/// for e in &events {
+///     app.event(e);
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct WebGamepads {
+    pads: HashMap<u32, (Vec<u8>, Vec<(u8, f64)>, DeviceInfo)>,
+}
+
+impl WebGamepads {
+    /// Creates a mapping without connected gamepads.
+    pub fn new() -> WebGamepads {
+        WebGamepads::default()
+    }
+
+    /// Returns the connected gamepads, sorted by index.
+    pub fn devices(&self) -> Vec<DeviceInfo> {
+        let mut devices: Vec<DeviceInfo> =
+            self.pads.values().map(|&(_, _, ref info)| info.clone()).collect();
+        devices.sort_by_key(|info| info.id);
+        devices
+    }
+
+    /// Updates the state of the connected gamepads, returning events for what changed.
+    ///
+    /// New gamepads emit a device added event, missing gamepads release their buttons
+    /// and emit a device removed event.
+    pub fn update(&mut self, gamepads: &[WebGamepadState]) -> Vec<Event> {
+        let mut events = vec![];
+        let mut removed: Vec<u32> = self.pads.keys()
+            .filter(|index| !gamepads.iter().any(|pad| pad.index == **index))
+            .cloned()
+            .collect();
+        removed.sort();
+        for index in removed {
+            let (buttons, _, info) = self.pads.remove(&index).unwrap();
+            for button in buttons {
+                events.push(button_event(index, button, ButtonState::Release));
+            }
+            events.push(DeviceArgs {state: DeviceState::Removed, info: info}.into());
+        }
+        for pad in gamepads {
+            let (buttons, axes) = pad.controls();
+            let (old_buttons, old_axes) = match self.pads.remove(&pad.index) {
+                Some((buttons, axes, _)) => (buttons, axes),
+                None => {
+                    events.push(DeviceArgs {
+                        state: DeviceState::Added,
+                        info: pad.device_info(),
+                    }.into());
+                    (vec![], vec![])
+                }
+            };
+            for &button in old_buttons.iter().filter(|b| !buttons.contains(b)) {
+                events.push(button_event(pad.index, button, ButtonState::Release));
+            }
+            for &button in buttons.iter().filter(|b| !old_buttons.contains(b)) {
+                events.push(button_event(pad.index, button, ButtonState::Press));
+            }
+            for &(axis, position) in &axes {
+                let old = old_axes.iter().find(|&&(a, _)| a == axis).map(|&(_, p)| p);
+                // Sticks and triggers at rest are not reported when connecting.
+                if old.unwrap_or(0.0) != position {
+                    events.push(ControllerAxisArgs::new(pad.index as i32, axis, position).into());
+                }
+            }
+            self.pads.insert(pad.index, (buttons, axes, pad.device_info()));
+        }
+        events
+    }
+}
+
+fn button_event(index: u32, button: u8, state: ButtonState) -> Event {
+    ButtonArgs {
+        state: state,
+        button: Button::Controller(ControllerButton::new(index as i32, button)),
+        scancode: None,
+        device: None,
+    }.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {ControllerAxisEvent, DeviceEvent, PressEvent, ReleaseEvent};
+
+    fn pad(pressed: &[usize], trigger: f64) -> WebGamepadState {
+        let mut buttons = vec![WebGamepadButton::default(); 17];
+        for &i in pressed {
+            buttons[i] = WebGamepadButton {pressed: true, value: 1.0};
+        }
+        buttons[7] = WebGamepadButton {pressed: trigger > 0.5, value: trigger};
+        WebGamepadState {
+            index: 1,
+            id: "Xbox 360 Controller (XInput STANDARD GAMEPAD Vendor: 045e Product: 028e)"
+                .into(),
+            standard: true,
+            buttons: buttons,
+            axes: vec![0.0; 4],
+        }
+    }
+
+    #[test]
+    fn test_web_gamepads() {
+        let state = pad(&[], 0.0);
+        assert_eq!(state.usb_id(), Some((0x045e, 0x028e)));
+        assert_eq!(state.name(), "Xbox 360 Controller");
+        let firefox = WebGamepadState {id: "054c-09cc-Wireless Controller".into(), ..state};
+        assert_eq!(firefox.usb_id(), Some((0x054c, 0x09cc)));
+        assert_eq!(firefox.name(), "Wireless Controller");
+
+        let mut gamepads = WebGamepads::new();
+        let events = gamepads.update(&[pad(&[], 0.0)]);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].device_added_args().map(|info| info.id), Some(1));
+
+        // Start is button `6` in SDL numbering, the right trigger is axis `5`.
+        let events = gamepads.update(&[pad(&[9], 0.75)]);
+        assert_eq!(events[0].press_args(),
+                   Some(Button::Controller(ControllerButton::new(1, 6))));
+        assert_eq!(events[1].controller_axis_args(),
+                   Some(ControllerAxisArgs::new(1, 5, 0.75)));
+        assert_eq!(events.len(), 2);
+
+        let events = gamepads.update(&[]);
+        assert_eq!(events[0].release_args(),
+                   Some(Button::Controller(ControllerButton::new(1, 6))));
+        assert_eq!(events[1].device_removed_args().map(|info| info.id), Some(1));
+        assert_eq!(gamepads.devices(), vec![]);
+    }
+}
@@ -0,0 +1,66 @@
+use {Event, Input};
+
+/// Stores whether the window is minimized, maximized or neither.
+#[derive(Copy, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum WindowState {
+    /// The window is neither minimized nor maximized.
+    Normal,
+    /// The window is minimized (iconified).
+    Minimized,
+    /// The window is maximized.
+    Maximized,
+}
+
+impl Default for WindowState {
+    fn default() -> WindowState {
+        WindowState::Normal
+    }
+}
+
+/// When the window is minimized, maximized or restored.
+pub trait WindowStateEvent: Sized {
+    /// Creates a window state event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_window_state(state: WindowState, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a window state event.
+    fn window_state<U, F>(&self, f: F) -> Option<U> where F: FnMut(WindowState) -> U;
+    /// Returns window state arguments.
+    fn window_state_args(&self) -> Option<WindowState> {
+        self.window_state(|state| state)
+    }
+}
+
+impl WindowStateEvent for Event {
+    fn from_window_state(state: WindowState, old_event: &Self) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {*x} else {None};
+        Some(Event::Input(Input::WindowState(state), timestamp))
+    }
+
+    fn window_state<U, F>(&self, mut f: F) -> Option<U>
+        where F: FnMut(WindowState) -> U
+    {
+        match *self {
+            Event::Input(Input::WindowState(state), _) => Some(f(state)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_window_state() {
+        use super::super::Input;
+
+        let e: Event = Input::WindowState(WindowState::Normal).into();
+        let x: Option<Event> = WindowStateEvent::from_window_state(WindowState::Minimized, &e);
+        let y: Option<Event> = x.clone()
+            .unwrap()
+            .window_state(|state| WindowStateEvent::from_window_state(state, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
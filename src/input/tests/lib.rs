@@ -15,22 +15,109 @@ fn test_encode_decode() {
         state: ButtonState::Press,
         button: Button::Keyboard(Key::A),
         scancode: None,
+        device: None,
     }));
     test(Input::Button(ButtonArgs {
         state: ButtonState::Release,
         button: Button::Keyboard(Key::A),
         scancode: None,
+        device: Some(1),
     }));
-    test(Input::Move(Motion::MouseCursor([0.0, 0.0])));
+    test(Input::Move(Motion::MouseCursor(LogicalPosition::new(1.0, 2.0))));
+    test(Input::Move(Motion::MouseDevice(MouseDeviceArgs { device: 2, relative: [1.0, 0.0] })));
     test(Input::Text("hello".into()));
     test(Input::Resize(ResizeArgs {
-        window_size: [0.0, 0.0],
-        draw_size: [0, 0],
+        window_size: LogicalSize::new(100.0, 50.0),
+        draw_size: PhysicalSize::new(200, 100),
     }));
     test(Input::Focus(true));
     test(Input::Cursor(true));
     test(Input::Close(CloseArgs));
+    test(Input::ScaleFactorChanged(2.0));
 
+    test(Input::Moved([10, 20]));
+    test(Input::WindowState(WindowState::Maximized));
+    test(Input::CloseRequested(CloseRequestedArgs));
+    test(Input::Context(ContextState::Lost));
+    test(Input::Context(ContextState::SurfaceLost));
+    test(Input::Context(ContextState::DeviceRemoved));
+    test(Input::Theme(Theme::Dark));
+    test(Input::SafeArea(SafeAreaInsets {top: 44.0, bottom: 34.0, left: 0.0, right: 0.0}));
+    test(Input::Wake(WakeArgs));
+    test(Input::Action(ActionArgs { action: "jump".into(), state: ButtonState::Press }));
+    test(Input::Axis(AxisArgs { axis: "steer".into(), value: -0.5 }));
+    test(Input::Combo(ComboArgs { combo: "dash".into() }));
+    test(Input::Accessibility(AccessibilitySettings {
+        sticky_keys: true,
+        key_repeat_delay: Some(0.5),
+        key_repeat_rate: None,
+        double_click_interval: Some(0.5),
+        reduced_motion: false,
+        high_contrast: true,
+    }));
+    test(Input::Device(DeviceArgs {
+        state: DeviceState::Added,
+        info: DeviceInfo {
+            kind: DeviceKind::Keyboard,
+            id: 1,
+            name: "Keyboard".into(),
+            capabilities: DeviceCapabilities::BUTTONS,
+            vendor_id: Some(0x046d),
+            product_id: None,
+            serial: Some("1234".into()),
+        },
+    }));
+    test(Input::Move(Motion::Midi(MidiArgs {
+        device: 1,
+        message: MidiMessage::ControlChange { channel: 0, controller: 64, value: 127 },
+        time: None,
+    })));
+    test(Input::Move(Motion::Gaze(GazeArgs { device: 3, position: [0.5, 0.25], confidence: 0.9 })));
+    test(Input::Move(Motion::Pose(PoseArgs {
+        device: 1,
+        position: [0.0, 1.5, -0.5],
+        orientation: [0.0, 0.0, 0.0, 1.0],
+        time: Some(0.5),
+    })));
+    test(Input::Move(Motion::SpaceMouse(SpaceMouseArgs {
+        device: 1,
+        translation: [0.0, 0.5, 0.0],
+        rotation: [0.25, 0.0, 0.0],
+    })));
+    test(Input::Tablet(Tablet::ExpressKey { device: 1, key: 3, state: ButtonState::Press }));
+    test(Input::Move(Motion::Accelerometer(SensorArgs {
+        device: 0,
+        values: [0.0, -9.8, 0.0],
+        time: Some(1.0),
+        sample_rate: Some(100.0),
+    })));
+    test(Input::Move(Motion::Gyroscope(SensorArgs {
+        device: 0,
+        values: [0.0, 0.5, 0.0],
+        time: None,
+        sample_rate: None,
+    })));
+    test(Input::Move(Motion::Magnetometer(SensorArgs {
+        device: 0,
+        values: [20.0, -40.0, 5.0],
+        time: None,
+        sample_rate: Some(50.0),
+    })));
+    test(Input::OrientationChanged(Orientation::PortraitUpsideDown));
+    test(Input::Lifecycle(Lifecycle::WillTerminate));
+    test(Input::LowMemory(MemoryPressure::Critical));
+    test(Input::Back(BackArgs { source: BackSource::Gesture }));
+    test(Input::Move(Motion::Pointer(PointerArgs {
+        id: 2,
+        kind: PointerKind::Touch,
+        primary: false,
+        position: [5.0, 5.0],
+        pressure: 0.5,
+        touch: Touch::Start,
+    })));
+    test(Input::OpenUrl("mygame://join/1234".into()));
+    test(Input::OpenFile("level.map".into()));
+    test(Input::DropData(DropData::Text("note".into())));
     let test = |l| {
         let encoded = serde_json::to_string(&l).unwrap();
         let decoded: Loop = serde_json::from_str(&encoded).unwrap();
@@ -40,8 +127,36 @@ fn test_encode_decode() {
         window_size: [0.0, 0.0],
         draw_size: [0, 0],
         ext_dt: 0.0,
+        ext_alpha: 0.0,
+        frame: 0,
+        time: 0.0,
+    }));
+    test(Loop::AfterRender(AfterRenderArgs { gpu_dt: Some(0.004) }));
+    test(Loop::Update(UpdateArgs { dt: 0.0, tick: 0, time: 0.0 }));
+    test(Loop::Idle(IdleArgs { dt: 0.0, sleep_dt: 0.0 }));
+    test(Loop::LagWarning(LagWarningArgs {
+        kind: LagKind::UpdateDebt,
+        lag_dt: 0.0,
+        dropped_dt: 0.0,
     }));
-    test(Loop::AfterRender(AfterRenderArgs));
-    test(Loop::Update(UpdateArgs { dt: 0.0 }));
-    test(Loop::Idle(IdleArgs { dt: 0.0 }));
+    test(Loop::Timer(TimerArgs { id: 1 }));
+    test(Loop::AfterUpdate(AfterUpdateArgs));
+    test(Loop::ChannelUpdate(ChannelUpdateArgs { id: 1, dt: 0.1 }));
+    test(Loop::Stall(StallArgs { phase: StallPhase::Render, dt: 0.1 }));
+    test(Loop::Inactivity(InactivityArgs { inactive: true, idle_time: 60.0 }));
+    test(Loop::HandlerPanicked(HandlerPanickedArgs { id: 1 }));
+    test(Loop::SwapFailed(SwapFailedArgs { id: 1 }));
+}
+
+#[test]
+fn test_bindings_encode_decode() {
+    use input::bindings::AxisSource;
+
+    let mut bindings = Bindings::new();
+    bindings.bind_action("jump", Key::Space);
+    bindings.bind_axis("steer", AxisSource::ControllerAxis { id: 0, axis: 0 });
+    let encoded = serde_json::to_string(&bindings).unwrap();
+    let decoded: Bindings = serde_json::from_str(&encoded).unwrap();
+    assert_eq!(decoded.action_bindings(), bindings.action_bindings());
+    assert_eq!(decoded.axis_bindings(), bindings.axis_bindings());
 }
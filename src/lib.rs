@@ -60,6 +60,11 @@
 //!
 //! It is common to use points for 2D graphics to match window coordinates.
 //!
+//! The number of pixels per point is called the scale factor,
+//! see [Window::scale_factor](window::Window::scale_factor).
+//! Use [LogicalSize](input::LogicalSize) and [PhysicalSize](input::PhysicalSize)
+//! to convert between the two coordinate systems.
+//!
 //! Unintentional blurring, e.g. of rendered text, might be a side effect incorrect sampling.
 //!
 //! ### About Piston as a Game Engine
@@ -79,13 +84,13 @@
 //! For example (a few libraries, there are many more):
 //!
 //! - [Image](https://github.com/pistondevelopers/image) library is standalone
-//! from both the core and the 2D graphics library,
-//! only connected through the 2D graphics backends.
+//!   from both the core and the 2D graphics library,
+//!   only connected through the 2D graphics backends.
 //! - [Piston's 2D graphics](https://github.com/pistondevelopers/graphics) is optional and can be used without a window backend.
-//! The window backend can be used without a 2D graphics backend, and so on.
+//!   The window backend can be used without a 2D graphics backend, and so on.
 //! - For image processing, see [Imageproc](https://github.com/pistondevelopers/imageproc).
 //! - [Dyon](https://github.com/pistondevelopers/dyon) is a Rusty dynamically typed scripting language,
-//! using a lifetime checker without garbage collection.
+//!   using a lifetime checker without garbage collection.
 //!
 //! For more information and an overview, see [Piston's README in the core repository](https://github.com/pistondevelopers/piston).
 //!
@@ -0,0 +1,83 @@
+//! Feature detection for window backends.
+
+/// Stores which optional features are supported by a window backend.
+///
+/// Cross-backend applications can use this to detect features up front,
+/// instead of calling a method that silently has no effect.
+///
+/// See [`AdvancedWindow::get_capabilities`](trait.AdvancedWindow.html#method.get_capabilities).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct WindowCapabilities {
+    /// Whether the clipboard can be read and written.
+    pub clipboard: bool,
+    /// Whether text input through an input method editor (IME) is supported.
+    pub ime: bool,
+    /// Whether controllers can rumble.
+    pub rumble: bool,
+    /// Whether exclusive fullscreen, changing the video mode, is supported.
+    pub exclusive_fullscreen: bool,
+    /// Whether vsync can be changed after the window is created.
+    pub set_vsync: bool,
+    /// Whether the mouse cursor can be confined or locked to the window.
+    pub cursor_grab: bool,
+    /// Whether connected monitors can be listed.
+    pub monitors: bool,
+    /// Whether connected input devices can be listed.
+    pub devices: bool,
+    /// Whether gaze events from eye trackers are emitted.
+    pub gaze: bool,
+    /// Whether pixels can be read from the frame buffer.
+    pub read_pixels: bool,
+}
+
+impl WindowCapabilities {
+    /// Returns whether the clipboard can be read and written.
+    pub fn supports_clipboard(&self) -> bool {
+        self.clipboard
+    }
+
+    /// Returns whether text input through an input method editor (IME) is supported.
+    pub fn supports_ime(&self) -> bool {
+        self.ime
+    }
+
+    /// Returns whether controllers can rumble.
+    pub fn supports_rumble(&self) -> bool {
+        self.rumble
+    }
+
+    /// Returns whether exclusive fullscreen, changing the video mode, is supported.
+    pub fn supports_exclusive_fullscreen(&self) -> bool {
+        self.exclusive_fullscreen
+    }
+
+    /// Returns whether vsync can be changed after the window is created.
+    pub fn supports_set_vsync(&self) -> bool {
+        self.set_vsync
+    }
+
+    /// Returns whether the mouse cursor can be confined or locked to the window.
+    pub fn supports_cursor_grab(&self) -> bool {
+        self.cursor_grab
+    }
+
+    /// Returns whether connected monitors can be listed.
+    pub fn supports_monitors(&self) -> bool {
+        self.monitors
+    }
+
+    /// Returns whether connected input devices can be listed.
+    pub fn supports_devices(&self) -> bool {
+        self.devices
+    }
+
+    /// Returns whether gaze events from eye trackers are emitted.
+    pub fn supports_gaze(&self) -> bool {
+        self.gaze
+    }
+
+    /// Returns whether pixels can be read from the frame buffer.
+    pub fn supports_read_pixels(&self) -> bool {
+        self.read_pixels
+    }
+}
@@ -0,0 +1,170 @@
+//! Loading window settings from configuration files.
+//!
+//! Requires the `toml` or `ron` feature.
+//!
+//! The `title` and `size` fields are required,
+//! other fields use the defaults of [`WindowSettings::new`](../struct.WindowSettings.html#method.new).
+//! Owner windows are passed when building instead,
+//! see [`WindowSettings::build_with_owner`](../struct.WindowSettings.html#method.build_with_owner).
+//!
+//! ```toml
+//! title = "My Game"
+//! size = [1280, 720]
+//! fullscreen = false
+//! vsync = true
+//! samples = 4
+//! graphics_api = { api = "OpenGL", major = 3, minor = 2 }
+//! ```
+
+use std::error::Error;
+
+use {Api, InvalidWindowSettingsError, WindowSettings};
+
+const GRAPHICS_APIS: &[&str] = &["OpenGL", "Vulkan", "DirectX", "Metal"];
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GraphicsApiConfig {
+    api: String,
+    major: u32,
+    minor: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WindowSettingsConfig {
+    title: String,
+    size: [u32; 2],
+    samples: Option<u8>,
+    fullscreen: Option<bool>,
+    exit_on_esc: Option<bool>,
+    automatic_close: Option<bool>,
+    vsync: Option<bool>,
+    graphics_api: Option<GraphicsApiConfig>,
+    srgb: Option<bool>,
+    resizable: Option<bool>,
+    decorated: Option<bool>,
+    controllers: Option<bool>,
+}
+
+impl WindowSettingsConfig {
+    fn into_settings(self) -> Result<WindowSettings, InvalidWindowSettingsError> {
+        if self.size[0] == 0 || self.size[1] == 0 {
+            return Err(InvalidWindowSettingsError {
+                setting: "size",
+                message: format!("Expected non-zero width and height, found {}x{}",
+                                 self.size[0], self.size[1]),
+            });
+        }
+        if let Some(samples) = self.samples {
+            if samples != 0 && !samples.is_power_of_two() {
+                return Err(InvalidWindowSettingsError {
+                    setting: "samples",
+                    message: format!("Expected 0 or a power of two, found {}", samples),
+                });
+            }
+        }
+
+        let mut settings = WindowSettings::new(self.title, self.size);
+        if let Some(x) = self.samples {settings.set_samples(x)}
+        if let Some(x) = self.fullscreen {settings.set_fullscreen(x)}
+        if let Some(x) = self.exit_on_esc {settings.set_exit_on_esc(x)}
+        if let Some(x) = self.automatic_close {settings.set_automatic_close(x)}
+        if let Some(x) = self.vsync {settings.set_vsync(x)}
+        if let Some(x) = self.srgb {settings.set_srgb(x)}
+        if let Some(x) = self.resizable {settings.set_resizable(x)}
+        if let Some(x) = self.decorated {settings.set_decorated(x)}
+        if let Some(x) = self.controllers {settings.set_controllers(x)}
+        if let Some(api) = self.graphics_api {
+            let name = match GRAPHICS_APIS.iter().find(|&&name| name == api.api) {
+                Some(&name) => name,
+                None => return Err(InvalidWindowSettingsError {
+                    setting: "graphics_api",
+                    message: format!("Expected one of {}, found `{}`",
+                                     GRAPHICS_APIS.join(", "), api.api),
+                }),
+            };
+            settings.set_graphics_api(Api {
+                api: name.into(),
+                major: api.major,
+                minor: api.minor,
+            });
+        }
+        settings.validate()?;
+        Ok(settings)
+    }
+}
+
+impl WindowSettings {
+    /// Loads window settings from a TOML document.
+    ///
+    /// Requires the `toml` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with the location in the document when
+    /// the document can not be parsed, has unknown fields or fields of the wrong type.
+    /// Returns an [`InvalidWindowSettingsError`](./struct.InvalidWindowSettingsError.html)
+    /// when a setting has an invalid value.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(source: &str) -> Result<WindowSettings, Box<Error>> {
+        let config: WindowSettingsConfig = ::toml::from_str(source)?;
+        Ok(config.into_settings()?)
+    }
+
+    /// Loads window settings from a RON document.
+    ///
+    /// Requires the `ron` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with the location in the document when
+    /// the document can not be parsed, has unknown fields or fields of the wrong type.
+    /// Returns an [`InvalidWindowSettingsError`](./struct.InvalidWindowSettingsError.html)
+    /// when a setting has an invalid value.
+    #[cfg(feature = "ron")]
+    pub fn from_ron(source: &str) -> Result<WindowSettings, Box<Error>> {
+        let config: WindowSettingsConfig = ::ron::from_str(source)?;
+        Ok(config.into_settings()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml() {
+        let settings = WindowSettings::from_toml(r#"
+            title = "test"
+            size = [640, 480]
+            vsync = true
+            graphics_api = { api = "OpenGL", major = 3, minor = 2 }
+        "#).unwrap();
+        assert_eq!(settings.get_title(), "test");
+        assert_eq!(settings.get_size(), [640, 480].into());
+        assert!(settings.get_vsync());
+        assert_eq!(settings.get_maybe_graphics_api(), Some(Api::opengl(3, 2)));
+
+        let err = WindowSettings::from_toml("title = \"test\"\nsize = [640, 480]\nvsnyc = true")
+            .err().unwrap();
+        assert!(err.to_string().contains("vsnyc"));
+
+        let err = WindowSettings::from_toml("title = \"test\"\nsize = [0, 480]").err().unwrap();
+        assert!(err.to_string().contains("size"));
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn test_from_ron() {
+        let settings = WindowSettings::from_ron(
+            r#"(title: "test", size: (640, 480), samples: Some(4))"#
+        ).unwrap();
+        assert_eq!(settings.get_samples(), 4);
+
+        let err = WindowSettings::from_ron(r#"(title: "test", size: (640, 480), samples: Some(3))"#)
+            .err().unwrap();
+        assert!(err.to_string().contains("samples"));
+    }
+}
@@ -0,0 +1,67 @@
+//! Mouse cursor appearance.
+
+use RgbaImage;
+
+/// Stores the icon of the mouse cursor.
+///
+/// See [`AdvancedWindow::set_cursor`](trait.AdvancedWindow.html#method.set_cursor).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CursorIcon {
+    /// The default arrow.
+    Arrow,
+    /// A pointing hand, usually over links.
+    Hand,
+    /// A text caret, usually over editable text.
+    Text,
+    /// A crosshair.
+    Crosshair,
+    /// The application is busy.
+    Wait,
+    /// The action is not allowed.
+    NotAllowed,
+    /// Something can be moved.
+    Move,
+    /// Resize horizontally (east-west).
+    ResizeEastWest,
+    /// Resize vertically (north-south).
+    ResizeNorthSouth,
+    /// Resize diagonally (north-west to south-east).
+    ResizeNorthWestSouthEast,
+    /// Resize diagonally (north-east to south-west).
+    ResizeNorthEastSouthWest,
+    /// A custom cursor from RGBA pixel data.
+    Custom {
+        /// The cursor image.
+        image: RgbaImage,
+        /// The pixel in the image pointing at the cursor position.
+        hotspot: [u32; 2],
+    },
+}
+
+impl Default for CursorIcon {
+    fn default() -> CursorIcon {
+        CursorIcon::Arrow
+    }
+}
+
+/// Stores how the mouse cursor is grabbed by the window.
+///
+/// See [`AdvancedWindow::set_cursor_grab`](trait.AdvancedWindow.html#method.set_cursor_grab).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CursorGrabMode {
+    /// The cursor moves freely.
+    None,
+    /// The cursor is confined to the window area.
+    Confined,
+    /// The cursor is locked in place.
+    ///
+    /// Movement is reported through relative mouse events,
+    /// for example in a first-person shooter game.
+    Locked,
+}
+
+impl Default for CursorGrabMode {
+    fn default() -> CursorGrabMode {
+        CursorGrabMode::None
+    }
+}
@@ -0,0 +1,155 @@
+//! Saving and restoring window geometry between launches.
+
+use input::WindowState;
+
+use {AdvancedWindow, Monitor, MonitorId, Position};
+
+/// Stores the size, position and state of a window.
+///
+/// Capture the geometry before closing the window, save it to a file,
+/// and restore it the next time the application is launched.
+/// Requires the `serde` feature for serialization.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WindowGeometry {
+    /// The size of the window in points.
+    pub size: [f64; 2],
+    /// The position of the window in points, if any.
+    pub position: Option<[i32; 2]>,
+    /// Whether the window was minimized or maximized.
+    pub window_state: WindowState,
+    /// The name of the monitor the window was on, if known.
+    pub monitor: Option<String>,
+}
+
+impl WindowGeometry {
+    /// Captures the current geometry of a window.
+    pub fn capture<W: AdvancedWindow>(window: &W) -> WindowGeometry {
+        WindowGeometry {
+            size: window.size().into(),
+            position: window.get_position().map(|pos| pos.into()),
+            window_state: window.get_window_state(),
+            monitor: window.current_monitor().and_then(|monitor| monitor.name),
+        }
+    }
+
+    /// Restores the geometry of a window.
+    ///
+    /// Performs sanity checks before restoring:
+    ///
+    /// - The size is at least 1x1 point and fits on the target monitor
+    /// - The position is ignored when the monitor is no longer connected,
+    ///   or when the window would end up outside all monitors
+    /// - A minimized window is restored as a normal window
+    ///
+    /// The checks against monitors are skipped when the window backend
+    /// does not support monitor enumeration.
+    pub fn restore<W: AdvancedWindow>(&self, window: &mut W) {
+        let monitors = window.monitors();
+        let target = match self.monitor {
+            Some(ref name) => monitors.iter()
+                .find(|monitor| monitor.name.as_ref() == Some(name)),
+            None => None,
+        };
+        let monitor_missing = self.monitor.is_some() && !monitors.is_empty() && target.is_none();
+
+        let mut size = [self.size[0].max(1.0), self.size[1].max(1.0)];
+        if let Some(monitor) = target {
+            let max = monitor_size_in_points(monitor);
+            size = [size[0].min(max[0]), size[1].min(max[1])];
+        }
+        window.set_size(size);
+
+        if let Some(pos) = self.position {
+            let visible = monitors.is_empty() ||
+                monitors.iter().any(|monitor| intersects(monitor, pos, size));
+            if visible && !monitor_missing {
+                window.set_position(Position::from(pos));
+            }
+        }
+
+        match self.window_state {
+            WindowState::Maximized => window.maximize(),
+            WindowState::Normal | WindowState::Minimized => {}
+        }
+    }
+}
+
+/// Stores the geometry and monitor of a window in windowed mode,
+/// to restore the placement when leaving fullscreen.
+///
+/// See [`AdvancedWindow::toggle_fullscreen`](trait.AdvancedWindow.html#method.toggle_fullscreen).
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowedPlacement {
+    /// The size, position and state of the window.
+    pub geometry: WindowGeometry,
+    /// The monitor the window was on, if known.
+    pub monitor: Option<MonitorId>,
+}
+
+impl WindowedPlacement {
+    /// Captures the current placement of a window.
+    pub fn capture<W: AdvancedWindow>(window: &W) -> WindowedPlacement {
+        WindowedPlacement {
+            geometry: WindowGeometry::capture(window),
+            monitor: window.current_monitor().map(|monitor| monitor.id),
+        }
+    }
+
+    /// Restores the placement of a window.
+    ///
+    /// When the position does not bring the window back to its monitor,
+    /// the window is moved to the upper left corner of the monitor.
+    pub fn restore<W: AdvancedWindow>(&self, window: &mut W) {
+        self.geometry.restore(window);
+        if self.monitor.is_none() || window.current_monitor().map(|m| m.id) == self.monitor {
+            return;
+        }
+        if let Some(monitor) = window.monitors().into_iter().find(|m| Some(m.id) == self.monitor) {
+            let s = monitor.scale_factor;
+            window.set_position([(monitor.position.x as f64 / s) as i32,
+                                 (monitor.position.y as f64 / s) as i32]);
+        }
+    }
+}
+
+fn monitor_size_in_points(monitor: &Monitor) -> [f64; 2] {
+    [monitor.physical_size[0] as f64 / monitor.scale_factor,
+     monitor.physical_size[1] as f64 / monitor.scale_factor]
+}
+
+/// Returns true if the window rectangle overlaps the monitor.
+fn intersects(monitor: &Monitor, pos: [i32; 2], size: [f64; 2]) -> bool {
+    let s = monitor.scale_factor;
+    let x = pos[0] as f64 * s;
+    let y = pos[1] as f64 * s;
+    let w = size[0] * s;
+    let h = size[1] * s;
+    let mx = monitor.position.x as f64;
+    let my = monitor.position.y as f64;
+    let mw = monitor.physical_size[0] as f64;
+    let mh = monitor.physical_size[1] as f64;
+    x < mx + mw && x + w > mx && y < my + mh && y + h > my
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {HeadlessWindow, Window, WindowSettings};
+
+    #[test]
+    fn test_capture_restore() {
+        let mut window = HeadlessWindow::new(&WindowSettings::new("test", [640, 480]));
+        window.set_position([100, 50]);
+        window.maximize();
+        let geometry = WindowGeometry::capture(&window);
+        assert_eq!(geometry.size, [640.0, 480.0]);
+        assert_eq!(geometry.position, Some([100, 50]));
+
+        let mut window = HeadlessWindow::new(&WindowSettings::new("test", [100, 100]));
+        geometry.restore(&mut window);
+        assert_eq!(window.size(), [640, 480].into());
+        assert_eq!(window.get_position(), Some([100, 50].into()));
+        assert_eq!(window.get_window_state(), WindowState::Maximized);
+    }
+}
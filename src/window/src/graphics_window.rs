@@ -0,0 +1,63 @@
+//! Raw window handles for graphics APIs other than OpenGL and owner windows.
+
+use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle, RawWindowHandle};
+
+use Window;
+
+/// Trait for windows that expose raw platform handles.
+///
+/// Graphics APIs such as Vulkan, Metal, DirectX and libraries such as wgpu
+/// create a rendering surface from the raw window and display handles.
+/// By using the [`GraphicsWindow`](trait.GraphicsWindow.html) trait,
+/// these renderers can be driven by a Piston window and event loop.
+///
+/// This trait is automatically implemented for windows that implement
+/// `HasRawWindowHandle` and `HasRawDisplayHandle` from the
+/// [raw-window-handle](https://crates.io/crates/raw-window-handle) library.
+///
+/// Requires the `raw-window-handle` feature.
+pub trait GraphicsWindow: Window + HasRawWindowHandle + HasRawDisplayHandle {}
+
+impl<T> GraphicsWindow for T where T: Window + HasRawWindowHandle + HasRawDisplayHandle {}
+
+/// Stores the relationship of a window to its owner window.
+///
+/// See [`WindowSettings::build_with_owner`](struct.WindowSettings.html#method.build_with_owner).
+///
+/// Requires the `raw-window-handle` feature.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum WindowOwner {
+    /// The window stays above its owner,
+    /// and is minimized and closed together with the owner.
+    Child(RawWindowHandle),
+    /// Like `Child`, but the owner does not receive input
+    /// while the window is open, for example in dialogs.
+    Modal(RawWindowHandle),
+}
+
+impl WindowOwner {
+    /// Creates a child relationship to the owner window.
+    pub fn child_of<W: HasRawWindowHandle>(owner: &W) -> WindowOwner {
+        WindowOwner::Child(owner.raw_window_handle())
+    }
+
+    /// Creates a modal relationship to the owner window.
+    pub fn modal_of<W: HasRawWindowHandle>(owner: &W) -> WindowOwner {
+        WindowOwner::Modal(owner.raw_window_handle())
+    }
+
+    /// Gets the raw window handle of the owner window.
+    pub fn handle(&self) -> RawWindowHandle {
+        match *self {
+            WindowOwner::Child(handle) | WindowOwner::Modal(handle) => handle,
+        }
+    }
+
+    /// Returns `true` if the owner does not receive input while the window is open.
+    pub fn is_modal(&self) -> bool {
+        match *self {
+            WindowOwner::Child(_) => false,
+            WindowOwner::Modal(_) => true,
+        }
+    }
+}
@@ -0,0 +1,611 @@
+//! A headless window with injected events and fake timing.
+//!
+//! Used to run event loop driven code in tests and on servers,
+//! without an operating system window.
+
+use {AdvancedWindow, BuildFromWindowSettings, Fullscreen, MockClock, Monitor, Position, Size,
+     SleepStrategy, SwapBuffersError, TimeSource, Window, WindowLevel, WindowSettings, WindowWaker,
+     WindowedPlacement};
+
+use input::{AccessibilitySettings, Button, ButtonArgs, ButtonState, CloseArgs, DeviceArgs,
+            DeviceInfo, DeviceState, Event, Input, Key, Orientation, SafeAreaInsets, WakeArgs,
+            WindowState};
+use std::collections::VecDeque;
+use std::error::Error;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use waker::WakeSignal;
+
+/// A window without user interface that emits injected events.
+///
+/// Events are either pushed to be emitted right away,
+/// or scheduled by time stamp on a clock.
+/// The window uses its own [`MockClock`](./struct.MockClock.html) by default,
+/// which only advances when waiting for events
+/// or when calling [`advance()`](#method.advance),
+/// so waiting never sleeps.
+/// Use [`set_time_source()`](#method.set_time_source) to share the clock of the event loop.
+///
+/// When no events are left, [`wait_event()`](trait.Window.html#tymethod.wait_event)
+/// sets the window to close and returns a close event.
+///
+/// Closing is emulated like a normal window backend:
+/// `Input::CloseRequested(..)` closes the window when automatic close is enabled,
+/// and pressing Esc closes the window when exit on esc is enabled.
+/// An `Input::Back(..)` event that is not marked as handled
+/// closes the window when polling the next event.
+pub struct HeadlessWindow {
+    should_close: bool,
+    /// Whether the last back event was handled, while it is being handled.
+    back_handled: Option<bool>,
+    automatic_close: bool,
+    exit_on_esc: bool,
+    title: String,
+    size: Size,
+    draw_size: Size,
+    pos: Position,
+    fullscreen: Option<Fullscreen>,
+    windowed: Option<WindowedPlacement>,
+    window_state: WindowState,
+    window_level: WindowLevel,
+    safe_area: SafeAreaInsets,
+    orientation: Option<Orientation>,
+    allowed_orientations: Vec<Orientation>,
+    accessibility: AccessibilitySettings,
+    devices: Vec<DeviceInfo>,
+    monitors: Vec<Monitor>,
+    clock: Arc<TimeSource>,
+    start: Instant,
+    events: VecDeque<(Duration, Event)>,
+    swap_count: u64,
+    swap_errors: VecDeque<SwapBuffersError>,
+    gpu_frame_time: Option<Duration>,
+    wake: WakeSignal,
+}
+
+impl HeadlessWindow {
+    /// Creates a new `HeadlessWindow`.
+    pub fn new(settings: &WindowSettings) -> HeadlessWindow {
+        let clock = MockClock::new();
+        let start = clock.now();
+        HeadlessWindow {
+            should_close: false,
+            back_handled: None,
+            automatic_close: settings.get_automatic_close(),
+            exit_on_esc: settings.get_exit_on_esc(),
+            title: settings.get_title(),
+            size: settings.get_size(),
+            draw_size: settings.get_size(),
+            pos: Position { x: 0, y: 0 },
+            fullscreen: settings.get_fullscreen_mode(),
+            windowed: None,
+            window_state: WindowState::Normal,
+            window_level: WindowLevel::Normal,
+            safe_area: SafeAreaInsets::default(),
+            orientation: None,
+            allowed_orientations: Orientation::ALL.to_vec(),
+            accessibility: AccessibilitySettings::default(),
+            devices: vec![],
+            monitors: vec![],
+            clock: Arc::new(clock),
+            start: start,
+            events: VecDeque::new(),
+            swap_count: 0,
+            swap_errors: VecDeque::new(),
+            gpu_frame_time: None,
+            wake: WakeSignal::default(),
+        }
+    }
+
+    /// Sets the source of time, e.g. the [`MockClock`](./struct.MockClock.html)
+    /// of the event loop, so scheduled events are emitted on the same time line.
+    ///
+    /// The time of scheduled events is measured from the current time of the source.
+    pub fn set_time_source(&mut self, source: Arc<TimeSource>) {
+        self.start = source.now();
+        self.clock = source;
+    }
+
+    /// Pushes an event to be emitted at the current time.
+    ///
+    /// Events at the same time are emitted in the order they were pushed.
+    pub fn push_event<E: Into<Event>>(&mut self, event: E) {
+        let time = self.time();
+        self.push_event_at(time, event);
+    }
+
+    /// Schedules an event to be emitted when the clock reaches `time`.
+    ///
+    /// The time is measured since the window was created,
+    /// or since the time source was set.
+    pub fn push_event_at<E: Into<Event>>(&mut self, time: Duration, event: E) {
+        let i = self.events.iter().position(|&(t, _)| t > time).unwrap_or(self.events.len());
+        self.events.insert(i, (time, event.into()));
+    }
+
+    /// Returns the time of the clock since the window was created,
+    /// or since the time source was set.
+    pub fn time(&self) -> Duration {
+        self.clock.now().duration_since(self.start)
+    }
+
+    /// Moves the clock forward.
+    ///
+    /// Sleeps when using a time source that can not be moved, such as the system clock.
+    pub fn advance(&mut self, dt: Duration) {
+        let time = self.time() + dt;
+        self.wait_until(time);
+    }
+
+    /// Waits until the clock reaches `time`.
+    fn wait_until(&self, time: Duration) {
+        if time > self.time() {
+            self.clock.sleep_until(self.start + time, SleepStrategy::default());
+        }
+    }
+
+    /// Returns the number of events that are not emitted yet.
+    pub fn pending_events(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns the number of times buffers were swapped.
+    ///
+    /// This equals the number of rendered frames when swapping automatically.
+    pub fn swap_count(&self) -> u64 {
+        self.swap_count
+    }
+
+    /// Fails the next swap of buffers with an error, emulating a lost context or device.
+    ///
+    /// Pushing several errors fails the swaps in order.
+    pub fn push_swap_error(&mut self, err: SwapBuffersError) {
+        self.swap_errors.push_back(err);
+    }
+
+    /// Sets the draw size separately from the window size,
+    /// emulating a monitor where a point is not equal to a pixel.
+    pub fn set_draw_size<S: Into<Size>>(&mut self, val: S) {
+        self.draw_size = val.into();
+    }
+
+    /// Sets the connected monitors, emulating a multi-monitor setup.
+    ///
+    /// The first monitor is the primary monitor.
+    /// The window is on the monitor containing its position.
+    pub fn set_monitors(&mut self, monitors: Vec<Monitor>) {
+        self.monitors = monitors;
+    }
+
+    /// Sets the GPU frame time to report, emulating a backend with timer queries.
+    pub fn set_gpu_frame_time(&mut self, val: Option<Duration>) {
+        self.gpu_frame_time = val;
+    }
+
+    /// Performs the default action of an unhandled back event.
+    fn end_back(&mut self) {
+        if let Some(false) = self.back_handled.take() {
+            self.should_close = true;
+        }
+    }
+
+    fn emit(&mut self, event: Event) -> Event {
+        match event {
+            Event::Input(Input::Back(_), _) => {
+                self.back_handled = Some(false);
+            }
+            Event::Input(Input::CloseRequested(_), _) if self.automatic_close => {
+                self.should_close = true;
+            }
+            Event::Input(Input::Button(ButtonArgs {
+                state: ButtonState::Press,
+                button: Button::Keyboard(Key::Escape),
+                ..
+            }), _) if self.exit_on_esc => {
+                self.should_close = true;
+            }
+            Event::Input(Input::Resize(ref args), _) => {
+                self.size = args.window_size.into();
+                self.draw_size = args.draw_size.into();
+            }
+            Event::Input(Input::Moved(pos), _) => {
+                self.pos = pos.into();
+            }
+            Event::Input(Input::WindowState(state), _) => {
+                self.window_state = state;
+            }
+            Event::Input(Input::SafeArea(insets), _) => {
+                self.safe_area = insets;
+            }
+            Event::Input(Input::OrientationChanged(orientation), _) => {
+                self.orientation = Some(orientation);
+            }
+            Event::Input(Input::Accessibility(settings), _) => {
+                self.accessibility = settings;
+            }
+            Event::Input(Input::Device(DeviceArgs { state, ref info }), _) => {
+                self.devices.retain(|device| device.kind != info.kind || device.id != info.id);
+                if state == DeviceState::Added {
+                    self.devices.push(info.clone());
+                }
+            }
+            _ => {}
+        }
+        event
+    }
+}
+
+impl Window for HeadlessWindow {
+    fn should_close(&self) -> bool {
+        self.should_close
+    }
+
+    fn set_should_close(&mut self, value: bool) {
+        self.should_close = value;
+    }
+
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn gpu_frame_time(&mut self) -> Option<Duration> {
+        self.gpu_frame_time
+    }
+
+    fn swap_buffers(&mut self) -> Result<(), SwapBuffersError> {
+        if let Some(err) = self.swap_errors.pop_front() {
+            return Err(err);
+        }
+        self.swap_count += 1;
+        Ok(())
+    }
+
+    fn wait_event(&mut self) -> Event {
+        self.end_back();
+        if self.wake.take() {
+            return Input::Wake(WakeArgs).into();
+        }
+        match self.events.pop_front() {
+            Some((time, event)) => {
+                self.wait_until(time);
+                self.emit(event)
+            }
+            None => {
+                self.should_close = true;
+                Input::Close(CloseArgs).into()
+            }
+        }
+    }
+
+    fn wait_event_timeout(&mut self, timeout: Duration) -> Option<Event> {
+        self.end_back();
+        if self.wake.take() {
+            return Some(Input::Wake(WakeArgs).into());
+        }
+        let end = self.time() + timeout;
+        match self.events.front() {
+            Some(&(time, _)) if time <= end => {}
+            _ => {
+                self.wait_until(end);
+                return None;
+            }
+        }
+        Some(self.wait_event())
+    }
+
+    fn poll_event(&mut self) -> Option<Event> {
+        self.end_back();
+        if self.wake.take() {
+            return Some(Input::Wake(WakeArgs).into());
+        }
+        match self.events.front() {
+            Some(&(time, _)) if time <= self.time() => {}
+            _ => return None,
+        }
+        let (_, event) = self.events.pop_front().unwrap();
+        Some(self.emit(event))
+    }
+
+    fn create_waker(&self) -> Option<WindowWaker> {
+        Some(self.wake.waker())
+    }
+
+    fn draw_size(&self) -> Size {
+        self.draw_size
+    }
+}
+
+impl BuildFromWindowSettings for HeadlessWindow {
+    /// # Errors
+    ///
+    /// This function will always return without error.
+    fn build_from_window_settings(settings: &WindowSettings) -> Result<Self, Box<Error>> {
+        Ok(HeadlessWindow::new(settings))
+    }
+}
+
+impl AdvancedWindow for HeadlessWindow {
+    fn get_title(&self) -> String {
+        self.title.clone()
+    }
+
+    fn set_title(&mut self, value: String) {
+        self.title = value;
+    }
+
+    fn get_exit_on_esc(&self) -> bool {
+        self.exit_on_esc
+    }
+
+    fn set_exit_on_esc(&mut self, value: bool) {
+        self.exit_on_esc = value;
+    }
+
+    fn get_automatic_close(&self) -> bool {
+        self.automatic_close
+    }
+
+    fn set_automatic_close(&mut self, value: bool) {
+        self.automatic_close = value;
+    }
+
+    fn set_capture_cursor(&mut self, _value: bool) {}
+
+    fn show(&mut self) {}
+
+    fn hide(&mut self) {}
+
+    fn minimize(&mut self) {
+        self.window_state = WindowState::Minimized;
+    }
+
+    fn maximize(&mut self) {
+        self.window_state = WindowState::Maximized;
+    }
+
+    fn restore(&mut self) {
+        self.window_state = WindowState::Normal;
+    }
+
+    fn get_window_state(&self) -> WindowState {
+        self.window_state
+    }
+
+    fn get_window_level(&self) -> WindowLevel {
+        self.window_level
+    }
+
+    fn set_window_level(&mut self, level: WindowLevel) {
+        self.window_level = level;
+    }
+
+    fn set_back_handled(&mut self, value: bool) {
+        if self.back_handled.is_some() {
+            self.back_handled = Some(value);
+        }
+    }
+
+    fn get_safe_area_insets(&self) -> SafeAreaInsets {
+        self.safe_area
+    }
+
+    fn get_orientation(&self) -> Option<Orientation> {
+        self.orientation
+    }
+
+    fn get_allowed_orientations(&self) -> Vec<Orientation> {
+        self.allowed_orientations.clone()
+    }
+
+    fn set_allowed_orientations(&mut self, orientations: &[Orientation]) {
+        self.allowed_orientations = if orientations.is_empty() {
+            Orientation::ALL.to_vec()
+        } else {
+            orientations.to_vec()
+        };
+    }
+
+    fn get_accessibility_settings(&self) -> AccessibilitySettings {
+        self.accessibility
+    }
+
+    fn devices(&self) -> Vec<DeviceInfo> {
+        self.devices.clone()
+    }
+
+    fn get_position(&self) -> Option<Position> {
+        Some(self.pos)
+    }
+
+    fn set_position<P: Into<Position>>(&mut self, val: P) {
+        self.pos = val.into();
+    }
+
+    fn set_size<S: Into<Size>>(&mut self, val: S) {
+        let size = val.into();
+        self.size = size;
+        self.draw_size = size;
+    }
+
+    fn get_fullscreen(&self) -> Option<Fullscreen> {
+        self.fullscreen
+    }
+
+    fn set_fullscreen(&mut self, value: Option<Fullscreen>) {
+        match (self.fullscreen.is_some(), value.is_some()) {
+            (false, true) => {
+                if self.windowed.is_none() {
+                    self.windowed = Some(WindowedPlacement::capture(self));
+                }
+                self.fullscreen = value;
+            }
+            (true, false) => {
+                self.fullscreen = None;
+                if let Some(placement) = self.windowed.take() {
+                    placement.restore(self);
+                    self.window_state = placement.geometry.window_state;
+                }
+            }
+            _ => self.fullscreen = value,
+        }
+    }
+
+    fn get_windowed_placement(&self) -> Option<WindowedPlacement> {
+        self.windowed.clone()
+    }
+
+    fn set_windowed_placement(&mut self, value: Option<WindowedPlacement>) {
+        self.windowed = value;
+    }
+
+    fn monitors(&self) -> Vec<Monitor> {
+        self.monitors.clone()
+    }
+
+    fn primary_monitor(&self) -> Option<Monitor> {
+        self.monitors.first().cloned()
+    }
+
+    fn current_monitor(&self) -> Option<Monitor> {
+        self.monitors.iter().find(|monitor| {
+            let x = self.pos.x as f64 * monitor.scale_factor - monitor.position.x as f64;
+            let y = self.pos.y as f64 * monitor.scale_factor - monitor.position.y as f64;
+            x >= 0.0 && x < monitor.physical_size[0] as f64 &&
+            y >= 0.0 && y < monitor.physical_size[1] as f64
+        }).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use input::{BackArgs, BackSource, CloseRequestedArgs, Motion};
+    use MonitorId;
+
+    #[test]
+    fn test_scheduled_events() {
+        let mut window = HeadlessWindow::new(&WindowSettings::new("test", [100, 100]));
+        window.push_event_at(Duration::from_millis(20), Motion::MouseCursor([2.0, 0.0].into()));
+        window.push_event_at(Duration::from_millis(10), Motion::MouseCursor([1.0, 0.0].into()));
+        assert_eq!(window.poll_event(), None);
+        assert_eq!(window.wait_event_timeout(Duration::from_millis(5)), None);
+        assert_eq!(window.time(), Duration::from_millis(5));
+        assert_eq!(window.wait_event_timeout(Duration::from_millis(10)),
+                   Some(Motion::MouseCursor([1.0, 0.0].into()).into()));
+        assert_eq!(window.time(), Duration::from_millis(10));
+        assert_eq!(window.wait_event(), Motion::MouseCursor([2.0, 0.0].into()).into());
+        assert_eq!(window.time(), Duration::from_millis(20));
+        assert!(!window.should_close());
+        window.wait_event();
+        assert!(window.should_close());
+    }
+
+    #[test]
+    fn test_toggle_fullscreen() {
+        let monitor = |id, x| Monitor {
+            id: MonitorId(id),
+            name: None,
+            physical_size: [1920, 1080],
+            position: [x, 0].into(),
+            scale_factor: 1.0,
+            video_modes: vec![],
+        };
+        let mut window = HeadlessWindow::new(&WindowSettings::new("test", [100, 100]));
+        window.set_monitors(vec![monitor(1, 0), monitor(2, 1920)]);
+        window.set_position([2000, 20]);
+        window.toggle_fullscreen();
+        assert_eq!(window.get_fullscreen(), Some(Fullscreen::Borderless(Some(MonitorId(2)))));
+        window.set_size([1920, 1080]);
+        window.set_position([0, 0]);
+        assert_eq!(window.current_monitor().map(|m| m.id), Some(MonitorId(1)));
+        window.toggle_fullscreen();
+        assert_eq!(window.get_fullscreen(), None);
+        assert_eq!(window.size(), [100, 100].into());
+        assert_eq!(window.get_position(), Some([2000, 20].into()));
+        assert_eq!(window.current_monitor().map(|m| m.id), Some(MonitorId(2)));
+        assert_eq!(window.get_windowed_placement(), None);
+    }
+
+    #[test]
+    fn test_capabilities() {
+        let mut window = HeadlessWindow::new(&WindowSettings::new("test", [100, 100]));
+        assert!(!window.get_capabilities().supports_monitors());
+        window.set_monitors(vec![Monitor {
+            id: MonitorId(1),
+            name: None,
+            physical_size: [1920, 1080],
+            position: [0, 0].into(),
+            scale_factor: 1.0,
+            video_modes: vec![],
+        }]);
+        let capabilities = window.get_capabilities();
+        assert!(capabilities.supports_monitors());
+        assert!(!capabilities.supports_clipboard());
+        assert!(!capabilities.supports_exclusive_fullscreen());
+    }
+
+    #[test]
+    fn test_automatic_close() {
+        let settings = WindowSettings::new("test", [100, 100]);
+        let mut window = HeadlessWindow::new(&settings);
+        window.push_event(CloseRequestedArgs);
+        assert!(window.poll_event().is_some());
+        assert!(window.should_close());
+
+        let mut window = HeadlessWindow::new(&settings.automatic_close(false));
+        window.push_event(CloseRequestedArgs);
+        assert!(window.poll_event().is_some());
+        assert!(!window.should_close());
+    }
+
+    #[test]
+    fn test_back() {
+        let mut window = HeadlessWindow::new(&WindowSettings::new("test", [100, 100]));
+        window.push_event(BackArgs { source: BackSource::Button });
+        assert!(window.poll_event().is_some());
+        window.set_back_handled(true);
+        assert!(window.poll_event().is_none());
+        assert!(!window.should_close());
+
+        window.push_event(BackArgs { source: BackSource::Gesture });
+        assert!(window.poll_event().is_some());
+        assert!(window.poll_event().is_none());
+        assert!(window.should_close());
+
+        let mut window = HeadlessWindow::new(&WindowSettings::new("test", [100, 100]));
+        window.push_event(BackArgs { source: BackSource::Button });
+        assert!(window.poll_event().is_some());
+        assert!(window.wait_event_timeout(Duration::from_millis(10)).is_none());
+        assert!(window.should_close());
+    }
+
+    #[test]
+    fn test_shared_time_source() {
+        let clock = MockClock::new();
+        clock.advance(Duration::from_secs(1));
+        let mut window = HeadlessWindow::new(&WindowSettings::new("test", [100, 100]));
+        window.set_time_source(Arc::new(clock.clone()));
+        window.push_event_at(Duration::from_millis(20), Motion::MouseCursor([1.0, 0.0].into()));
+        clock.advance(Duration::from_millis(20));
+        assert_eq!(window.time(), Duration::from_millis(20));
+        assert!(window.poll_event().is_some());
+        window.advance(Duration::from_millis(5));
+        assert_eq!(clock.elapsed(), Duration::from_millis(1025));
+    }
+
+    #[test]
+    fn test_build_srgb_opengl_2() {
+        let settings = WindowSettings::new("test", [100, 100]).graphics_api(::Api::opengl(2, 1));
+        assert!(settings.get_srgb());
+        assert!(settings.build::<HeadlessWindow>().is_ok());
+    }
+
+    #[test]
+    fn test_build_srgb_vulkan() {
+        let settings = WindowSettings::new("test", [100, 100]).graphics_api(::Api::vulkan(1, 0));
+        assert!(settings.build::<HeadlessWindow>().is_ok());
+        let settings = settings.srgb(true);
+        assert_eq!(settings.validate().err().map(|err| err.setting), Some("srgb"));
+        assert!(settings.build::<HeadlessWindow>().is_err());
+    }
+}
@@ -0,0 +1,77 @@
+//! Hit testing for windows without decoration.
+//!
+//! Windows without decoration have no title bar or border provided
+//! by the operating system. Hit test regions tell the operating system
+//! which parts of the window behave like a title bar or a border,
+//! so custom title bars can be dragged and resized like native ones.
+
+/// Stores what part of the window is under the mouse cursor.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum HitTest {
+    /// The content of the window, handled by the application.
+    Client,
+    /// Dragging moves the window, like a title bar.
+    Caption,
+    /// Dragging resizes the top edge.
+    ResizeTop,
+    /// Dragging resizes the bottom edge.
+    ResizeBottom,
+    /// Dragging resizes the left edge.
+    ResizeLeft,
+    /// Dragging resizes the right edge.
+    ResizeRight,
+    /// Dragging resizes the top left corner.
+    ResizeTopLeft,
+    /// Dragging resizes the top right corner.
+    ResizeTopRight,
+    /// Dragging resizes the bottom left corner.
+    ResizeBottomLeft,
+    /// Dragging resizes the bottom right corner.
+    ResizeBottomRight,
+}
+
+/// Stores a rectangle in the window with hit test behavior.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HitTestRegion {
+    /// The rectangle `[x, y, width, height]` in points,
+    /// with origin in the upper left corner of the window.
+    pub rect: [f64; 4],
+    /// The behavior inside the rectangle.
+    pub hit_test: HitTest,
+}
+
+impl HitTestRegion {
+    /// Returns true if the position in points is inside the region.
+    pub fn contains(&self, pos: [f64; 2]) -> bool {
+        let [x, y, w, h] = self.rect;
+        pos[0] >= x && pos[0] < x + w && pos[1] >= y && pos[1] < y + h
+    }
+}
+
+/// Returns the hit test behavior at a position in points.
+///
+/// Regions later in the list take precedence over earlier ones.
+/// Returns `HitTest::Client` when the position is outside all regions.
+/// Window backends use this to answer hit test requests from the operating system.
+pub fn hit_test(regions: &[HitTestRegion], pos: [f64; 2]) -> HitTest {
+    regions.iter().rev()
+        .find(|region| region.contains(pos))
+        .map(|region| region.hit_test)
+        .unwrap_or(HitTest::Client)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hit_test() {
+        let regions = [
+            HitTestRegion {rect: [0.0, 0.0, 100.0, 20.0], hit_test: HitTest::Caption},
+            HitTestRegion {rect: [90.0, 0.0, 10.0, 10.0], hit_test: HitTest::ResizeTopRight},
+        ];
+        assert_eq!(hit_test(&regions, [50.0, 10.0]), HitTest::Caption);
+        assert_eq!(hit_test(&regions, [95.0, 5.0]), HitTest::ResizeTopRight);
+        assert_eq!(hit_test(&regions, [50.0, 50.0]), HitTest::Client);
+    }
+}
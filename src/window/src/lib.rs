@@ -15,26 +15,77 @@
 //! The [`WindowSettings`](./struct.WindowSettings.html) structure is the preferred way of building
 //! new windows in Piston. It uses the `BuildFromWindowSettings` trait,
 //! which backends implement to handle window creation and setup.
+//! With the `toml` or `ron` feature, window settings can be loaded from configuration files.
 //!
 //! The [`OpenGLWindow`](./trait.OpenGLWindow.html) trait is used to provide low-level
 //! access to OpenGL through the abstract Piston API.
 //!
+//! The `GraphicsWindow` trait is used to provide raw window handles
+//! for other graphics APIs, such as Vulkan and Metal.
+//! It requires the `raw-window-handle` feature.
+//!
+//! The [`NoWindow`](./struct.NoWindow.html) and [`HeadlessWindow`](./struct.HeadlessWindow.html)
+//! structures run without an operating system window, e.g. on servers and in tests.
+//!
 //! The [`Size`](./struct.Size.html) structure is used throughout Piston to store window sizes.
 //! It implements some conversion traits for convenience.
+//!
+//! The [`Monitor`](./struct.Monitor.html) structure describes a monitor
+//! returned by [`AdvancedWindow::monitors`](./trait.AdvancedWindow.html#method.monitors).
 
 extern crate graphics_api_version;
 extern crate input;
+#[cfg(feature = "raw-window-handle")]
+pub extern crate raw_window_handle;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde_derive;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "toml")]
+extern crate toml;
+#[cfg(feature = "ron")]
+extern crate ron;
 
 use std::convert::From;
+use std::fmt;
+use std::path::PathBuf;
 use std::time::Duration;
 use std::error::Error;
-use input::Event;
+use input::{AccessibilitySettings, DeviceInfo, Event, LogicalSize, Orientation, PhysicalSize,
+            SafeAreaInsets, Theme, WindowState};
 
 pub use graphics_api_version::Version as Api;
 pub use graphics_api_version::UnsupportedGraphicsApiError;
 pub use no_window::NoWindow;
+pub use headless_window::HeadlessWindow;
+pub use monitor::{Monitor, MonitorId, VideoMode};
+pub use rgba_image::RgbaImage;
+pub use cursor::{CursorGrabMode, CursorIcon};
+pub use hit_test::{hit_test, HitTest, HitTestRegion};
+pub use geometry::{WindowGeometry, WindowedPlacement};
+pub use capabilities::WindowCapabilities;
+pub use waker::WindowWaker;
+pub use sleep::SleepStrategy;
+pub use time_source::{MockClock, SystemClock, TimeSource};
+#[cfg(feature = "raw-window-handle")]
+pub use graphics_window::{GraphicsWindow, WindowOwner};
 
 mod no_window;
+mod headless_window;
+mod monitor;
+mod rgba_image;
+mod cursor;
+mod hit_test;
+mod geometry;
+mod capabilities;
+mod waker;
+mod sleep;
+mod time_source;
+#[cfg(any(feature = "toml", feature = "ron"))]
+mod config;
+#[cfg(feature = "raw-window-handle")]
+mod graphics_window;
 
 /// The type of an OpenGL function address.
 ///
@@ -127,6 +178,36 @@ impl From<Size> for (f64, f64) {
     }
 }
 
+impl From<LogicalSize> for Size {
+    #[inline(always)]
+    fn from(value: LogicalSize) -> Size {
+        Size {
+            width: value.width,
+            height: value.height,
+        }
+    }
+}
+
+impl From<PhysicalSize> for Size {
+    #[inline(always)]
+    fn from(value: PhysicalSize) -> Size {
+        Size {
+            width: value.width as f64,
+            height: value.height as f64,
+        }
+    }
+}
+
+impl From<Size> for LogicalSize {
+    #[inline(always)]
+    fn from(value: Size) -> LogicalSize {
+        LogicalSize {
+            width: value.width,
+            height: value.height,
+        }
+    }
+}
+
 /// Structure to store the window position.
 ///
 /// The width and height are in *points*. On most computers, a point
@@ -177,6 +258,194 @@ impl From<Position> for (i32, i32) {
     }
 }
 
+/// Stores the kind of fullscreen.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Fullscreen {
+    /// A borderless window covering the whole monitor.
+    ///
+    /// The video mode of the monitor is left unchanged.
+    /// When the monitor is `None`, the current monitor is used.
+    Borderless(Option<MonitorId>),
+    /// Exclusive fullscreen, changing the video mode of the monitor.
+    ///
+    /// When the monitor is `None`, the current monitor is used.
+    Exclusive {
+        /// The monitor to use.
+        monitor: Option<MonitorId>,
+        /// The video mode to use.
+        ///
+        /// This should be one of the video modes
+        /// returned by the monitor.
+        video_mode: VideoMode,
+    },
+}
+
+impl Fullscreen {
+    /// Returns the monitor to use, if any.
+    pub fn monitor(&self) -> Option<MonitorId> {
+        match *self {
+            Fullscreen::Borderless(monitor) => monitor,
+            Fullscreen::Exclusive { monitor, .. } => monitor,
+        }
+    }
+
+    /// Returns the video mode of exclusive fullscreen, if any.
+    pub fn video_mode(&self) -> Option<VideoMode> {
+        match *self {
+            Fullscreen::Borderless(_) => None,
+            Fullscreen::Exclusive { video_mode, .. } => Some(video_mode),
+        }
+    }
+}
+
+/// Stores data to drag out of the window.
+///
+/// See [`AdvancedWindow::start_drag`](./trait.AdvancedWindow.html#method.start_drag).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DragData {
+    /// A list of files.
+    Paths(Vec<PathBuf>),
+    /// A text snippet.
+    Text(String),
+}
+
+/// Stores how urgently the window requests attention from the user.
+///
+/// See [`AdvancedWindow::request_attention`](./trait.AdvancedWindow.html#method.request_attention).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AttentionType {
+    /// Notifies the user once, for example by flashing the taskbar button once
+    /// or bouncing the dock icon once.
+    Informational,
+    /// Notifies the user until the window gets focus.
+    Critical,
+}
+
+/// Stores the stacking order of a window relative to other windows.
+///
+/// See [`AdvancedWindow::set_window_level`](./trait.AdvancedWindow.html#method.set_window_level).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum WindowLevel {
+    /// The window is stacked like other windows.
+    Normal,
+    /// The window stays above normal windows, for example in overlays.
+    AlwaysOnTop,
+    /// The window stays below normal windows, for example in desktop widgets.
+    AlwaysOnBottom,
+}
+
+impl Default for WindowLevel {
+    fn default() -> WindowLevel {
+        WindowLevel::Normal
+    }
+}
+
+/// Stores how a window handles monitors with different DPI.
+///
+/// See [`WindowSettings::set_dpi_awareness`](./struct.WindowSettings.html#method.set_dpi_awareness).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DpiAwareness {
+    /// Uses the default of the window backend.
+    Default,
+    /// The scale factor is taken from the primary monitor when the window is created.
+    ///
+    /// The operating system scales the window by stretching on other monitors.
+    System,
+    /// The scale factor follows the monitor the window is on.
+    ///
+    /// When the window moves to a monitor with a different DPI,
+    /// an `Input::ScaleFactorChanged` event is emitted,
+    /// followed by an `Input::Resize` event with the new draw size.
+    PerMonitor,
+}
+
+impl Default for DpiAwareness {
+    fn default() -> DpiAwareness {
+        DpiAwareness::Default
+    }
+}
+
+/// Stores the progress shown on the taskbar button or dock icon.
+///
+/// The progress values are in the range `0.0..=1.0`.
+///
+/// See [`AdvancedWindow::set_taskbar_progress`](./trait.AdvancedWindow.html#method.set_taskbar_progress).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TaskbarProgress {
+    /// No progress is shown.
+    None,
+    /// The operation is running, but the progress is unknown.
+    Indeterminate,
+    /// The operation is running.
+    Normal(f64),
+    /// The operation is paused.
+    Paused(f64),
+    /// The operation failed.
+    Error(f64),
+}
+
+/// An error for when window settings are invalid.
+///
+/// See [`WindowSettings::validate`](./struct.WindowSettings.html#method.validate)
+/// and [`WindowSettings::from_toml`](./struct.WindowSettings.html#method.from_toml).
+#[derive(Debug)]
+pub struct InvalidWindowSettingsError {
+    /// The name of the setting, e.g. `"srgb"`.
+    pub setting: &'static str,
+    /// Describes what is wrong with the setting.
+    pub message: String,
+}
+
+impl fmt::Display for InvalidWindowSettingsError {
+    fn fmt(&self, w: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(w, "Invalid window setting `{}`: {}", self.setting, self.message)
+    }
+}
+
+impl Error for InvalidWindowSettingsError {}
+
+/// An error for when swapping render buffers fails.
+///
+/// See [`Window::swap_buffers`](./trait.Window.html#tymethod.swap_buffers).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SwapBuffersError {
+    /// The graphics context was lost, e.g. after a driver reset.
+    ContextLost,
+    /// The rendering surface was lost, e.g. when the window was minimized
+    /// or the swapchain became out of date.
+    SurfaceLost,
+    /// The graphics device was removed or disconnected.
+    DeviceRemoved,
+    /// Some other error reported by the window backend.
+    Other(String),
+}
+
+impl SwapBuffersError {
+    /// Returns `true` if graphics resources must be created again
+    /// before rendering the next frame.
+    pub fn is_context_lost(&self) -> bool {
+        match *self {
+            SwapBuffersError::ContextLost |
+            SwapBuffersError::SurfaceLost |
+            SwapBuffersError::DeviceRemoved => true,
+            SwapBuffersError::Other(_) => false,
+        }
+    }
+}
+
+impl fmt::Display for SwapBuffersError {
+    fn fmt(&self, w: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            SwapBuffersError::ContextLost => write!(w, "Graphics context was lost"),
+            SwapBuffersError::SurfaceLost => write!(w, "Rendering surface was lost"),
+            SwapBuffersError::DeviceRemoved => write!(w, "Graphics device was removed"),
+            SwapBuffersError::Other(ref msg) => write!(w, "Could not swap buffers: {}", msg),
+        }
+    }
+}
+
+impl Error for SwapBuffersError {}
+
 /// Constructs a window from a [`WindowSettings`](./struct.WindowSettings.html)
 /// object.
 ///
@@ -191,6 +460,25 @@ pub trait BuildFromWindowSettings: Sized {
     /// See your backend's documentation for details about what kind of
     /// error strings can be returned, and the conditions for error.
     fn build_from_window_settings(settings: &WindowSettings) -> Result<Self, Box<Error>>;
+
+    /// Builds the window from a `WindowSettings` object, with an owner window.
+    ///
+    /// Popup windows and dialogs stay above their owner window.
+    /// The default implementation ignores the owner, for platforms
+    /// that do not support owner windows.
+    ///
+    /// Requires the `raw-window-handle` feature.
+    ///
+    /// # Errors
+    ///
+    /// See your backend's documentation for details about what kind of
+    /// error strings can be returned, and the conditions for error.
+    #[cfg(feature = "raw-window-handle")]
+    fn build_from_window_settings_with_owner(settings: &WindowSettings, _owner: WindowOwner)
+        -> Result<Self, Box<Error>>
+    {
+        Self::build_from_window_settings(settings)
+    }
 }
 
 /// Trait representing the minimum requirements for defining a window.
@@ -205,6 +493,9 @@ pub trait BuildFromWindowSettings: Sized {
 /// since these are generated by the event loop.
 pub trait Window {
     /// Tells the window to close or stay open.
+    ///
+    /// Calling this with `false` while handling an `Input::CloseRequested(..)` event
+    /// vetoes the attempt to close the window.
     fn set_should_close(&mut self, value: bool);
 
     /// Returns true if the window should close.
@@ -218,15 +509,32 @@ pub trait Window {
     /// When this is set to false, this method must be called manually
     /// or through the window backend. By default it is set to true, so
     /// usually it is not needed in application code.
-    fn swap_buffers(&mut self);
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the frame could not be presented,
+    /// for example when the graphics context or surface was lost.
+    /// The event loop reports lost contexts, surfaces and devices
+    /// through `Input::Context` events with the matching `ContextState`,
+    /// and other errors through `Loop::SwapFailed` events.
+    fn swap_buffers(&mut self) -> Result<(), SwapBuffersError>;
 
     /// Wait indefinitely for an input event to be available from the window.
+    ///
+    /// This blocks the current thread without busy polling,
+    /// so lazy applications use no CPU while waiting.
+    /// Events that are already available are returned immediately.
     fn wait_event(&mut self) -> Event;
 
     /// Wait for an input event to be available from the window or for the
     /// specified timeout to be reached.
     ///
     /// Returns `None` only if there is no input event within the timeout.
+    ///
+    /// Like [`wait_event()`](#tymethod.wait_event), this blocks the current thread
+    /// without busy polling. It should not return `None` before the timeout is reached.
+    /// A backend that can not block on its event queue should sleep instead.
+    /// With a zero timeout, this behaves like [`poll_event()`](#tymethod.poll_event).
     fn wait_event_timeout(&mut self, timeout: Duration) -> Option<Event>;
 
     /// Polls an input event from the window.
@@ -234,6 +542,24 @@ pub trait Window {
     /// Return `None` if no events available.
     fn poll_event(&mut self) -> Option<Event>;
 
+    /// Creates a handle that interrupts waiting for events from other threads.
+    ///
+    /// Useful in lazy event loops that need to react to work other than input,
+    /// for example when a network message arrives.
+    /// Returns `None` if the platform does not support this.
+    fn create_waker(&self) -> Option<WindowWaker> {
+        None
+    }
+
+    /// Returns the GPU time of the last finished frame.
+    ///
+    /// Backends that support timer queries measure the time the GPU spent
+    /// rendering, which is reported through `AfterRender` events.
+    /// Returns `None` if no measurement is available.
+    fn gpu_frame_time(&mut self) -> Option<Duration> {
+        None
+    }
+
     /// Gets the draw size of the window.
     ///
     /// This is equal to the size of the frame buffer of the inner window,
@@ -242,6 +568,21 @@ pub trait Window {
     /// This information is given to the client code through the
     /// [`Render`](../input/enum.Event.html) event.
     fn draw_size(&self) -> Size;
+
+    /// Gets the scale factor of the window.
+    ///
+    /// This is the number of pixels per point.
+    /// Changes are reported through `Input::ScaleFactorChanged` events.
+    ///
+    /// By default, this is computed from the draw size and the window size.
+    fn scale_factor(&self) -> f64 {
+        let size = self.size();
+        if size.width == 0.0 {
+            1.0
+        } else {
+            self.draw_size().width / size.width
+        }
+    }
 }
 
 /// Trait representing a window with the most features that are still generic.
@@ -297,7 +638,7 @@ pub trait AdvancedWindow: Window + Sized {
 
     /// Sets whether the window will automatically close when attempting
     /// to close it. If this is disabled, attempts to close the window
-    /// can be detected via an `Input::Close(..)` event, and
+    /// can be detected via an `Input::CloseRequested(..)` event, and
     /// [`Window::set_should_close()`](trait.Window.html#tymethod.set_should_close)
     /// can be called to actually close the window.
     ///
@@ -306,7 +647,7 @@ pub trait AdvancedWindow: Window + Sized {
 
     /// Sets whether the window will automatically close when attempting
     /// to close it. If this is disabled, attempts to close the window
-    /// can be detected via an `Input::Close(..)` event, and
+    /// can be detected via an `Input::CloseRequested(..)` event, and
     /// [`Window::set_should_close()`](trait.Window.html#tymethod.set_should_close)
     /// can be called to actually close the window.
     ///
@@ -324,7 +665,21 @@ pub trait AdvancedWindow: Window + Sized {
     ///
     /// This is used to lock and hide cursor to the window,
     /// for example in a first-person shooter game.
-    fn set_capture_cursor(&mut self, value: bool);
+    ///
+    /// This is the same as calling
+    /// [`set_cursor_grab()`](#method.set_cursor_grab) with `CursorGrabMode::Locked`
+    /// and [`set_cursor_visible()`](#method.set_cursor_visible) with `false`.
+    /// When locking is not supported, confining the cursor is attempted instead.
+    fn set_capture_cursor(&mut self, value: bool) {
+        if value {
+            if self.set_cursor_grab(CursorGrabMode::Locked).is_err() {
+                let _ = self.set_cursor_grab(CursorGrabMode::Confined);
+            }
+        } else {
+            let _ = self.set_cursor_grab(CursorGrabMode::None);
+        }
+        self.set_cursor_visible(!value);
+    }
 
     /// Sets whether to capture/grab the cursor.
     ///
@@ -336,6 +691,94 @@ pub trait AdvancedWindow: Window + Sized {
         self
     }
 
+    /// Sets whether the mouse cursor is visible while it is over the window.
+    ///
+    /// If the platform does not support this, it will have no effect.
+    fn set_cursor_visible(&mut self, _value: bool) {}
+
+    /// Returns whether the window backend supports a cursor grab mode.
+    ///
+    /// `CursorGrabMode::None` is always supported.
+    fn supports_cursor_grab(&self, mode: CursorGrabMode) -> bool {
+        mode == CursorGrabMode::None
+    }
+
+    /// Gets which optional features are supported by the window backend.
+    ///
+    /// By default, this is derived from
+    /// [`supports_set_vsync()`](#method.supports_set_vsync),
+    /// [`supports_cursor_grab()`](#method.supports_cursor_grab),
+    /// [`monitors()`](#method.monitors) and [`devices()`](#method.devices),
+    /// where monitors and devices are supported when the list is not empty.
+    /// Backends that support the other features must override this.
+    fn get_capabilities(&self) -> WindowCapabilities {
+        WindowCapabilities {
+            set_vsync: self.supports_set_vsync(),
+            cursor_grab: self.supports_cursor_grab(CursorGrabMode::Confined) ||
+                         self.supports_cursor_grab(CursorGrabMode::Locked),
+            monitors: !self.monitors().is_empty(),
+            devices: !self.devices().is_empty(),
+            ..WindowCapabilities::default()
+        }
+    }
+
+    /// Sets how the mouse cursor is grabbed by the window.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the grab mode is not supported,
+    /// see [`supports_cursor_grab()`](#method.supports_cursor_grab).
+    fn set_cursor_grab(&mut self, mode: CursorGrabMode) -> Result<(), Box<Error>> {
+        if mode == CursorGrabMode::None {
+            Ok(())
+        } else {
+            Err(format!("Cursor grab mode `{:?}` is not supported by this window backend",
+                        mode).into())
+        }
+    }
+
+    /// Sets the icon of the mouse cursor while it is over the window.
+    ///
+    /// If the platform does not support the icon, it will have no effect.
+    fn set_cursor(&mut self, _icon: CursorIcon) {}
+
+    /// Brings the window to the front and gives it input focus.
+    ///
+    /// Some platforms restrict focus stealing,
+    /// in which case this might only request attention from the user.
+    /// If the platform does not support this, it will have no effect.
+    fn request_focus(&mut self) {}
+
+    /// Returns true if the window has input focus.
+    ///
+    /// Changes are reported through `Input::Focus` events.
+    /// Returns `true` if the platform does not support this.
+    fn is_focused(&self) -> bool {
+        true
+    }
+
+    /// Starts moving the window with the mouse, like dragging a title bar.
+    ///
+    /// Call this when a mouse button is pressed over a custom title bar
+    /// of a window without decoration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the platform does not support this.
+    fn drag_window(&mut self) -> Result<(), Box<Error>> {
+        Err("Dragging the window is not supported by this window backend".into())
+    }
+
+    /// Sets the hit test regions of the window.
+    ///
+    /// This tells the operating system which parts of a window without decoration
+    /// behave like a title bar or a border.
+    /// Regions later in the list take precedence, see [`hit_test`](./fn.hit_test.html).
+    /// Passing an empty list treats the whole window as content.
+    ///
+    /// If the platform does not support this, it will have no effect.
+    fn set_hit_test(&mut self, _regions: Vec<HitTestRegion>) {}
+
     /// Shows the window.
     ///
     /// If the platform does not support this, it will have no effect.
@@ -346,9 +789,195 @@ pub trait AdvancedWindow: Window + Sized {
     /// If the platform does not support this, it will have no effect.
     fn hide(&mut self);
 
+    /// Minimizes the window.
+    ///
+    /// If the platform does not support this, it will have no effect.
+    fn minimize(&mut self) {}
+
+    /// Maximizes the window.
+    ///
+    /// If the platform does not support this, it will have no effect.
+    fn maximize(&mut self) {}
+
+    /// Restores the window from being minimized or maximized.
+    ///
+    /// If the platform does not support this, it will have no effect.
+    fn restore(&mut self) {}
+
+    /// Gets whether the window is minimized, maximized or neither.
+    ///
+    /// Changes are reported through `Input::WindowState` events.
+    /// Returns `WindowState::Normal` if the platform does not support this.
+    fn get_window_state(&self) -> WindowState {
+        WindowState::Normal
+    }
+
+    /// Requests attention from the user while the window is not focused.
+    ///
+    /// This flashes the taskbar button or bounces the dock icon,
+    /// depending on the platform.
+    /// If the platform does not support this, it will have no effect.
+    fn request_attention(&mut self, _kind: AttentionType) {}
+
+    /// Gets the stacking order of the window relative to other windows.
+    ///
+    /// Returns `WindowLevel::Normal` if the platform does not support this.
+    fn get_window_level(&self) -> WindowLevel {
+        WindowLevel::Normal
+    }
+
+    /// Sets the stacking order of the window relative to other windows.
+    ///
+    /// If the platform does not support this, it will have no effect.
+    fn set_window_level(&mut self, _level: WindowLevel) {}
+
+    /// Sets the stacking order of the window relative to other windows.
+    ///
+    /// This method moves the current window data,
+    /// unlike [`set_window_level()`](#method.set_window_level), so
+    /// that it can be used in method chaining.
+    fn window_level(mut self, level: WindowLevel) -> Self {
+        self.set_window_level(level);
+        self
+    }
+
+    /// Sets the progress shown on the taskbar button or dock icon,
+    /// for example during long operations like baking assets.
+    ///
+    /// If the platform does not support this, it will have no effect.
+    fn set_taskbar_progress(&mut self, _progress: TaskbarProgress) {}
+
+    /// Sets the opacity of the whole window.
+    ///
+    /// The value is clamped to the range `0.0..=1.0`,
+    /// where `0.0` is fully transparent and `1.0` is fully opaque.
+    /// If the platform does not support this, it will have no effect.
+    fn set_opacity(&mut self, _value: f32) {}
+
+    /// Sets the opacity of the whole window.
+    ///
+    /// This method moves the current window data,
+    /// unlike [`set_opacity()`](#method.set_opacity), so
+    /// that it can be used in method chaining.
+    fn opacity(mut self, value: f32) -> Self {
+        self.set_opacity(value);
+        self
+    }
+
+    /// Sets whether the window contents are protected from screen capture.
+    ///
+    /// A protected window appears blank in screenshots and screen recordings,
+    /// which is useful for DRM-sensitive or privacy-sensitive content.
+    /// If the platform does not support this, it will have no effect.
+    fn set_content_protected(&mut self, _value: bool) {}
+
+    /// Sets whether the window contents are protected from screen capture.
+    ///
+    /// This method moves the current window data,
+    /// unlike [`set_content_protected()`](#method.set_content_protected), so
+    /// that it can be used in method chaining.
+    fn content_protected(mut self, value: bool) -> Self {
+        self.set_content_protected(value);
+        self
+    }
+
+    /// Marks the last `Input::Back` event as handled by the application,
+    /// e.g. when closing a screen of a navigation stack.
+    ///
+    /// Must be called while handling the event.
+    /// Unhandled back events perform the default action of the platform,
+    /// such as closing the application.
+    /// If the platform does not support this, it will have no effect.
+    fn set_back_handled(&mut self, _value: bool) {}
+
+    /// Gets whether the operating system is in light or dark mode.
+    ///
+    /// Changes are reported through `Input::Theme` events.
+    /// Returns `None` if the platform does not support this.
+    fn get_theme(&self) -> Option<Theme> {
+        None
+    }
+
+    /// Gets the safe area insets of the window, in points.
+    ///
+    /// UI should not be placed under display cutouts, rounded corners
+    /// or system UI such as a home indicator.
+    /// Changes are reported through `Input::SafeArea` events,
+    /// for example when a mobile device is rotated.
+    /// Returns zero insets if the platform does not support this.
+    fn get_safe_area_insets(&self) -> SafeAreaInsets {
+        SafeAreaInsets::default()
+    }
+
+    /// Gets the orientation of the screen.
+    ///
+    /// Changes are reported through `Input::OrientationChanged` events.
+    /// Returns `None` if the platform does not support this.
+    fn get_orientation(&self) -> Option<Orientation> {
+        None
+    }
+
+    /// Gets the orientations the screen is allowed to rotate to.
+    ///
+    /// Returns all orientations if the platform does not support this.
+    fn get_allowed_orientations(&self) -> Vec<Orientation> {
+        Orientation::ALL.to_vec()
+    }
+
+    /// Sets the orientations the screen is allowed to rotate to,
+    /// e.g. to lock a game to landscape.
+    ///
+    /// An empty list allows all orientations.
+    /// If the platform does not support this, it will have no effect.
+    fn set_allowed_orientations(&mut self, _orientations: &[Orientation]) {}
+
+    /// Sets the orientations the screen is allowed to rotate to.
+    ///
+    /// This method moves the current window data,
+    /// unlike [`set_allowed_orientations()`](#method.set_allowed_orientations), so
+    /// that it can be used in method chaining.
+    fn allowed_orientations(mut self, orientations: &[Orientation]) -> Self {
+        self.set_allowed_orientations(orientations);
+        self
+    }
+
+    /// Gets the accessibility settings of the operating system,
+    /// such as sticky keys, the double click interval and reduced motion.
+    ///
+    /// Changes are reported through `Input::Accessibility` events.
+    /// Returns the default settings if the platform does not support this.
+    fn get_accessibility_settings(&self) -> AccessibilitySettings {
+        AccessibilitySettings::default()
+    }
+
+    /// Returns `true` if the user prefers reduced motion.
+    ///
+    /// Changes are reported through `Input::Accessibility` events.
+    fn prefers_reduced_motion(&self) -> bool {
+        self.get_accessibility_settings().reduced_motion
+    }
+
+    /// Returns `true` if a high contrast mode is active.
+    ///
+    /// Changes are reported through `Input::Accessibility` events.
+    fn is_high_contrast(&self) -> bool {
+        self.get_accessibility_settings().high_contrast
+    }
+
+    /// Returns the input devices that are connected,
+    /// such as keyboards, mice and controllers.
+    ///
+    /// Changes are reported through `Input::Device` events.
+    /// Returns an empty list if the platform does not support this.
+    fn devices(&self) -> Vec<DeviceInfo> {
+        vec![]
+    }
+
     /// Gets the position of window.
     ///
-    // Returns `None` if the window no longer has a position.
+    /// Returns `None` if the window no longer has a position.
+    ///
+    /// When the window is moved, an `Input::Moved` event is emitted.
     fn get_position(&self) -> Option<Position>;
 
     /// Sets the position of window.
@@ -372,6 +1001,205 @@ pub trait AdvancedWindow: Window + Sized {
         self.set_position(val);
         self
     }
+
+    /// Gets the text stored in the clipboard.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clipboard does not contain text,
+    /// or if the platform does not support this.
+    fn get_clipboard_text(&mut self) -> Result<String, Box<Error>> {
+        Err("Clipboard is not supported by this window backend".into())
+    }
+
+    /// Stores text in the clipboard.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the platform does not support this.
+    fn set_clipboard_text(&mut self, _text: &str) -> Result<(), Box<Error>> {
+        Err("Clipboard is not supported by this window backend".into())
+    }
+
+    /// Gets the image stored in the clipboard.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clipboard does not contain an image,
+    /// or if the platform does not support images in the clipboard.
+    fn get_clipboard_image(&mut self) -> Result<RgbaImage, Box<Error>> {
+        Err("Clipboard images are not supported by this window backend".into())
+    }
+
+    /// Stores an image in the clipboard.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the platform does not support images in the clipboard.
+    fn set_clipboard_image(&mut self, _image: &RgbaImage) -> Result<(), Box<Error>> {
+        Err("Clipboard images are not supported by this window backend".into())
+    }
+
+    /// Starts dragging data out of the window.
+    ///
+    /// This begins a drag and drop operation of the operating system,
+    /// usually while a mouse button is held down.
+    /// The data can then be dropped into other applications.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the platform does not support this.
+    fn start_drag(&mut self, _data: DragData) -> Result<(), Box<Error>> {
+        Err("Dragging is not supported by this window backend".into())
+    }
+
+    /// Returns true if vsync can be changed after the window is built.
+    ///
+    /// See [`set_vsync()`](#method.set_vsync).
+    fn supports_set_vsync(&self) -> bool {
+        false
+    }
+
+    /// Sets whether the window should use vsync.
+    ///
+    /// This replaces the vsync setting from `WindowSettings`,
+    /// for example when offered as a graphics option in a game.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if vsync can not be changed,
+    /// see [`supports_set_vsync()`](#method.supports_set_vsync).
+    fn set_vsync(&mut self, _value: bool) -> Result<(), Box<Error>> {
+        Err("Changing vsync is not supported by this window backend".into())
+    }
+
+    /// Reads pixels from the frame buffer of the window.
+    ///
+    /// The rectangle is `[x, y, width, height]` in pixels,
+    /// with origin in the upper left corner of the draw area.
+    /// Call this after rendering and before swapping buffers,
+    /// e.g. to take screenshots or for automated visual tests.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rectangle is outside the draw area,
+    /// or if the platform does not support this.
+    fn read_pixels(&mut self, _rect: [u32; 4]) -> Result<RgbaImage, Box<Error>> {
+        Err("Reading pixels is not supported by this window backend".into())
+    }
+
+    /// Gets whether the frame buffer of the window uses sRGB color space.
+    ///
+    /// This is the color space that was actually obtained,
+    /// which might differ from the one requested in `WindowSettings`.
+    /// Returns `None` if the platform can not tell.
+    fn get_srgb(&self) -> Option<bool> {
+        None
+    }
+
+    /// Sets the icon of the window from RGBA pixel data.
+    ///
+    /// The length of `rgba` must be `width * height * 4`,
+    /// see [`RgbaImage`](./struct.RgbaImage.html).
+    ///
+    /// If the platform does not support this, it will have no effect.
+    fn set_icon(&mut self, _width: u32, _height: u32, _rgba: Vec<u8>) {}
+
+    /// Sets the icon of the window from RGBA pixel data.
+    ///
+    /// This method moves the current window data,
+    /// unlike [`set_icon()`](#method.set_icon), so
+    /// that it can be used in method chaining.
+    fn icon(mut self, width: u32, height: u32, rgba: Vec<u8>) -> Self {
+        self.set_icon(width, height, rgba);
+        self
+    }
+
+    /// Gets the fullscreen mode of the window.
+    ///
+    /// Returns `None` if the window is not fullscreen,
+    /// or if the platform does not support this.
+    fn get_fullscreen(&self) -> Option<Fullscreen> {
+        None
+    }
+
+    /// Sets the fullscreen mode of the window.
+    ///
+    /// When `None` is passed, the window leaves fullscreen.
+    /// If the platform does not support this, it will have no effect.
+    ///
+    /// Backends should remember the size, position and state of the window
+    /// when entering fullscreen, and restore them when leaving fullscreen.
+    fn set_fullscreen(&mut self, _value: Option<Fullscreen>) {}
+
+    /// Sets the fullscreen mode of the window.
+    ///
+    /// This method moves the current window data,
+    /// unlike [`set_fullscreen()`](#method.set_fullscreen), so
+    /// that it can be used in method chaining.
+    fn fullscreen(mut self, value: Option<Fullscreen>) -> Self {
+        self.set_fullscreen(value);
+        self
+    }
+
+    /// Toggles between windowed mode and borderless fullscreen
+    /// on the current monitor.
+    ///
+    /// The size, position, state and monitor of the window are remembered
+    /// when entering fullscreen, and restored when leaving fullscreen.
+    /// See [`set_fullscreen()`](#method.set_fullscreen).
+    fn toggle_fullscreen(&mut self) {
+        if self.get_fullscreen().is_some() {
+            self.set_fullscreen(None);
+            // The backend might have restored the placement already.
+            if let Some(placement) = self.get_windowed_placement() {
+                self.set_windowed_placement(None);
+                placement.restore(self);
+            }
+        } else {
+            let placement = WindowedPlacement::capture(self);
+            let monitor = placement.monitor;
+            self.set_windowed_placement(Some(placement));
+            self.set_fullscreen(Some(Fullscreen::Borderless(monitor)));
+        }
+    }
+
+    /// Gets the placement of the window to restore when leaving fullscreen.
+    ///
+    /// Returns `None` if the window is not fullscreen,
+    /// or if the backend does not store the placement.
+    fn get_windowed_placement(&self) -> Option<WindowedPlacement> {
+        None
+    }
+
+    /// Sets the placement of the window to restore when leaving fullscreen.
+    ///
+    /// Used by [`toggle_fullscreen()`](#method.toggle_fullscreen).
+    /// Backends store the value so the placement can be restored,
+    /// otherwise restoring is left to [`set_fullscreen()`](#method.set_fullscreen).
+    fn set_windowed_placement(&mut self, _value: Option<WindowedPlacement>) {}
+
+    /// Returns the monitors that are connected.
+    ///
+    /// Returns an empty list if the platform does not support this.
+    fn monitors(&self) -> Vec<Monitor> {
+        vec![]
+    }
+
+    /// Returns the primary monitor.
+    ///
+    /// Returns `None` if the platform does not support this.
+    fn primary_monitor(&self) -> Option<Monitor> {
+        None
+    }
+
+    /// Returns the monitor the window is currently on.
+    ///
+    /// Returns `None` if the platform does not support this,
+    /// or if the window is not on any monitor.
+    fn current_monitor(&self) -> Option<Monitor> {
+        None
+    }
 }
 
 /// Trait for OpenGL specific operations on a window.
@@ -398,6 +1226,28 @@ pub trait OpenGLWindow: Window {
 
     /// Make the window's gl context the current gl context.
     fn make_current(&mut self);
+
+    /// Returns true if the window's gl context was lost.
+    ///
+    /// Context loss is reported through `Input::Context(ContextState::Lost)` events.
+    /// While the context is lost, rendering has no effect.
+    /// Returns `false` if the platform does not detect context loss.
+    fn is_context_lost(&self) -> bool {
+        false
+    }
+
+    /// Recreates the window's gl context after it was lost.
+    ///
+    /// On success, an `Input::Context(ContextState::Restored)` event is emitted
+    /// and all gl resources must be created again.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the context could not be created,
+    /// or if the platform does not support this.
+    fn recreate_context(&mut self) -> Result<(), Box<Error>> {
+        Err("Recreating the gl context is not supported by this window backend".into())
+    }
 }
 
 /// Settings structure for window behavior.
@@ -406,20 +1256,31 @@ pub trait OpenGLWindow: Window {
 /// constructing most windows. This structure makes it easy to create multiple
 /// windows with the same settings, and it also makes piston's multiple backends
 /// easier to implement for piston devs.
+///
+/// Owner windows are not part of the settings, because raw window handles
+/// are neither `Send` nor `Sync` and can not be loaded from configuration files.
+/// Pass the owner to [`build_with_owner()`](#method.build_with_owner) instead,
+/// which requires the `raw-window-handle` feature.
 #[derive(Clone)]
 pub struct WindowSettings {
     title: String,
     size: Size,
     samples: u8,
-    fullscreen: bool,
+    fullscreen: Option<Fullscreen>,
     exit_on_esc: bool,
     automatic_close: bool,
     vsync: bool,
     graphics_api: Option<Api>,
     srgb: bool,
+    // Whether sRGB was requested explicitly instead of by default.
+    srgb_requested: bool,
     resizable: bool,
     decorated: bool,
     controllers: bool,
+    dpi_awareness: DpiAwareness,
+    accept_first_mouse: bool,
+    prevent_default: bool,
+    icon: Option<RgbaImage>,
 }
 
 impl WindowSettings {
@@ -433,20 +1294,29 @@ impl WindowSettings {
     /// - resizable: true
     /// - decorated: true
     /// - controllers: true
+    /// - dpi_awareness: Default
+    /// - accept_first_mouse: false
+    /// - prevent_default: true
+    /// - icon: None
     pub fn new<T: Into<String>, S: Into<Size>>(title: T, size: S) -> WindowSettings {
         WindowSettings {
             title: title.into(),
             size: size.into(),
             samples: 0,
-            fullscreen: false,
+            fullscreen: None,
             exit_on_esc: false,
             automatic_close: true,
             vsync: false,
             graphics_api: None,
             srgb: true,
+            srgb_requested: false,
             resizable: true,
             decorated: true,
             controllers: true,
+            dpi_awareness: DpiAwareness::Default,
+            accept_first_mouse: false,
+            prevent_default: true,
+            icon: None,
         }
     }
 
@@ -462,9 +1332,56 @@ impl WindowSettings {
     /// See your backend's documentation on `build_from_window_settings()`
     /// for more details.
     pub fn build<W: BuildFromWindowSettings>(&self) -> Result<W, Box<Error>> {
+        self.validate()?;
         BuildFromWindowSettings::build_from_window_settings(self)
     }
 
+    /// Checks that the settings can be used together.
+    ///
+    /// This is called by [`build()`](#method.build) before the window is built.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when settings conflict, for example
+    /// when requesting a sRGB frame buffer with a graphics API other than OpenGL,
+    /// where the color space is picked when creating the swapchain.
+    pub fn validate(&self) -> Result<(), InvalidWindowSettingsError> {
+        if let Some(ref api) = self.graphics_api {
+            if self.srgb && self.srgb_requested && !api.is_opengl() {
+                return Err(InvalidWindowSettingsError {
+                    setting: "srgb",
+                    message: format!("sRGB frame buffers require OpenGL, found {} {}.{}",
+                                     api.api, api.major, api.minor),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds window from the given settings, with an owner window.
+    ///
+    /// The owner is passed separately from the settings,
+    /// because raw window handles can not be sent to other threads
+    /// nor loaded from configuration files.
+    /// The same settings can build both top-level windows with
+    /// [`build()`](#method.build) and owned windows with this method.
+    /// If the platform does not support owner windows, the owner is ignored.
+    ///
+    /// Requires the `raw-window-handle` feature.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if your backend returns an error.
+    /// See your backend's documentation on `build_from_window_settings_with_owner()`
+    /// for more details.
+    #[cfg(feature = "raw-window-handle")]
+    pub fn build_with_owner<W: BuildFromWindowSettings>(&self, owner: WindowOwner)
+        -> Result<W, Box<Error>>
+    {
+        self.validate()?;
+        BuildFromWindowSettings::build_from_window_settings_with_owner(self, owner)
+    }
+
     /// Gets the title of built windows.
     pub fn get_title(&self) -> String {
         self.title.clone()
@@ -507,12 +1424,17 @@ impl WindowSettings {
 
     /// Gets whether built windows will be fullscreen.
     pub fn get_fullscreen(&self) -> bool {
-        self.fullscreen
+        self.fullscreen.is_some()
     }
 
     /// Sets whether built windows will be fullscreen.
+    ///
+    /// When set to `true`, built windows use borderless fullscreen
+    /// on the current monitor. For selecting exclusive fullscreen
+    /// or a specific monitor, see
+    /// [`set_fullscreen_mode()`](#method.set_fullscreen_mode).
     pub fn set_fullscreen(&mut self, value: bool) {
-        self.fullscreen = value;
+        self.fullscreen = if value {Some(Fullscreen::Borderless(None))} else {None};
     }
 
     /// Sets whether built windows will be fullscreen.
@@ -525,6 +1447,30 @@ impl WindowSettings {
         self
     }
 
+    /// Gets the fullscreen mode of built windows.
+    ///
+    /// Returns `None` if built windows will not be fullscreen.
+    pub fn get_fullscreen_mode(&self) -> Option<Fullscreen> {
+        self.fullscreen
+    }
+
+    /// Sets the fullscreen mode of built windows.
+    ///
+    /// When `None` is passed, built windows will not be fullscreen.
+    pub fn set_fullscreen_mode(&mut self, value: Option<Fullscreen>) {
+        self.fullscreen = value;
+    }
+
+    /// Sets the fullscreen mode of built windows.
+    ///
+    /// This method moves the current window data,
+    /// unlike [`set_fullscreen_mode()`](#method.set_fullscreen_mode),
+    /// so that it can be used in method chaining.
+    pub fn fullscreen_mode(mut self, value: Option<Fullscreen>) -> Self {
+        self.set_fullscreen_mode(value);
+        self
+    }
+
     /// Gets whether built windows should exit when the Esc key is pressed.
     pub fn get_exit_on_esc(&self) -> bool {
         self.exit_on_esc
@@ -553,7 +1499,7 @@ impl WindowSettings {
 
     /// Sets whether built windows should automatically close when the X or
     /// ALT+F4 are pressed. If this is disabled, attempts to close the window
-    /// can be detected via an `Input::Close(..)` event, and
+    /// can be detected via an `Input::CloseRequested(..)` event, and
     /// [`Window::set_should_close()`](trait.Window.html#tymethod.set_should_close)
     /// can be called to actually close the window.
     pub fn set_automatic_close(&mut self, value: bool) {
@@ -562,7 +1508,7 @@ impl WindowSettings {
 
     /// Sets whether built windows should automatically close when the X or
     /// ALT+F4 are pressed. If this is disabled, attempts to close the window
-    /// can be detected via an `Input::Close(..)` event, and
+    /// can be detected via an `Input::CloseRequested(..)` event, and
     /// [`Window::set_should_close()`](trait.Window.html#tymethod.set_should_close)
     /// can be called to actually close the window.
     ///
@@ -703,8 +1649,16 @@ impl WindowSettings {
     ///
     /// See [`get_srgb()`](#method.get_srgb) for more information about
     /// the srgb setting.
+    ///
+    /// Requesting sRGB with a graphics API other than OpenGL is an error,
+    /// see [`validate()`](#method.validate).
+    /// When the platform can not provide a sRGB frame buffer,
+    /// backends fall back to a frame buffer without sRGB.
+    /// To check whether a built window got a sRGB frame buffer, use
+    /// [`AdvancedWindow::get_srgb`](./trait.AdvancedWindow.html#method.get_srgb).
     pub fn set_srgb(&mut self, value: bool) {
         self.srgb = value;
+        self.srgb_requested = value;
     }
 
     /// Sets whether built windows should use hardware accelerated color conversion.
@@ -797,4 +1751,120 @@ impl WindowSettings {
         self.set_controllers(value);
         self
     }
+
+    /// Gets how built windows handle monitors with different DPI.
+    pub fn get_dpi_awareness(&self) -> DpiAwareness {
+        self.dpi_awareness
+    }
+
+    /// Sets how built windows handle monitors with different DPI.
+    ///
+    /// Use `DpiAwareness::PerMonitor` to opt in to per-monitor DPI awareness,
+    /// instead of relying on the default of the window backend.
+    /// This is mostly relevant on Windows, where the DPI awareness
+    /// must be set before the window is created.
+    /// If the platform does not support the setting, it has no effect.
+    pub fn set_dpi_awareness(&mut self, value: DpiAwareness) {
+        self.dpi_awareness = value;
+    }
+
+    /// Sets how built windows handle monitors with different DPI.
+    ///
+    /// This method moves the current window data,
+    /// unlike [`set_dpi_awareness()`](#method.set_dpi_awareness),
+    /// so that it can be used in method chaining.
+    pub fn dpi_awareness(mut self, value: DpiAwareness) -> Self {
+        self.set_dpi_awareness(value);
+        self
+    }
+
+    /// Gets whether the click that focuses built windows is delivered as input.
+    pub fn get_accept_first_mouse(&self) -> bool {
+        self.accept_first_mouse
+    }
+
+    /// Sets whether the click that focuses built windows is delivered as input.
+    ///
+    /// When `true`, clicking an unfocused window both focuses the window
+    /// and emits a mouse button press event, which games usually want.
+    /// When `false`, the first click only focuses the window,
+    /// which avoids accidental strokes in drawing applications.
+    /// This is only supported on macOS; other platforms have no effect.
+    pub fn set_accept_first_mouse(&mut self, value: bool) {
+        self.accept_first_mouse = value;
+    }
+
+    /// Sets whether the click that focuses built windows is delivered as input.
+    ///
+    /// This method moves the current window data,
+    /// unlike [`set_accept_first_mouse()`](#method.set_accept_first_mouse),
+    /// so that it can be used in method chaining.
+    pub fn accept_first_mouse(mut self, value: bool) -> Self {
+        self.set_accept_first_mouse(value);
+        self
+    }
+
+    /// Gets whether built windows prevent the default behavior of the browser for input.
+    pub fn get_prevent_default(&self) -> bool {
+        self.prevent_default
+    }
+
+    /// Sets whether built windows prevent the default behavior of the browser for input.
+    ///
+    /// When `true`, input on the canvas does not scroll or zoom the page,
+    /// and does not trigger browser shortcuts, which games usually want.
+    /// When `false`, the page can be scrolled and zoomed while the canvas has focus.
+    /// This is only supported by web backends; other platforms have no effect.
+    pub fn set_prevent_default(&mut self, value: bool) {
+        self.prevent_default = value;
+    }
+
+    /// Sets whether built windows prevent the default behavior of the browser for input.
+    ///
+    /// This method moves the current window data,
+    /// unlike [`set_prevent_default()`](#method.set_prevent_default),
+    /// so that it can be used in method chaining.
+    pub fn prevent_default(mut self, value: bool) -> Self {
+        self.set_prevent_default(value);
+        self
+    }
+
+    /// Gets the icon of built windows.
+    ///
+    /// If `None` is returned, the default icon of the platform is used.
+    pub fn get_icon(&self) -> Option<&RgbaImage> {
+        self.icon.as_ref()
+    }
+
+    /// Sets the icon of built windows from RGBA pixel data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `rgba` is not `width * height * 4`.
+    pub fn set_icon(&mut self, width: u32, height: u32, rgba: Vec<u8>) {
+        self.icon = Some(RgbaImage::new(width, height, rgba));
+    }
+
+    /// Sets the icon of built windows from RGBA pixel data.
+    ///
+    /// This method moves the current window data,
+    /// unlike [`set_icon()`](#method.set_icon),
+    /// so that it can be used in method chaining.
+    pub fn icon(mut self, width: u32, height: u32, rgba: Vec<u8>) -> Self {
+        self.set_icon(width, height, rgba);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use WindowSettings;
+
+    // Owner windows are passed to `build_with_owner`,
+    // so that settings can be sent to other threads.
+    #[test]
+    fn test_window_settings_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<WindowSettings>();
+    }
 }
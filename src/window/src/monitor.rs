@@ -0,0 +1,63 @@
+//! Monitor enumeration.
+//!
+//! Window backends that support it return a list of monitors
+//! through [`AdvancedWindow::monitors`](../trait.AdvancedWindow.html#method.monitors).
+//! This is used to place windows and pick a target for fullscreen.
+
+use Position;
+
+/// A backend specific identifier of a monitor.
+///
+/// The identifier is only guaranteed to be stable while the monitor stays connected.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MonitorId(pub u32);
+
+/// Stores a video mode supported by a monitor.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct VideoMode {
+    /// The width and height in pixels.
+    pub size: [u32; 2],
+    /// The number of bits per pixel.
+    pub bit_depth: u16,
+    /// The refresh rate in millihertz.
+    ///
+    /// For example, a 59.94 Hz monitor has refresh rate `59940`.
+    pub refresh_rate_millihertz: u32,
+}
+
+impl VideoMode {
+    /// Returns the refresh rate in hertz.
+    pub fn refresh_rate(&self) -> f64 {
+        self.refresh_rate_millihertz as f64 / 1000.0
+    }
+}
+
+/// Stores information about a monitor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Monitor {
+    /// The identifier of the monitor.
+    pub id: MonitorId,
+    /// The human readable name of the monitor, if available.
+    pub name: Option<String>,
+    /// The width and height of the monitor in pixels.
+    pub physical_size: [u32; 2],
+    /// The position of the upper left corner on the virtual desktop, in pixels.
+    pub position: Position,
+    /// The number of pixels per point.
+    pub scale_factor: f64,
+    /// The video modes supported by the monitor.
+    pub video_modes: Vec<VideoMode>,
+}
+
+impl Monitor {
+    /// Returns the video mode with the largest size and highest refresh rate.
+    ///
+    /// Returns `None` if the backend did not report any video modes.
+    pub fn best_video_mode(&self) -> Option<VideoMode> {
+        self.video_modes.iter().cloned().max_by_key(|mode| {
+            (mode.size[0] as u64 * mode.size[1] as u64,
+             mode.refresh_rate_millihertz,
+             mode.bit_depth)
+        })
+    }
+}
@@ -6,10 +6,12 @@
 //!
 //! Often used in servers as an event loop.
 
-use {Window, WindowSettings, BuildFromWindowSettings, AdvancedWindow, Position, Size};
+use {Window, WindowSettings, BuildFromWindowSettings, AdvancedWindow, Fullscreen, Position, Size,
+     SwapBuffersError, WindowLevel, WindowWaker, WindowedPlacement};
 
-use input::Event;
+use input::{Event, Input, WakeArgs, WindowState};
 use std::time::Duration;
+use waker::WakeSignal;
 use std::error::Error;
 
 /// A window without user interface, often used in server event loops.
@@ -24,6 +26,11 @@ pub struct NoWindow {
     title: String,
     size: Size,
     pos: Position,
+    fullscreen: Option<Fullscreen>,
+    windowed: Option<WindowedPlacement>,
+    window_state: WindowState,
+    window_level: WindowLevel,
+    wake: WakeSignal,
 }
 
 impl NoWindow {
@@ -35,6 +42,11 @@ impl NoWindow {
             title: settings.get_title(),
             size: settings.get_size(),
             pos: Position { x: 0, y: 0 },
+            fullscreen: settings.get_fullscreen_mode(),
+            windowed: None,
+            window_state: WindowState::Normal,
+            window_level: WindowLevel::Normal,
+            wake: WakeSignal::default(),
         }
     }
 }
@@ -52,18 +64,35 @@ impl Window for NoWindow {
         self.size
     }
 
-    fn swap_buffers(&mut self) {}
+    fn swap_buffers(&mut self) -> Result<(), SwapBuffersError> {
+        Ok(())
+    }
 
+    /// Blocks until woken by a [`WindowWaker`](struct.WindowWaker.html),
+    /// since there are no other events.
     fn wait_event(&mut self) -> Event {
-        panic!("NoWindow will never return an input event");
+        self.wake.wait();
+        Input::Wake(WakeArgs).into()
     }
 
-    fn wait_event_timeout(&mut self, _timeout: Duration) -> Option<Event> {
-        None
+    fn wait_event_timeout(&mut self, timeout: Duration) -> Option<Event> {
+        if self.wake.wait_timeout(timeout) {
+            Some(Input::Wake(WakeArgs).into())
+        } else {
+            None
+        }
     }
 
     fn poll_event(&mut self) -> Option<Event> {
-        None
+        if self.wake.take() {
+            Some(Input::Wake(WakeArgs).into())
+        } else {
+            None
+        }
+    }
+
+    fn create_waker(&self) -> Option<WindowWaker> {
+        Some(self.wake.waker())
     }
 
     fn draw_size(&self) -> Size {
@@ -109,6 +138,30 @@ impl AdvancedWindow for NoWindow {
 
     fn hide(&mut self) {}
 
+    fn minimize(&mut self) {
+        self.window_state = WindowState::Minimized;
+    }
+
+    fn maximize(&mut self) {
+        self.window_state = WindowState::Maximized;
+    }
+
+    fn restore(&mut self) {
+        self.window_state = WindowState::Normal;
+    }
+
+    fn get_window_state(&self) -> WindowState {
+        self.window_state
+    }
+
+    fn get_window_level(&self) -> WindowLevel {
+        self.window_level
+    }
+
+    fn set_window_level(&mut self, level: WindowLevel) {
+        self.window_level = level;
+    }
+
     fn get_position(&self) -> Option<Position> {
         Some(self.pos)
     }
@@ -120,4 +173,20 @@ impl AdvancedWindow for NoWindow {
     fn set_size<S: Into<Size>>(&mut self, val: S) {
         self.size = val.into();
     }
+
+    fn get_fullscreen(&self) -> Option<Fullscreen> {
+        self.fullscreen
+    }
+
+    fn set_fullscreen(&mut self, value: Option<Fullscreen>) {
+        self.fullscreen = value;
+    }
+
+    fn get_windowed_placement(&self) -> Option<WindowedPlacement> {
+        self.windowed.clone()
+    }
+
+    fn set_windowed_placement(&mut self, value: Option<WindowedPlacement>) {
+        self.windowed = value;
+    }
 }
@@ -0,0 +1,43 @@
+//! RGBA image data passed to and from window backends.
+
+/// Stores an image with 8 bit red, green, blue and alpha channels.
+///
+/// The pixels are stored row by row, starting with the upper left corner.
+/// Each pixel takes 4 bytes in `data`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RgbaImage {
+    /// The width in pixels.
+    pub width: u32,
+    /// The height in pixels.
+    pub height: u32,
+    /// The pixel data.
+    pub data: Vec<u8>,
+}
+
+impl RgbaImage {
+    /// Creates a new image from pixel data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `data` is not `width * height * 4`.
+    pub fn new(width: u32, height: u32, data: Vec<u8>) -> RgbaImage {
+        assert_eq!(data.len(), width as usize * height as usize * 4,
+                   "RGBA data does not match image size {}x{}", width, height);
+        RgbaImage {
+            width: width,
+            height: height,
+            data: data,
+        }
+    }
+
+    /// Returns the color of a pixel.
+    ///
+    /// Returns `None` if the pixel is outside the image.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<[u8; 4]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let i = (y as usize * self.width as usize + x as usize) * 4;
+        Some([self.data[i], self.data[i + 1], self.data[i + 2], self.data[i + 3]])
+    }
+}
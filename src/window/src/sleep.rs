@@ -0,0 +1,88 @@
+//! Precise sleeping for frame pacing.
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Stores how the event loop waits until the next frame or update.
+///
+/// Sleeping with the operating system can overshoot by multiple milliseconds,
+/// which causes visible judder. Spinning is precise, but uses more CPU.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SleepStrategy {
+    /// Sleeps with the operating system.
+    Sleep,
+    /// Sleeps with the operating system until the duration before the deadline,
+    /// then spins for the rest of the time.
+    ///
+    /// On Windows, a high-resolution timer is used while sleeping.
+    Hybrid(Duration),
+    /// Spins until the deadline, without sleeping.
+    Spin,
+}
+
+impl Default for SleepStrategy {
+    fn default() -> SleepStrategy {
+        SleepStrategy::Hybrid(Duration::from_millis(1))
+    }
+}
+
+/// Waits until the deadline is reached.
+pub fn sleep_until(deadline: Instant, strategy: SleepStrategy) {
+    let now = Instant::now();
+    if deadline <= now {
+        return;
+    }
+    match strategy {
+        SleepStrategy::Sleep => thread::sleep(deadline - now),
+        SleepStrategy::Hybrid(spin) => {
+            if deadline - now > spin {
+                precise_sleep(deadline - now - spin);
+            }
+            spin_until(deadline);
+        }
+        SleepStrategy::Spin => spin_until(deadline),
+    }
+}
+
+fn spin_until(deadline: Instant) {
+    while Instant::now() < deadline {
+        thread::yield_now();
+    }
+}
+
+#[cfg(not(windows))]
+fn precise_sleep(dur: Duration) {
+    thread::sleep(dur);
+}
+
+/// Sleeps with a timer resolution of 1 millisecond instead of the default 15.6.
+#[cfg(windows)]
+fn precise_sleep(dur: Duration) {
+    #[link(name = "winmm")]
+    extern "system" {
+        fn timeBeginPeriod(period: u32) -> u32;
+        fn timeEndPeriod(period: u32) -> u32;
+    }
+
+    unsafe {
+        timeBeginPeriod(1);
+    }
+    thread::sleep(dur);
+    unsafe {
+        timeEndPeriod(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sleep_until() {
+        for &strategy in &[SleepStrategy::Sleep, SleepStrategy::default(), SleepStrategy::Spin] {
+            let deadline = Instant::now() + Duration::from_millis(3);
+            sleep_until(deadline, strategy);
+            assert!(Instant::now() >= deadline);
+        }
+    }
+}
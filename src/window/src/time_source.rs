@@ -0,0 +1,84 @@
+//! Sources of time for the event loop and headless windows.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use sleep::{self, SleepStrategy};
+
+/// A source of time for the event loop.
+///
+/// The event loop uses the [`SystemClock`](./struct.SystemClock.html) by default.
+/// Use a [`MockClock`](./struct.MockClock.html) to step updates and renders
+/// deterministically in tests, see `Events::set_time_source`.
+/// Share the clock with a [`HeadlessWindow`](./struct.HeadlessWindow.html)
+/// to schedule window events on the same time line,
+/// see [`HeadlessWindow::set_time_source`](./struct.HeadlessWindow.html#method.set_time_source).
+pub trait TimeSource: Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> Instant;
+
+    /// Waits until the deadline.
+    fn sleep_until(&self, deadline: Instant, strategy: SleepStrategy) {
+        sleep::sleep_until(deadline, strategy);
+    }
+}
+
+/// Uses the time of the operating system.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SystemClock;
+
+impl TimeSource for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A clock that only moves when advanced, for testing.
+///
+/// Instead of sleeping, waiting advances the clock to the deadline.
+/// Clones share the same time, so a test can keep a clone to advance the clock
+/// while the event loop uses another.
+#[derive(Clone, Debug)]
+pub struct MockClock {
+    start: Instant,
+    elapsed: Arc<Mutex<Duration>>,
+}
+
+impl MockClock {
+    /// Creates a new mock clock.
+    pub fn new() -> MockClock {
+        MockClock {
+            start: Instant::now(),
+            elapsed: Arc::new(Mutex::new(Duration::from_secs(0))),
+        }
+    }
+
+    /// Returns the time since the clock was created.
+    pub fn elapsed(&self) -> Duration {
+        *self.elapsed.lock().unwrap()
+    }
+
+    /// Moves the clock forward.
+    pub fn advance(&self, dt: Duration) {
+        *self.elapsed.lock().unwrap() += dt;
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> MockClock {
+        MockClock::new()
+    }
+}
+
+impl TimeSource for MockClock {
+    fn now(&self) -> Instant {
+        self.start + self.elapsed()
+    }
+
+    fn sleep_until(&self, deadline: Instant, _strategy: SleepStrategy) {
+        let mut elapsed = self.elapsed.lock().unwrap();
+        if deadline > self.start + *elapsed {
+            *elapsed = deadline - self.start;
+        }
+    }
+}
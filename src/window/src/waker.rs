@@ -0,0 +1,107 @@
+//! Interrupting blocking waits for events from other threads.
+
+use std::fmt;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+
+/// A handle that interrupts waiting for events from any thread.
+///
+/// When woken, [`Window::wait_event`](trait.Window.html#tymethod.wait_event)
+/// and [`Window::wait_event_timeout`](trait.Window.html#tymethod.wait_event_timeout)
+/// return an `Input::Wake` event. If the window is not waiting,
+/// the wake event is emitted the next time events are polled.
+/// Multiple wakes before the event is emitted result in a single wake event.
+///
+/// See [`Window::create_waker`](trait.Window.html#method.create_waker).
+#[derive(Clone)]
+pub struct WindowWaker {
+    wake: Arc<Fn() + Send + Sync>,
+}
+
+impl WindowWaker {
+    /// Creates a new window waker from a function that wakes the window.
+    ///
+    /// Used by window backends, usually by posting a user event to
+    /// the event queue of the operating system.
+    pub fn new<F>(wake: F) -> WindowWaker
+        where F: 'static + Fn() + Send + Sync
+    {
+        WindowWaker {wake: Arc::new(wake)}
+    }
+
+    /// Wakes the window.
+    pub fn wake(&self) {
+        (self.wake)()
+    }
+}
+
+impl fmt::Debug for WindowWaker {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("WindowWaker")
+    }
+}
+
+/// A wake flag that window backends without an event queue can block on.
+#[derive(Clone, Default)]
+pub(crate) struct WakeSignal {
+    inner: Arc<(Mutex<bool>, Condvar)>,
+}
+
+impl WakeSignal {
+    /// Creates a waker that sets the flag.
+    pub fn waker(&self) -> WindowWaker {
+        let inner = self.inner.clone();
+        WindowWaker::new(move || {
+            let &(ref woken, ref cvar) = &*inner;
+            *woken.lock().unwrap() = true;
+            cvar.notify_all();
+        })
+    }
+
+    /// Clears the flag, returning `true` if it was set.
+    pub fn take(&self) -> bool {
+        let mut woken = self.inner.0.lock().unwrap();
+        let res = *woken;
+        *woken = false;
+        res
+    }
+
+    /// Blocks until the flag is set, then clears it.
+    pub fn wait(&self) {
+        let &(ref woken, ref cvar) = &*self.inner;
+        let mut guard = woken.lock().unwrap();
+        while !*guard {
+            guard = cvar.wait(guard).unwrap();
+        }
+        *guard = false;
+    }
+
+    /// Blocks until the flag is set or the timeout is reached.
+    ///
+    /// Returns `true` and clears the flag if it was set.
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        let &(ref woken, ref cvar) = &*self.inner;
+        let guard = woken.lock().unwrap();
+        let (mut guard, _) = cvar.wait_timeout_while(guard, timeout, |woken| !*woken).unwrap();
+        let res = *guard;
+        *guard = false;
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_wake_from_thread() {
+        let signal = WakeSignal::default();
+        let waker = signal.waker();
+        assert!(!signal.wait_timeout(Duration::from_millis(1)));
+        let handle = thread::spawn(move || waker.wake());
+        signal.wait();
+        handle.join().unwrap();
+        assert!(!signal.take());
+    }
+}